@@ -282,6 +282,8 @@ pub enum DataType {
     Custom(ObjectName, Vec<String>),
     /// Arrays
     Array(ArrayElemTypeDef),
+    /// SQL standard multiset of an element type, e.g. `INT MULTISET`
+    Multiset(Box<DataType>),
     /// Map
     ///
     /// [clickhouse]: https://clickhouse.com/docs/en/sql-reference/data-types/map
@@ -482,6 +484,7 @@ impl fmt::Display for DataType {
                 ArrayElemTypeDef::AngleBracket(t) => write!(f, "ARRAY<{t}>"),
                 ArrayElemTypeDef::Parenthesis(t) => write!(f, "Array({t})"),
             },
+            DataType::Multiset(t) => write!(f, "{t} MULTISET"),
             DataType::Custom(ty, modifiers) => {
                 if modifiers.is_empty() {
                     write!(f, "{ty}")
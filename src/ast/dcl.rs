@@ -14,7 +14,7 @@
 //! (commonly referred to as Data Control Language, or DCL)
 
 #[cfg(not(feature = "std"))]
-use alloc::vec::Vec;
+use alloc::{boxed::Box, vec::Vec};
 use core::fmt;
 
 #[cfg(feature = "serde")]
@@ -24,7 +24,7 @@ use serde::{Deserialize, Serialize};
 use sqlparser_derive::{Visit, VisitMut};
 
 use super::{Expr, Ident, Password};
-use crate::ast::{display_separated, ObjectName};
+use crate::ast::{display_comma_separated, display_separated, ObjectName};
 
 /// An option in `ROLE` statement.
 ///
@@ -91,12 +91,51 @@ impl fmt::Display for RoleOption {
     }
 }
 
+/// An option in `CREATE ROLE` statement: either a [`RoleOption`] shared with
+/// `ALTER ROLE`, or a role membership that can only be given on creation.
+///
+/// <https://www.postgresql.org/docs/current/sql-createrole.html>
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub enum CreateRoleOption {
+    Option(Box<RoleOption>),
+    /// `IN ROLE role_name [, ...]`
+    InRole(Vec<Ident>),
+    /// `IN GROUP role_name [, ...]`
+    InGroup(Vec<Ident>),
+    /// `ROLE role_name [, ...]`
+    Role(Vec<Ident>),
+    /// `USER role_name [, ...]`
+    User(Vec<Ident>),
+    /// `ADMIN role_name [, ...]`
+    Admin(Vec<Ident>),
+}
+
+impl fmt::Display for CreateRoleOption {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CreateRoleOption::Option(option) => write!(f, "{option}"),
+            CreateRoleOption::InRole(roles) => {
+                write!(f, "IN ROLE {}", display_comma_separated(roles))
+            }
+            CreateRoleOption::InGroup(roles) => {
+                write!(f, "IN GROUP {}", display_comma_separated(roles))
+            }
+            CreateRoleOption::Role(roles) => write!(f, "ROLE {}", display_comma_separated(roles)),
+            CreateRoleOption::User(roles) => write!(f, "USER {}", display_comma_separated(roles)),
+            CreateRoleOption::Admin(roles) => write!(f, "ADMIN {}", display_comma_separated(roles)),
+        }
+    }
+}
+
 /// SET config value option:
 /// * SET `configuration_parameter` { TO | = } { `value` | DEFAULT }
 /// * SET `configuration_parameter` FROM CURRENT
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+#[allow(clippy::large_enum_variant)]
 pub enum SetConfigValue {
     Default,
     FromCurrent,
@@ -118,6 +157,7 @@ pub enum ResetConfig {
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+#[allow(clippy::large_enum_variant)]
 pub enum AlterRoleOperation {
     /// Generic
     RenameRole {
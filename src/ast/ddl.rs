@@ -379,7 +379,7 @@ impl fmt::Display for AlterColumnOperation {
             AlterColumnOperation::SetDefault { value } => {
                 write!(f, "SET DEFAULT {value}")
             }
-            AlterColumnOperation::DropDefault {} => {
+            AlterColumnOperation::DropDefault => {
                 write!(f, "DROP DEFAULT")
             }
             AlterColumnOperation::SetDataType { data_type, using } => {
@@ -486,7 +486,7 @@ pub enum TableConstraint {
         characteristics: Option<ConstraintCharacteristics>,
     },
     /// A referential integrity constraint (`[ CONSTRAINT <name> ] FOREIGN KEY (<columns>)
-    /// REFERENCES <foreign_table> (<referred_columns>)
+    /// REFERENCES <foreign_table> (<referred_columns>) [MATCH <match_kind>]
     /// { [ON DELETE <referential_action>] [ON UPDATE <referential_action>] |
     ///   [ON UPDATE <referential_action>] [ON DELETE <referential_action>]
     /// }`).
@@ -495,14 +495,19 @@ pub enum TableConstraint {
         columns: Vec<Ident>,
         foreign_table: ObjectName,
         referred_columns: Vec<Ident>,
+        match_kind: Option<ConstraintReferenceMatchKind>,
         on_delete: Option<ReferentialAction>,
         on_update: Option<ReferentialAction>,
+        /// `ON UPDATE` was given before `ON DELETE`
+        on_update_first: bool,
         characteristics: Option<ConstraintCharacteristics>,
     },
     /// `[ CONSTRAINT <name> ] CHECK (<expr>)`
     Check {
         name: Option<Ident>,
         expr: Box<Expr>,
+        /// `NO INHERIT` (PostgreSQL)
+        no_inherit: bool,
     },
     /// MySQLs [index definition][1] for index creation. Not present on ANSI so, for now, the usage
     /// is restricted to MySQL, as no other dialects that support this syntax were found.
@@ -604,31 +609,47 @@ impl fmt::Display for TableConstraint {
                 columns,
                 foreign_table,
                 referred_columns,
+                match_kind,
                 on_delete,
                 on_update,
+                on_update_first,
                 characteristics,
             } => {
                 write!(
                     f,
-                    "{}FOREIGN KEY ({}) REFERENCES {}({})",
+                    "{}FOREIGN KEY ({}) REFERENCES {}",
                     display_constraint_name(name),
                     display_comma_separated(columns),
                     foreign_table,
-                    display_comma_separated(referred_columns),
                 )?;
-                if let Some(action) = on_delete {
-                    write!(f, " ON DELETE {action}")?;
+                if !referred_columns.is_empty() {
+                    write!(f, "({})", display_comma_separated(referred_columns))?;
+                }
+                if let Some(match_kind) = match_kind {
+                    write!(f, " MATCH {match_kind}")?;
                 }
-                if let Some(action) = on_update {
-                    write!(f, " ON UPDATE {action}")?;
+                let on_delete = display_option(" ON DELETE ", "", on_delete);
+                let on_update = display_option(" ON UPDATE ", "", on_update);
+                if *on_update_first {
+                    write!(f, "{on_update}{on_delete}")?;
+                } else {
+                    write!(f, "{on_delete}{on_update}")?;
                 }
                 if let Some(characteristics) = characteristics {
                     write!(f, " {}", characteristics)?;
                 }
                 Ok(())
             }
-            TableConstraint::Check { name, expr } => {
-                write!(f, "{}CHECK ({})", display_constraint_name(name), expr)
+            TableConstraint::Check {
+                name,
+                expr,
+                no_inherit,
+            } => {
+                write!(f, "{}CHECK ({})", display_constraint_name(name), expr)?;
+                if *no_inherit {
+                    write!(f, " NO INHERIT")?;
+                }
+                Ok(())
             }
             TableConstraint::Index {
                 display_as_key,
@@ -852,6 +873,29 @@ impl fmt::Display for ViewColumnDef {
     }
 }
 
+/// `WITH [ CASCADED | LOCAL ] CHECK OPTION` on a `CREATE VIEW`
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub enum CheckOption {
+    /// `WITH CHECK OPTION`, which behaves as `CASCADED`
+    Unqualified,
+    /// `WITH CASCADED CHECK OPTION`
+    Cascaded,
+    /// `WITH LOCAL CHECK OPTION`
+    Local,
+}
+
+impl fmt::Display for CheckOption {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CheckOption::Unqualified => write!(f, "WITH CHECK OPTION"),
+            CheckOption::Cascaded => write!(f, "WITH CASCADED CHECK OPTION"),
+            CheckOption::Local => write!(f, "WITH LOCAL CHECK OPTION"),
+        }
+    }
+}
+
 /// An optionally-named `ColumnOption`: `[ CONSTRAINT <name> ] <column-option>`.
 ///
 /// Note that implementations are substantially more permissive than the ANSI
@@ -900,7 +944,7 @@ pub enum ColumnOption {
         characteristics: Option<ConstraintCharacteristics>,
     },
     /// A referential integrity constraint (`[FOREIGN KEY REFERENCES
-    /// <foreign_table> (<referred_columns>)
+    /// <foreign_table> (<referred_columns>) [MATCH <match_kind>]
     /// { [ON DELETE <referential_action>] [ON UPDATE <referential_action>] |
     ///   [ON UPDATE <referential_action>] [ON DELETE <referential_action>]
     /// }
@@ -909,12 +953,19 @@ pub enum ColumnOption {
     ForeignKey {
         foreign_table: ObjectName,
         referred_columns: Vec<Ident>,
+        match_kind: Option<ConstraintReferenceMatchKind>,
         on_delete: Option<ReferentialAction>,
         on_update: Option<ReferentialAction>,
+        /// `ON UPDATE` was given before `ON DELETE`
+        on_update_first: bool,
         characteristics: Option<ConstraintCharacteristics>,
     },
-    /// `CHECK (<expr>)`
-    Check(Expr),
+    /// `CHECK (<expr>) [ NO INHERIT ]`
+    Check {
+        expr: Expr,
+        /// `NO INHERIT` (PostgreSQL)
+        no_inherit: bool,
+    },
     /// Dialect-specific options, such as:
     /// - MySQL's `AUTO_INCREMENT` or SQLite's `AUTOINCREMENT`
     /// - ...
@@ -940,6 +991,20 @@ pub enum ColumnOption {
     /// [1]: https://cloud.google.com/bigquery/docs/reference/standard-sql/data-definition-language#view_column_option_list
     /// [2]: https://cloud.google.com/bigquery/docs/reference/standard-sql/data-definition-language#column_option_list
     Options(Vec<SqlOption>),
+    /// Redshift specific: column compression encoding
+    /// Syntax
+    /// ```sql
+    /// ENCODE az64
+    /// ```
+    /// [1]: https://docs.aws.amazon.com/redshift/latest/dg/c_Compression_encodings.html
+    Encode(Ident),
+    /// MySQL specific: column visibility, `true` for `VISIBLE` and `false` for `INVISIBLE`
+    /// Syntax
+    /// ```sql
+    /// { VISIBLE | INVISIBLE }
+    /// ```
+    /// [1]: https://dev.mysql.com/doc/refman/8.0/en/invisible-columns.html
+    Visible(bool),
 }
 
 impl fmt::Display for ColumnOption {
@@ -962,26 +1027,38 @@ impl fmt::Display for ColumnOption {
             ForeignKey {
                 foreign_table,
                 referred_columns,
+                match_kind,
                 on_delete,
                 on_update,
+                on_update_first,
                 characteristics,
             } => {
                 write!(f, "REFERENCES {foreign_table}")?;
                 if !referred_columns.is_empty() {
                     write!(f, " ({})", display_comma_separated(referred_columns))?;
                 }
-                if let Some(action) = on_delete {
-                    write!(f, " ON DELETE {action}")?;
+                if let Some(match_kind) = match_kind {
+                    write!(f, " MATCH {match_kind}")?;
                 }
-                if let Some(action) = on_update {
-                    write!(f, " ON UPDATE {action}")?;
+                let on_delete = display_option(" ON DELETE ", "", on_delete);
+                let on_update = display_option(" ON UPDATE ", "", on_update);
+                if *on_update_first {
+                    write!(f, "{on_update}{on_delete}")?;
+                } else {
+                    write!(f, "{on_delete}{on_update}")?;
                 }
                 if let Some(characteristics) = characteristics {
                     write!(f, " {}", characteristics)?;
                 }
                 Ok(())
             }
-            Check(expr) => write!(f, "CHECK ({expr})"),
+            Check { expr, no_inherit } => {
+                write!(f, "CHECK ({expr})")?;
+                if *no_inherit {
+                    write!(f, " NO INHERIT")?;
+                }
+                Ok(())
+            }
             DialectSpecific(val) => write!(f, "{}", display_separated(val, " ")),
             CharacterSet(n) => write!(f, "CHARACTER SET {n}"),
             Comment(v) => write!(f, "COMMENT '{}'", escape_single_quote_string(v)),
@@ -1032,6 +1109,255 @@ impl fmt::Display for ColumnOption {
             Options(options) => {
                 write!(f, "OPTIONS({})", display_comma_separated(options))
             }
+            Encode(encoding) => write!(f, "ENCODE {encoding}"),
+            Visible(visible) => {
+                write!(f, "{}", if *visible { "VISIBLE" } else { "INVISIBLE" })
+            }
+        }
+    }
+}
+
+/// Redshift table distribution style, `DISTSTYLE { AUTO | EVEN | KEY | ALL }`.
+///
+/// <https://docs.aws.amazon.com/redshift/latest/dg/r_CREATE_TABLE_NEW.html>
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub enum DistStyle {
+    Auto,
+    Even,
+    Key,
+    All,
+}
+
+impl fmt::Display for DistStyle {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            DistStyle::Auto => "AUTO",
+            DistStyle::Even => "EVEN",
+            DistStyle::Key => "KEY",
+            DistStyle::All => "ALL",
+        })
+    }
+}
+
+/// Redshift table sort key, `[ COMPOUND | INTERLEAVED ] SORTKEY ( column [, ...] )`.
+///
+/// <https://docs.aws.amazon.com/redshift/latest/dg/r_CREATE_TABLE_NEW.html>
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub struct SortKey {
+    /// `COMPOUND` or `INTERLEAVED`, if specified
+    pub kind: Option<SortKeyKind>,
+    pub columns: Vec<Ident>,
+}
+
+impl fmt::Display for SortKey {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if let Some(kind) = &self.kind {
+            write!(f, "{kind} ")?;
+        }
+        write!(f, "SORTKEY({})", display_comma_separated(&self.columns))
+    }
+}
+
+/// The kind of a Redshift [`SortKey`]
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub enum SortKeyKind {
+    Compound,
+    Interleaved,
+}
+
+impl fmt::Display for SortKeyKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            SortKeyKind::Compound => "COMPOUND",
+            SortKeyKind::Interleaved => "INTERLEAVED",
+        })
+    }
+}
+
+/// `LIKE` clause of `CREATE TABLE`, copying the definition of other tables.
+///
+/// <https://www.postgresql.org/docs/current/sql-createtable.html>
+/// <https://dev.mysql.com/doc/refman/8.0/en/create-table-like.html>
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub enum CreateTableLikeKind {
+    /// `CREATE TABLE t LIKE src`
+    Plain(CreateTableLike),
+    /// `LIKE src ...` elements of the column list, e.g. `CREATE TABLE t (LIKE src, extra INT)`
+    Parenthesized(Vec<CreateTableLikeElement>),
+}
+
+/// `LIKE <table>` copying the definition of another table
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub struct CreateTableLike {
+    pub name: ObjectName,
+    /// PostgreSQL `{ INCLUDING | EXCLUDING } <property>` options
+    pub options: Vec<CreateTableLikeOption>,
+}
+
+impl fmt::Display for CreateTableLike {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "LIKE {}", self.name)?;
+        if !self.options.is_empty() {
+            write!(f, " {}", display_separated(&self.options, " "))?;
+        }
+        Ok(())
+    }
+}
+
+/// A `LIKE <table>` element of a `CREATE TABLE` column list
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub struct CreateTableLikeElement {
+    /// Number of column definitions preceding the element in the column list
+    pub column_index: usize,
+    pub like: CreateTableLike,
+}
+
+/// `{ INCLUDING | EXCLUDING } <property>` option of a [`CreateTableLike`],
+/// e.g. `INCLUDING DEFAULTS`
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub struct CreateTableLikeOption {
+    pub including: bool,
+    pub property: Ident,
+}
+
+impl fmt::Display for CreateTableLikeOption {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let action = if self.including {
+            "INCLUDING"
+        } else {
+            "EXCLUDING"
+        };
+        write!(f, "{action} {}", self.property)
+    }
+}
+
+/// PostgreSQL declarative partitioning of a table,
+/// `PARTITION BY { RANGE | LIST | HASH } ( <key> [, ...] )`.
+///
+/// <https://www.postgresql.org/docs/current/sql-createtable.html>
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub struct TablePartitioning {
+    pub method: PartitionMethod,
+    pub keys: Vec<Expr>,
+}
+
+impl fmt::Display for TablePartitioning {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "PARTITION BY {} ({})",
+            self.method,
+            display_comma_separated(&self.keys)
+        )
+    }
+}
+
+/// The partitioning method of a [`TablePartitioning`]
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub enum PartitionMethod {
+    Range,
+    List,
+    Hash,
+}
+
+impl fmt::Display for PartitionMethod {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            PartitionMethod::Range => "RANGE",
+            PartitionMethod::List => "LIST",
+            PartitionMethod::Hash => "HASH",
+        })
+    }
+}
+
+/// PostgreSQL `PARTITION OF <parent> <bound>` clause of `CREATE TABLE`.
+///
+/// The parent is written before the optional column list and the bound after it.
+///
+/// <https://www.postgresql.org/docs/current/sql-createtable.html>
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub struct PartitionOf {
+    pub parent: ObjectName,
+    pub bound: PartitionBound,
+}
+
+/// The bound of a PostgreSQL partition created with `CREATE TABLE ... PARTITION OF`.
+///
+/// <https://www.postgresql.org/docs/current/sql-createtable.html>
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub enum PartitionBound {
+    /// `FOR VALUES IN (<expr> [, ...])`
+    In(Vec<Expr>),
+    /// `FOR VALUES FROM (<value> [, ...]) TO (<value> [, ...])`
+    FromTo {
+        from: Vec<PartitionRangeValue>,
+        to: Vec<PartitionRangeValue>,
+    },
+    /// `FOR VALUES WITH (MODULUS <modulus>, REMAINDER <remainder>)`
+    WithModulus { modulus: u64, remainder: u64 },
+    /// `DEFAULT`
+    Default,
+}
+
+impl fmt::Display for PartitionBound {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            PartitionBound::In(values) => {
+                write!(f, "FOR VALUES IN ({})", display_comma_separated(values))
+            }
+            PartitionBound::FromTo { from, to } => write!(
+                f,
+                "FOR VALUES FROM ({}) TO ({})",
+                display_comma_separated(from),
+                display_comma_separated(to)
+            ),
+            PartitionBound::WithModulus { modulus, remainder } => write!(
+                f,
+                "FOR VALUES WITH (MODULUS {modulus}, REMAINDER {remainder})"
+            ),
+            PartitionBound::Default => write!(f, "DEFAULT"),
+        }
+    }
+}
+
+/// A value of a range [`PartitionBound`]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub enum PartitionRangeValue {
+    Expr(Box<Expr>),
+    MinValue,
+    MaxValue,
+}
+
+impl fmt::Display for PartitionRangeValue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            PartitionRangeValue::Expr(expr) => write!(f, "{expr}"),
+            PartitionRangeValue::MinValue => write!(f, "MINVALUE"),
+            PartitionRangeValue::MaxValue => write!(f, "MAXVALUE"),
         }
     }
 }
@@ -1206,14 +1532,39 @@ impl fmt::Display for ReferentialAction {
     }
 }
 
+/// `MATCH` type of a foreign key reference
+///
+/// See [PostgreSQL](https://www.postgresql.org/docs/current/sql-createtable.html)
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub enum ConstraintReferenceMatchKind {
+    Full,
+    Partial,
+    Simple,
+}
+
+impl fmt::Display for ConstraintReferenceMatchKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            ConstraintReferenceMatchKind::Full => "FULL",
+            ConstraintReferenceMatchKind::Partial => "PARTIAL",
+            ConstraintReferenceMatchKind::Simple => "SIMPLE",
+        })
+    }
+}
+
 /// SQL user defined type definition
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum UserDefinedTypeRepresentation {
+    /// `AS (<attribute> <data_type> [, ...])`
     Composite {
         attributes: Vec<UserDefinedTypeCompositeAttributeDef>,
     },
+    /// `AS ENUM ('<label>' [, ...])`
+    Enum { labels: Vec<String> },
 }
 
 impl fmt::Display for UserDefinedTypeRepresentation {
@@ -1222,6 +1573,16 @@ impl fmt::Display for UserDefinedTypeRepresentation {
             UserDefinedTypeRepresentation::Composite { attributes } => {
                 write!(f, "({})", display_comma_separated(attributes))
             }
+            UserDefinedTypeRepresentation::Enum { labels } => {
+                write!(f, "ENUM (")?;
+                for (i, label) in labels.iter().enumerate() {
+                    if i != 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "'{}'", escape_single_quote_string(label))?;
+                }
+                write!(f, ")")
+            }
         }
     }
 }
@@ -22,10 +22,11 @@ use sqlparser_derive::{Visit, VisitMut};
 pub use super::ddl::{ColumnDef, TableConstraint};
 
 use super::{
-    display_comma_separated, display_separated, CommentDef, Expr, FileFormat, FromTable,
-    HiveDistributionStyle, HiveFormat, HiveIOFormat, HiveRowFormat, Ident, InsertAliases,
-    MysqlInsertPriority, ObjectName, OnCommit, OnInsert, OneOrManyWithParens, OrderByExpr, Query,
-    RowAccessPolicy, SelectItem, SqlOption, SqliteOnConflict, TableEngine, TableWithJoins, Tag,
+    display_comma_separated, display_separated, Assignment, CommentDef, CreateTableLikeKind,
+    DistStyle, Expr, FileFormat, FromTable, HiveDistributionStyle, HiveFormat, HiveIOFormat,
+    HiveRowFormat, Ident, InsertAliases, MysqlInsertPriority, ObjectName, OnCommit, OnInsert,
+    OneOrManyWithParens, OrderByExpr, PartitionOf, Query, RowAccessPolicy, SelectItem, SortKey,
+    SqlOption, SqliteOnConflict, TableEngine, TablePartitioning, TableWithJoins, Tag,
     WrappedCollection,
 };
 
@@ -72,9 +73,22 @@ pub struct CreateTable {
     pub file_format: Option<FileFormat>,
     pub location: Option<String>,
     pub query: Option<Box<Query>>,
+    /// Column names given to the result of the query, as in
+    /// `CREATE TABLE t (a, b) AS SELECT ...`
+    pub column_names: Vec<Ident>,
+    /// PostgreSQL `WITH [ NO ] DATA` following the query
+    /// <https://www.postgresql.org/docs/current/sql-createtableas.html>
+    pub with_data: Option<bool>,
     pub without_rowid: bool,
-    pub like: Option<ObjectName>,
+    pub like: Option<CreateTableLikeKind>,
     pub clone: Option<ObjectName>,
+    /// PostgreSQL "PARTITION OF" clause naming the parent table and the partition bound
+    /// <https://www.postgresql.org/docs/current/sql-createtable.html>
+    pub partition_of: Option<PartitionOf>,
+    /// PostgreSQL "INHERITS" clause listing the parent tables
+    pub inherits: Option<Vec<ObjectName>>,
+    /// PostgreSQL "PARTITION BY { RANGE | LIST | HASH }" clause
+    pub partitioning: Option<TablePartitioning>,
     pub engine: Option<TableEngine>,
     pub comment: Option<CommentDef>,
     pub auto_increment_offset: Option<u32>,
@@ -131,6 +145,15 @@ pub struct CreateTable {
     /// Snowflake "WITH TAG" clause
     /// <https://docs.snowflake.com/en/sql-reference/sql/create-table>
     pub with_tags: Option<Vec<Tag>>,
+    /// Redshift "DISTSTYLE" clause
+    /// <https://docs.aws.amazon.com/redshift/latest/dg/r_CREATE_TABLE_NEW.html>
+    pub dist_style: Option<DistStyle>,
+    /// Redshift "DISTKEY" clause
+    /// <https://docs.aws.amazon.com/redshift/latest/dg/r_CREATE_TABLE_NEW.html>
+    pub dist_key: Option<Ident>,
+    /// Redshift "SORTKEY" clause
+    /// <https://docs.aws.amazon.com/redshift/latest/dg/r_CREATE_TABLE_NEW.html>
+    pub sort_key: Option<SortKey>,
 }
 
 impl Display for CreateTable {
@@ -169,24 +192,64 @@ impl Display for CreateTable {
                 on_cluster.replace('{', "'{").replace('}', "}'")
             )?;
         }
-        if !self.columns.is_empty() || !self.constraints.is_empty() {
-            write!(f, " ({}", display_comma_separated(&self.columns))?;
-            if !self.columns.is_empty() && !self.constraints.is_empty() {
-                write!(f, ", ")?;
+        if let Some(partition_of) = &self.partition_of {
+            write!(f, " PARTITION OF {}", partition_of.parent)?;
+        }
+        let like_elements = match &self.like {
+            Some(CreateTableLikeKind::Parenthesized(elements)) => elements.as_slice(),
+            _ => &[],
+        };
+        if !self.column_names.is_empty() {
+            write!(f, " ({})", display_comma_separated(&self.column_names))?;
+        } else if !self.columns.is_empty()
+            || !self.constraints.is_empty()
+            || !like_elements.is_empty()
+        {
+            // `LIKE` elements are printed in between the columns they were parsed with
+            let mut like_elements = like_elements.iter().peekable();
+            let mut delim = "";
+            write!(f, " (")?;
+            for (index, column) in self.columns.iter().enumerate() {
+                while let Some(element) = like_elements.next_if(|e| e.column_index <= index) {
+                    write!(f, "{delim}{}", element.like)?;
+                    delim = ", ";
+                }
+                write!(f, "{delim}{column}")?;
+                delim = ", ";
+            }
+            for element in like_elements {
+                write!(f, "{delim}{}", element.like)?;
+                delim = ", ";
+            }
+            for constraint in &self.constraints {
+                write!(f, "{delim}{constraint}")?;
+                delim = ", ";
             }
-            write!(f, "{})", display_comma_separated(&self.constraints))?;
-        } else if self.query.is_none() && self.like.is_none() && self.clone.is_none() {
+            write!(f, ")")?;
+        } else if self.query.is_none()
+            && self.like.is_none()
+            && self.clone.is_none()
+            && self.partition_of.is_none()
+        {
             // PostgreSQL allows `CREATE TABLE t ();`, but requires empty parens
             write!(f, " ()")?;
         }
+        if let Some(partition_of) = &self.partition_of {
+            write!(f, " {}", partition_of.bound)?;
+        }
         // Only for SQLite
         if self.without_rowid {
             write!(f, " WITHOUT ROWID")?;
         }
+        if let Some(inherits) = &self.inherits {
+            write!(f, " INHERITS ({})", display_comma_separated(inherits))?;
+        }
+        if let Some(partitioning) = &self.partitioning {
+            write!(f, " {partitioning}")?;
+        }
 
-        // Only for Hive
-        if let Some(l) = &self.like {
-            write!(f, " LIKE {l}")?;
+        if let Some(CreateTableLikeKind::Plain(like)) = &self.like {
+            write!(f, " {like}")?;
         }
 
         if let Some(c) = &self.clone {
@@ -275,7 +338,9 @@ impl Display for CreateTable {
             if let Some(file_format) = self.file_format {
                 write!(f, " STORED AS {file_format}")?;
             }
-            write!(f, " LOCATION '{}'", self.location.as_ref().unwrap())?;
+            if let Some(location) = &self.location {
+                write!(f, " LOCATION '{location}'")?;
+            }
         }
         if !self.table_properties.is_empty() {
             write!(
@@ -310,6 +375,15 @@ impl Display for CreateTable {
         if let Some(order_by) = &self.order_by {
             write!(f, " ORDER BY {}", order_by)?;
         }
+        if let Some(dist_style) = &self.dist_style {
+            write!(f, " DISTSTYLE {dist_style}")?;
+        }
+        if let Some(dist_key) = &self.dist_key {
+            write!(f, " DISTKEY({dist_key})")?;
+        }
+        if let Some(sort_key) = &self.sort_key {
+            write!(f, " {sort_key}")?;
+        }
         if let Some(partition_by) = self.partition_by.as_ref() {
             write!(f, " PARTITION BY {partition_by}")?;
         }
@@ -378,6 +452,11 @@ impl Display for CreateTable {
         if let Some(query) = &self.query {
             write!(f, " AS {query}")?;
         }
+        match self.with_data {
+            Some(true) => write!(f, " WITH DATA")?,
+            Some(false) => write!(f, " WITH NO DATA")?,
+            None => (),
+        }
         if let Some(default_charset) = &self.default_charset {
             write!(f, " DEFAULT CHARSET={default_charset}")?;
         }
@@ -432,12 +511,16 @@ pub struct Insert {
     pub on: Option<OnInsert>,
     /// RETURNING
     pub returning: Option<Vec<SelectItem>>,
-    /// Only for mysql
+    /// `RETURNING ... INTO` targets (PL/pgSQL)
+    pub returning_into: Option<ReturningInto>,
+    /// `REPLACE [INTO]` rather than `INSERT` (MySQL, SQLite)
     pub replace_into: bool,
     /// Only for mysql
     pub priority: Option<MysqlInsertPriority>,
     /// Only for mysql
     pub insert_alias: Option<InsertAliases>,
+    /// `SET` assignments used in place of a source (MySQL)
+    pub assignments: Vec<Assignment>,
 }
 
 /// DELETE statement.
@@ -455,8 +538,33 @@ pub struct Delete {
     pub selection: Option<Expr>,
     /// RETURNING
     pub returning: Option<Vec<SelectItem>>,
+    /// `RETURNING ... INTO` targets (PL/pgSQL)
+    pub returning_into: Option<ReturningInto>,
     /// ORDER BY (MySQL)
     pub order_by: Vec<OrderByExpr>,
     /// LIMIT (MySQL)
     pub limit: Option<Expr>,
 }
+
+/// `INTO [ STRICT ] target [, ...]` following the `RETURNING` list of a
+/// PL/pgSQL `INSERT`, `UPDATE` or `DELETE`
+///
+/// <https://www.postgresql.org/docs/current/plpgsql-statements.html#PLPGSQL-STATEMENTS-SQL-ONEROW>
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub struct ReturningInto {
+    /// `STRICT`, requiring exactly one returned row
+    pub strict: bool,
+    pub targets: Vec<Ident>,
+}
+
+impl Display for ReturningInto {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "INTO ")?;
+        if self.strict {
+            write!(f, "STRICT ")?;
+        }
+        write!(f, "{}", display_comma_separated(&self.targets))
+    }
+}
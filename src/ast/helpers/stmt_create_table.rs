@@ -9,9 +9,10 @@ use sqlparser_derive::{Visit, VisitMut};
 
 use super::super::dml::CreateTable;
 use crate::ast::{
-    ColumnDef, CommentDef, Expr, FileFormat, HiveDistributionStyle, HiveFormat, Ident, ObjectName,
-    OnCommit, OneOrManyWithParens, Query, RowAccessPolicy, SqlOption, Statement, TableConstraint,
-    TableEngine, Tag, WrappedCollection,
+    ColumnDef, CommentDef, CreateTableLikeKind, DistStyle, Expr, FileFormat, HiveDistributionStyle,
+    HiveFormat, Ident, ObjectName, OnCommit, OneOrManyWithParens, PartitionOf, Query,
+    RowAccessPolicy, SortKey, SqlOption, Statement, TableConstraint, TableEngine,
+    TablePartitioning, Tag, WrappedCollection,
 };
 use crate::parser::ParserError;
 
@@ -64,9 +65,14 @@ pub struct CreateTableBuilder {
     pub file_format: Option<FileFormat>,
     pub location: Option<String>,
     pub query: Option<Box<Query>>,
+    pub column_names: Vec<Ident>,
+    pub with_data: Option<bool>,
     pub without_rowid: bool,
-    pub like: Option<ObjectName>,
+    pub like: Option<CreateTableLikeKind>,
     pub clone: Option<ObjectName>,
+    pub partition_of: Option<PartitionOf>,
+    pub inherits: Option<Vec<ObjectName>>,
+    pub partitioning: Option<TablePartitioning>,
     pub engine: Option<TableEngine>,
     pub comment: Option<CommentDef>,
     pub auto_increment_offset: Option<u32>,
@@ -89,6 +95,9 @@ pub struct CreateTableBuilder {
     pub with_aggregation_policy: Option<ObjectName>,
     pub with_row_access_policy: Option<RowAccessPolicy>,
     pub with_tags: Option<Vec<Tag>>,
+    pub dist_style: Option<DistStyle>,
+    pub dist_key: Option<Ident>,
+    pub sort_key: Option<SortKey>,
 }
 
 impl CreateTableBuilder {
@@ -111,9 +120,14 @@ impl CreateTableBuilder {
             file_format: None,
             location: None,
             query: None,
+            column_names: vec![],
+            with_data: None,
             without_rowid: false,
             like: None,
             clone: None,
+            partition_of: None,
+            inherits: None,
+            partitioning: None,
             engine: None,
             comment: None,
             auto_increment_offset: None,
@@ -136,6 +150,9 @@ impl CreateTableBuilder {
             with_aggregation_policy: None,
             with_row_access_policy: None,
             with_tags: None,
+            dist_style: None,
+            dist_key: None,
+            sort_key: None,
         }
     }
     pub fn or_replace(mut self, or_replace: bool) -> Self {
@@ -215,12 +232,22 @@ impl CreateTableBuilder {
         self.query = query;
         self
     }
+
+    pub fn column_names(mut self, column_names: Vec<Ident>) -> Self {
+        self.column_names = column_names;
+        self
+    }
+
+    pub fn with_data(mut self, with_data: Option<bool>) -> Self {
+        self.with_data = with_data;
+        self
+    }
     pub fn without_rowid(mut self, without_rowid: bool) -> Self {
         self.without_rowid = without_rowid;
         self
     }
 
-    pub fn like(mut self, like: Option<ObjectName>) -> Self {
+    pub fn like(mut self, like: Option<CreateTableLikeKind>) -> Self {
         self.like = like;
         self
     }
@@ -231,6 +258,21 @@ impl CreateTableBuilder {
         self
     }
 
+    pub fn partition_of(mut self, partition_of: Option<PartitionOf>) -> Self {
+        self.partition_of = partition_of;
+        self
+    }
+
+    pub fn inherits(mut self, inherits: Option<Vec<ObjectName>>) -> Self {
+        self.inherits = inherits;
+        self
+    }
+
+    pub fn partitioning(mut self, partitioning: Option<TablePartitioning>) -> Self {
+        self.partitioning = partitioning;
+        self
+    }
+
     pub fn engine(mut self, engine: Option<TableEngine>) -> Self {
         self.engine = engine;
         self
@@ -347,6 +389,21 @@ impl CreateTableBuilder {
         self
     }
 
+    pub fn dist_style(mut self, dist_style: Option<DistStyle>) -> Self {
+        self.dist_style = dist_style;
+        self
+    }
+
+    pub fn dist_key(mut self, dist_key: Option<Ident>) -> Self {
+        self.dist_key = dist_key;
+        self
+    }
+
+    pub fn sort_key(mut self, sort_key: Option<SortKey>) -> Self {
+        self.sort_key = sort_key;
+        self
+    }
+
     pub fn build(self) -> Statement {
         Statement::CreateTable(CreateTable {
             or_replace: self.or_replace,
@@ -366,9 +423,14 @@ impl CreateTableBuilder {
             file_format: self.file_format,
             location: self.location,
             query: self.query,
+            column_names: self.column_names,
+            with_data: self.with_data,
             without_rowid: self.without_rowid,
             like: self.like,
             clone: self.clone,
+            partition_of: self.partition_of,
+            inherits: self.inherits,
+            partitioning: self.partitioning,
             engine: self.engine,
             comment: self.comment,
             auto_increment_offset: self.auto_increment_offset,
@@ -391,6 +453,9 @@ impl CreateTableBuilder {
             with_aggregation_policy: self.with_aggregation_policy,
             with_row_access_policy: self.with_row_access_policy,
             with_tags: self.with_tags,
+            dist_style: self.dist_style,
+            dist_key: self.dist_key,
+            sort_key: self.sort_key,
         })
     }
 }
@@ -420,9 +485,14 @@ impl TryFrom<Statement> for CreateTableBuilder {
                 file_format,
                 location,
                 query,
+                column_names,
+                with_data,
                 without_rowid,
                 like,
                 clone,
+                partition_of,
+                inherits,
+                partitioning,
                 engine,
                 comment,
                 auto_increment_offset,
@@ -445,6 +515,9 @@ impl TryFrom<Statement> for CreateTableBuilder {
                 with_aggregation_policy,
                 with_row_access_policy,
                 with_tags,
+                dist_style,
+                dist_key,
+                sort_key,
             }) => Ok(Self {
                 or_replace,
                 temporary,
@@ -462,9 +535,14 @@ impl TryFrom<Statement> for CreateTableBuilder {
                 file_format,
                 location,
                 query,
+                column_names,
+                with_data,
                 without_rowid,
                 like,
                 clone,
+                partition_of,
+                inherits,
+                partitioning,
                 engine,
                 comment,
                 auto_increment_offset,
@@ -488,6 +566,9 @@ impl TryFrom<Statement> for CreateTableBuilder {
                 with_row_access_policy,
                 with_tags,
                 volatile,
+                dist_style,
+                dist_key,
+                sort_key,
             }),
             _ => Err(ParserError::ParserError(format!(
                 "Expected create table statement, but received: {stmt}"
@@ -504,6 +585,14 @@ pub(crate) struct BigQueryTableConfiguration {
     pub options: Option<Vec<SqlOption>>,
 }
 
+/// Helper return type when parsing configuration for a Redshift `CREATE TABLE` statement.
+#[derive(Default)]
+pub(crate) struct RedshiftTableConfiguration {
+    pub dist_style: Option<DistStyle>,
+    pub dist_key: Option<Ident>,
+    pub sort_key: Option<SortKey>,
+}
+
 #[cfg(test)]
 mod tests {
     use crate::ast::helpers::stmt_create_table::CreateTableBuilder;
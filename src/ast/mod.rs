@@ -30,15 +30,20 @@ use sqlparser_derive::{Visit, VisitMut};
 pub use self::data_type::{
     ArrayElemTypeDef, CharLengthUnits, CharacterLength, DataType, ExactNumberInfo, TimezoneInfo,
 };
-pub use self::dcl::{AlterRoleOperation, ResetConfig, RoleOption, SetConfigValue};
+pub use self::dcl::{
+    AlterRoleOperation, CreateRoleOption, ResetConfig, RoleOption, SetConfigValue,
+};
 pub use self::ddl::{
-    AlterColumnOperation, AlterIndexOperation, AlterTableOperation, ColumnDef, ColumnOption,
-    ColumnOptionDef, ConstraintCharacteristics, DeferrableInitial, GeneratedAs,
-    GeneratedExpressionMode, IndexOption, IndexType, KeyOrIndexDisplay, Partition, ProcedureParam,
-    ReferentialAction, TableConstraint, UserDefinedTypeCompositeAttributeDef,
-    UserDefinedTypeRepresentation, ViewColumnDef,
+    AlterColumnOperation, AlterIndexOperation, AlterTableOperation, CheckOption, ColumnDef,
+    ColumnOption, ColumnOptionDef, ConstraintCharacteristics, ConstraintReferenceMatchKind,
+    CreateTableLike, CreateTableLikeElement, CreateTableLikeKind, CreateTableLikeOption,
+    DeferrableInitial, DistStyle, GeneratedAs, GeneratedExpressionMode, IndexOption, IndexType,
+    KeyOrIndexDisplay, Partition, PartitionBound, PartitionMethod, PartitionOf,
+    PartitionRangeValue, ProcedureParam, ReferentialAction, SortKey, SortKeyKind, TableConstraint,
+    TablePartitioning, UserDefinedTypeCompositeAttributeDef, UserDefinedTypeRepresentation,
+    ViewColumnDef,
 };
-pub use self::dml::{CreateIndex, CreateTable, Delete, Insert};
+pub use self::dml::{CreateIndex, CreateTable, Delete, Insert, ReturningInto};
 pub use self::operator::{BinaryOperator, UnaryOperator};
 pub use self::query::{
     AfterMatchSkip, ConnectBy, Cte, CteAsMaterialized, Distinct, EmptyMatchesMode,
@@ -46,11 +51,11 @@ pub use self::query::{
     GroupByExpr, IdentWithAlias, IlikeSelectItem, Join, JoinConstraint, JoinOperator,
     JsonTableColumn, JsonTableColumnErrorHandling, LateralView, LockClause, LockType,
     MatchRecognizePattern, MatchRecognizeSymbol, Measure, NamedWindowDefinition, NamedWindowExpr,
-    NonBlock, Offset, OffsetRows, OrderByExpr, PivotValueSource, Query, RenameSelectItem,
-    RepetitionQuantifier, ReplaceSelectElement, ReplaceSelectItem, RowsPerMatch, Select,
-    SelectInto, SelectItem, SetExpr, SetOperator, SetQuantifier, SymbolDefinition, Table,
-    TableAlias, TableFactor, TableVersion, TableWithJoins, Top, TopQuantity, ValueTableMode,
-    Values, WildcardAdditionalOptions, With,
+    NonBlock, NullInclusion, Offset, OffsetRows, OrderByExpr, PivotValueSource, Query,
+    RenameSelectItem, RepetitionQuantifier, ReplaceSelectElement, ReplaceSelectItem, RowsPerMatch,
+    Select, SelectInto, SelectItem, SetExpr, SetOperator, SetQuantifier, SymbolDefinition, Table,
+    TableAlias, TableFactor, TableVersion, TableWithJoins, TimeTravelPoint, Top, TopQuantity,
+    ValueTableMode, Values, WildcardAdditionalOptions, With,
 };
 pub use self::value::{
     escape_double_quote_string, escape_quoted_string, DateTimeField, DollarQuotedString,
@@ -166,7 +171,7 @@ impl fmt::Display for Ident {
                 let escaped = value::escape_quoted_string(&self.value, q);
                 write!(f, "{q}{escaped}{q}")
             }
-            Some('[') => write!(f, "[{}]", self.value),
+            Some('[') => write!(f, "[{}]", value::escape_quoted_string(&self.value, ']')),
             None => f.write_str(&self.value),
             _ => panic!("unexpected quote style"),
         }
@@ -311,6 +316,61 @@ impl fmt::Display for DictionaryField {
     }
 }
 
+/// A `key VALUE value` or `key : value` entry of a `JSON_OBJECT` constructor.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub struct JsonKeyValue {
+    /// Whether the entry was introduced with the optional `KEY` keyword.
+    pub explicit_key: bool,
+    pub key: Expr,
+    pub value: Expr,
+    pub separator: JsonKeyValueSeparator,
+}
+
+impl fmt::Display for JsonKeyValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.explicit_key {
+            write!(f, "KEY ")?;
+        }
+        match self.separator {
+            JsonKeyValueSeparator::Value => write!(f, "{} VALUE {}", self.key, self.value),
+            JsonKeyValueSeparator::Colon => write!(f, "{} : {}", self.key, self.value),
+        }
+    }
+}
+
+/// The token separating the key from the value in a [`JsonKeyValue`].
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub enum JsonKeyValueSeparator {
+    /// `key VALUE value`
+    Value,
+    /// `key : value`
+    Colon,
+}
+
+/// The `{ NULL | ABSENT } ON NULL` clause of the JSON constructor functions.
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub enum JsonNullClause {
+    /// `NULL ON NULL`
+    NullOnNull,
+    /// `ABSENT ON NULL`
+    AbsentOnNull,
+}
+
+impl fmt::Display for JsonNullClause {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            JsonNullClause::NullOnNull => write!(f, "NULL ON NULL"),
+            JsonNullClause::AbsentOnNull => write!(f, "ABSENT ON NULL"),
+        }
+    }
+}
+
 /// Options for `CAST` / `TRY_CAST`
 /// BigQuery: <https://cloud.google.com/bigquery/docs/reference/standard-sql/format-elements#formatting_syntax>
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
@@ -359,6 +419,7 @@ impl fmt::Display for MapAccessKey {
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+#[allow(clippy::large_enum_variant)]
 pub enum JsonPathElem {
     /// Accesses an object field using dot notation, e.g. `obj:foo.bar.baz`.
     ///
@@ -457,6 +518,9 @@ pub enum Expr {
         path: JsonPath,
     },
     /// CompositeAccess (postgres) eg: SELECT (information_schema._pg_expandarray(array['i','i'])).n
+    ///
+    /// Also used for field access on other non-identifier expressions in
+    /// BigQuery and DuckDB, e.g. `func().field` or `UNNEST(x).y`.
     CompositeAccess {
         expr: Box<Expr>,
         key: Ident,
@@ -506,6 +570,11 @@ pub enum Expr {
         low: Box<Expr>,
         high: Box<Expr>,
     },
+    /// `(<start>, <end>) OVERLAPS (<start>, <end>)`, comparing two periods
+    Overlaps {
+        left: Box<Expr>,
+        right: Box<Expr>,
+    },
     /// Binary operation e.g. `1 + 1` or `foo > bar`
     BinaryOp {
         left: Box<Expr>,
@@ -597,6 +666,19 @@ pub enum Expr {
         field: DateTimeField,
         expr: Box<Expr>,
     },
+    /// Extract a field from a timestamp, with the field given as a string,
+    /// e.g. `DATE_PART('epoch', foo)`
+    ///
+    /// Syntax:
+    /// ```sql
+    /// DATE_PART('DateTimeField', <expr>)
+    /// ```
+    ///
+    /// [PostgreSQL](https://www.postgresql.org/docs/current/functions-datetime.html#FUNCTIONS-DATETIME-EXTRACT)
+    DatePart {
+        field: DateTimeField,
+        expr: Box<Expr>,
+    },
     /// ```sql
     /// CEIL(<expr> [TO DateTimeField])
     /// ```
@@ -708,7 +790,10 @@ pub enum Expr {
     /// `SELECT (subquery) AS x` or `WHERE (subquery) = x`
     Subquery(Box<Query>),
     /// The `GROUPING SETS` expr.
-    GroupingSets(Vec<Vec<Expr>>),
+    ///
+    /// Each set is either a parenthesized, possibly empty, expression list or
+    /// a bare expression such as a column or a nested `CUBE`.
+    GroupingSets(Vec<OneOrManyWithParens<Expr>>),
     /// The `CUBE` expr.
     Cube(Vec<Vec<Expr>>),
     /// The `ROLLUP` expr.
@@ -753,6 +838,21 @@ pub enum Expr {
     },
     /// An array expression e.g. `ARRAY[1, 2]`
     Array(Array),
+    /// SQL:2016 `JSON_OBJECT` constructor e.g. `JSON_OBJECT('a' VALUE 1 ABSENT ON NULL)`
+    ///
+    /// Syntax:
+    /// ```sql
+    /// JSON_OBJECT([[KEY] <key> VALUE <value> | <key> : <value>, ...] [{NULL | ABSENT} ON NULL])
+    /// ```
+    JsonObject {
+        entries: Vec<JsonKeyValue>,
+        null_clause: Option<JsonNullClause>,
+    },
+    /// SQL:2016 `JSON_ARRAY` constructor e.g. `JSON_ARRAY(1, 2 NULL ON NULL)`
+    JsonArray {
+        values: Vec<Expr>,
+        null_clause: Option<JsonNullClause>,
+    },
     /// An interval expression e.g. `INTERVAL '1' YEAR`
     Interval(Interval),
     /// `MySQL` specific text search function [(1)].
@@ -773,6 +873,24 @@ pub enum Expr {
         /// `<search modifier>`
         opt_search_modifier: Option<SearchModifier>,
     },
+    /// `MSSQL` specific full-text predicates [(1)] [(2)].
+    ///
+    /// Syntax:
+    /// ```sql
+    /// { CONTAINS | FREETEXT } ( { <col> | (<col>, <col>, ...) | * }, <expr> [, LANGUAGE <expr>] )
+    /// ```
+    /// [(1)]: https://learn.microsoft.com/en-us/sql/t-sql/queries/contains-transact-sql
+    /// [(2)]: https://learn.microsoft.com/en-us/sql/t-sql/queries/freetext-transact-sql
+    FullTextPredicate {
+        /// `CONTAINS` or `FREETEXT`.
+        kind: FullTextPredicateKind,
+        /// The searched columns; empty for `*`.
+        columns: Vec<ObjectName>,
+        /// The search condition, usually a string literal or a variable.
+        search_condition: Box<Expr>,
+        /// `LANGUAGE <expr>`.
+        language: Option<Box<Expr>>,
+    },
     Wildcard,
     /// Qualified wildcard, e.g. `alias.*` or `schema.table.*`.
     /// (Same caveats apply to `QualifiedWildcard` as to `Wildcard`.)
@@ -809,6 +927,7 @@ pub enum Expr {
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+#[allow(clippy::large_enum_variant)]
 pub enum Subscript {
     /// Accesses the element of the array at the given index.
     Index { index: Expr },
@@ -984,7 +1103,8 @@ impl fmt::Display for Expr {
                 low,
                 high
             ),
-            Expr::BinaryOp { left, op, right } => write!(f, "({left} {op} {right})"),
+            Expr::Overlaps { left, right } => write!(f, "{left} OVERLAPS {right}"),
+            Expr::BinaryOp { left, op, right } => write!(f, "{left} {op} {right}"),
             Expr::Like {
                 negated,
                 expr,
@@ -1141,6 +1261,7 @@ impl fmt::Display for Expr {
                 }
             },
             Expr::Extract { field, expr } => write!(f, "EXTRACT({field} FROM {expr})"),
+            Expr::DatePart { field, expr } => write!(f, "DATE_PART('{field}', {expr})"),
             Expr::Ceil { expr, field } => {
                 if field == &DateTimeField::NoDateTime {
                     write!(f, "CEIL({expr})")
@@ -1192,14 +1313,7 @@ impl fmt::Display for Expr {
             ),
             Expr::Subquery(s) => write!(f, "({s})"),
             Expr::GroupingSets(sets) => {
-                write!(f, "GROUPING SETS (")?;
-                let mut sep = "";
-                for set in sets {
-                    write!(f, "{sep}")?;
-                    sep = ", ";
-                    write!(f, "({})", display_comma_separated(set))?;
-                }
-                write!(f, ")")
+                write!(f, "GROUPING SETS ({})", display_comma_separated(sets))
             }
             Expr::Cube(sets) => {
                 write!(f, "CUBE (")?;
@@ -1322,6 +1436,32 @@ impl fmt::Display for Expr {
             Expr::Array(set) => {
                 write!(f, "{set}")
             }
+            Expr::JsonObject {
+                entries,
+                null_clause,
+            } => {
+                write!(f, "JSON_OBJECT({}", display_comma_separated(entries))?;
+                if let Some(null_clause) = null_clause {
+                    if !entries.is_empty() {
+                        write!(f, " ")?;
+                    }
+                    write!(f, "{null_clause}")?;
+                }
+                write!(f, ")")
+            }
+            Expr::JsonArray {
+                values,
+                null_clause,
+            } => {
+                write!(f, "JSON_ARRAY({}", display_comma_separated(values))?;
+                if let Some(null_clause) = null_clause {
+                    if !values.is_empty() {
+                        write!(f, " ")?;
+                    }
+                    write!(f, "{null_clause}")?;
+                }
+                write!(f, ")")
+            }
             Expr::JsonAccess { value, path } => {
                 write!(f, "{value}{path}")
             }
@@ -1337,6 +1477,24 @@ impl fmt::Display for Expr {
             Expr::Interval(interval) => {
                 write!(f, "{interval}")
             }
+            Expr::FullTextPredicate {
+                kind,
+                columns,
+                search_condition,
+                language,
+            } => {
+                write!(f, "{kind}(")?;
+                match columns.as_slice() {
+                    [] => write!(f, "*")?,
+                    [column] => write!(f, "{column}")?,
+                    columns => write!(f, "({})", display_comma_separated(columns))?,
+                }
+                write!(f, ", {search_condition}")?;
+                if let Some(language) = language {
+                    write!(f, ", LANGUAGE {language}")?;
+                }
+                write!(f, ")")
+            }
             Expr::MatchAgainst {
                 columns,
                 match_value: match_expr,
@@ -1506,6 +1664,27 @@ impl fmt::Display for NullTreatment {
     }
 }
 
+/// Specifies whether `NTH_VALUE` counts rows from the start or the end
+/// of the window frame.
+/// For example
+/// `NTH_VALUE(column2, 2) FROM LAST OVER (ORDER BY column1)`
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub enum NthValueOrder {
+    FromFirst,
+    FromLast,
+}
+
+impl fmt::Display for NthValueOrder {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            NthValueOrder::FromFirst => "FROM FIRST",
+            NthValueOrder::FromLast => "FROM LAST",
+        })
+    }
+}
+
 /// Specifies [WindowFrame]'s `start_bound` and `end_bound`
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -1580,14 +1759,32 @@ impl fmt::Display for ShowCreateObject {
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum CommentObject {
     Column,
+    Database,
+    Extension,
+    Index,
+    MaterializedView,
+    Role,
+    Schema,
+    Sequence,
     Table,
+    Type,
+    View,
 }
 
 impl fmt::Display for CommentObject {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
             CommentObject::Column => f.write_str("COLUMN"),
+            CommentObject::Database => f.write_str("DATABASE"),
+            CommentObject::Extension => f.write_str("EXTENSION"),
+            CommentObject::Index => f.write_str("INDEX"),
+            CommentObject::MaterializedView => f.write_str("MATERIALIZED VIEW"),
+            CommentObject::Role => f.write_str("ROLE"),
+            CommentObject::Schema => f.write_str("SCHEMA"),
+            CommentObject::Sequence => f.write_str("SEQUENCE"),
             CommentObject::Table => f.write_str("TABLE"),
+            CommentObject::Type => f.write_str("TYPE"),
+            CommentObject::View => f.write_str("VIEW"),
         }
     }
 }
@@ -1595,6 +1792,7 @@ impl fmt::Display for CommentObject {
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+#[allow(clippy::large_enum_variant)]
 pub enum Password {
     Password(Expr),
     NullPassword,
@@ -1892,13 +2090,17 @@ pub enum Statement {
     /// ```sql
     /// TRUNCATE
     /// ```
-    /// Truncate (Hive)
+    /// Truncate (Hive, MySQL, PostgreSQL)
     Truncate {
-        #[cfg_attr(feature = "visitor", visit(with = "visit_relation"))]
-        table_name: ObjectName,
+        /// One or more tables to truncate
+        table_names: Vec<TruncateTableTarget>,
         partitions: Option<Vec<Expr>>,
         /// TABLE - optional keyword;
         table: bool,
+        /// `RESTART IDENTITY` or `CONTINUE IDENTITY` (PostgreSQL)
+        identity: Option<TruncateIdentityOption>,
+        /// `CASCADE` or `RESTRICT` (PostgreSQL)
+        cascade: Option<CascadeOption>,
     },
     /// ```sql
     /// MSCK
@@ -1954,6 +2156,8 @@ pub enum Statement {
         to: bool,
         /// The target of 'COPY TO', or the source of 'COPY FROM'
         target: CopyTarget,
+        /// Whether the options were introduced with the optional `WITH` keyword
+        with: bool,
         /// WITH options (from PostgreSQL version 9.0)
         options: Vec<CopyOption>,
         /// WITH options (before PostgreSQL version 9.0)
@@ -2003,6 +2207,8 @@ pub enum Statement {
         selection: Option<Expr>,
         /// RETURNING
         returning: Option<Vec<SelectItem>>,
+        /// `RETURNING ... INTO` targets (PL/pgSQL)
+        returning_into: Option<ReturningInto>,
     },
     /// ```sql
     /// DELETE
@@ -2029,6 +2235,10 @@ pub enum Statement {
         if_not_exists: bool,
         /// if true, has SQLite `TEMP` or `TEMPORARY` clause <https://www.sqlite.org/lang_createview.html>
         temporary: bool,
+        /// `WITH [ CASCADED | LOCAL ] CHECK OPTION`
+        with_check_option: Option<CheckOption>,
+        /// PostgreSQL `WITH [ NO ] DATA` on a materialized view
+        with_data: Option<bool>,
     },
     /// ```sql
     /// CREATE TABLE
@@ -2057,21 +2267,10 @@ pub enum Statement {
         names: Vec<ObjectName>,
         if_not_exists: bool,
         // Postgres
-        login: Option<bool>,
-        inherit: Option<bool>,
-        bypassrls: Option<bool>,
-        password: Option<Password>,
-        superuser: Option<bool>,
-        create_db: Option<bool>,
-        create_role: Option<bool>,
-        replication: Option<bool>,
-        connection_limit: Option<Expr>,
-        valid_until: Option<Expr>,
-        in_role: Vec<Ident>,
-        in_group: Vec<Ident>,
-        role: Vec<Ident>,
-        user: Vec<Ident>,
-        admin: Vec<Ident>,
+        /// Whether the options were introduced with the optional `WITH` keyword
+        with: bool,
+        /// Role options such as `LOGIN` or `IN ROLE`, in the order they were specified
+        options: Vec<CreateRoleOption>,
         // MSSQL
         authorization_owner: Option<ObjectName>,
     },
@@ -2151,11 +2350,12 @@ pub enum Statement {
         database_alias: Option<Ident>,
         attach_options: Vec<AttachDuckDBDatabaseOption>,
     },
-    /// (DuckDB-specific)
+    /// (DuckDB- and SQLite-specific)
     /// ```sql
     /// DETACH db_alias;
     /// ```
     /// See <https://duckdb.org/docs/sql/statements/attach.html>
+    /// and <https://www.sqlite.org/lang_detach.html>
     DetachDuckDBDatabase {
         if_exists: bool,
         /// true if the syntax is 'DETACH DATABASE', false if it's just 'DETACH'
@@ -2262,6 +2462,18 @@ pub enum Statement {
     /// but may also compatible with other SQL.
     Discard { object_type: DiscardObject },
     /// ```sql
+    /// REFRESH MATERIALIZED VIEW [ CONCURRENTLY ] name [ WITH [ NO ] DATA ]
+    /// ```
+    ///
+    /// Note: this is a PostgreSQL-specific statement.
+    RefreshMaterializedView {
+        #[cfg_attr(feature = "visitor", visit(with = "visit_relation"))]
+        name: ObjectName,
+        concurrently: bool,
+        /// `Some(true)` for `WITH DATA`, `Some(false)` for `WITH NO DATA`
+        with_data: Option<bool>,
+    },
+    /// ```sql
     /// SET [ SESSION | LOCAL ] ROLE role_name
     /// ```
     ///
@@ -2278,7 +2490,7 @@ pub enum Statement {
         role_name: Option<Ident>,
     },
     /// ```sql
-    /// SET <variable> = expression;
+    /// SET [ SESSION | LOCAL | GLOBAL ] <variable> { = | TO } expression;
     /// SET (variable[, ...]) = (expression[, ...]);
     /// ```
     ///
@@ -2286,10 +2498,12 @@ pub enum Statement {
     /// least MySQL and PostgreSQL. Not all MySQL-specific syntatic forms are
     /// supported yet.
     SetVariable {
-        local: bool,
+        scope: ContextModifier,
         hivevar: bool,
         variables: OneOrManyWithParens<ObjectName>,
         value: Vec<Expr>,
+        /// `true` if the value was assigned with `TO` rather than `=`
+        to_keyword: bool,
     },
     /// ```sql
     /// SET TIME ZONE <value>
@@ -2297,15 +2511,17 @@ pub enum Statement {
     ///
     /// Note: this is a PostgreSQL-specific statements
     /// `SET TIME ZONE <value>` is an alias for `SET timezone TO <value>` in PostgreSQL
-    SetTimeZone { local: bool, value: Expr },
+    SetTimeZone { scope: ContextModifier, value: Expr },
     /// ```sql
     /// SET NAMES 'charset_name' [COLLATE 'collation_name']
     /// ```
     ///
-    /// Note: this is a MySQL-specific statement.
+    /// Note: this is a MySQL and PostgreSQL statement.
     SetNames {
-        charset_name: String,
-        collation_name: Option<String>,
+        /// The character set, quoted or not as written
+        charset_name: Ident,
+        /// The collation, quoted or not as written
+        collation_name: Option<Ident>,
     },
     /// ```sql
     /// SET NAMES DEFAULT
@@ -2381,11 +2597,17 @@ pub enum Statement {
     /// Note: this is a MySQL-specific statement.
     ShowCollation { filter: Option<ShowStatementFilter> },
     /// ```sql
-    /// USE
+    /// SHOW DATABASES [LIKE 'pattern' | WHERE expr]
+    /// ```
+    ///
+    /// Note: this is a MySQL-specific statement.
+    ShowDatabases { filter: Option<ShowStatementFilter> },
+    /// ```sql
+    /// USE [ DATABASE | SCHEMA | WAREHOUSE | ROLE ] <name>
     /// ```
     ///
-    /// Note: This is a MySQL-specific statement.
-    Use { db_name: Ident },
+    /// Note: the object kinds are Snowflake-specific.
+    Use(Use),
     /// ```sql
     /// START  [ TRANSACTION | WORK ] | START TRANSACTION } ...
     /// ```
@@ -2398,6 +2620,8 @@ pub enum Statement {
     StartTransaction {
         modes: Vec<TransactionMode>,
         begin: bool,
+        /// The optional `TRANSACTION` or `WORK` keyword following `BEGIN`
+        transaction: Option<BeginTransactionKind>,
         /// Only for SQLite
         modifier: Option<TransactionModifier>,
     },
@@ -2430,8 +2654,12 @@ pub enum Statement {
     /// ROLLBACK [ TRANSACTION | WORK ] [ AND [ NO ] CHAIN ] [ TO [ SAVEPOINT ] savepoint_name ]
     /// ```
     Rollback {
+        /// `TRANSACTION` or `WORK`, if given
+        transaction: Option<BeginTransactionKind>,
         chain: bool,
         savepoint: Option<Ident>,
+        /// `true` if the optional `SAVEPOINT` keyword was present after `TO`
+        savepoint_keyword: bool,
     },
     /// ```sql
     /// CREATE SCHEMA
@@ -2458,6 +2686,7 @@ pub enum Statement {
     /// 1. [Hive](https://cwiki.apache.org/confluence/display/hive/languagemanual+ddl#LanguageManualDDL-Create/Drop/ReloadFunction)
     /// 2. [Postgres](https://www.postgresql.org/docs/15/sql-createfunction.html)
     /// 3. [BigQuery](https://cloud.google.com/bigquery/docs/reference/standard-sql/data-definition-language#create_function_statement)
+    /// 4. [Snowflake](https://docs.snowflake.com/en/sql-reference/sql/create-function)
     CreateFunction {
         or_replace: bool,
         temporary: bool,
@@ -2578,14 +2807,18 @@ pub enum Statement {
         objects: GrantObjects,
         grantees: Vec<Ident>,
         granted_by: Option<Ident>,
-        cascade: bool,
+        cascade: Option<CascadeOption>,
     },
     /// ```sql
     /// DEALLOCATE [ PREPARE ] { name | ALL }
     /// ```
     ///
     /// Note: this is a PostgreSQL-specific statement.
-    Deallocate { name: Ident, prepare: bool },
+    Deallocate {
+        /// The prepared statement to deallocate, or `None` for `ALL`
+        name: Option<Ident>,
+        prepare: bool,
+    },
     /// ```sql
     /// EXECUTE name [ ( parameter [, ...] ) ] [USING <expr>]
     /// ```
@@ -2653,7 +2886,11 @@ pub enum Statement {
     /// ```sql
     /// RELEASE [ SAVEPOINT ] savepoint_name
     /// ```
-    ReleaseSavepoint { name: Ident },
+    ReleaseSavepoint {
+        name: Ident,
+        /// `true` if the optional `SAVEPOINT` keyword was present
+        savepoint_keyword: bool,
+    },
     /// A `MERGE` statement.
     ///
     /// ```sql
@@ -2730,6 +2967,26 @@ pub enum Statement {
         is_eq: bool,
     },
     /// ```sql
+    /// VACUUM [ ( option [, ...] ) ] [ table_name [ ( column_name [, ...] ) ] [, ...] ]
+    /// VACUUM [ FULL ] [ FREEZE ] [ VERBOSE ] [ ANALYZE ] [ table_name [, ...] ]
+    /// VACUUM [ schema_name ] [ INTO file_name ]
+    /// ```
+    /// (PostgreSQL and SQLite)
+    ///
+    /// See <https://www.postgresql.org/docs/current/sql-vacuum.html>
+    /// and <https://www.sqlite.org/lang_vacuum.html>
+    Vacuum {
+        /// Options given either in parentheses or as bare keywords
+        options: Vec<VacuumOption>,
+        /// true if the options were given in parentheses,
+        /// false for the legacy `VACUUM FULL VERBOSE` form
+        parenthesized_options: bool,
+        /// Tables (or SQLite schemas) to vacuum, with optional column lists
+        tables: Vec<VacuumTable>,
+        /// SQLite `INTO file_name`
+        into: Option<Expr>,
+    },
+    /// ```sql
     /// LOCK TABLES <table_name> [READ [LOCAL] | [LOW_PRIORITY] WRITE]
     /// ```
     /// Note: this is a MySQL-specific statement. See <https://dev.mysql.com/doc/refman/8.0/en/lock-tables.html>
@@ -2888,12 +3145,24 @@ impl fmt::Display for Statement {
                 Ok(())
             }
             Statement::Truncate {
-                table_name,
+                table_names,
                 partitions,
                 table,
+                identity,
+                cascade,
             } => {
                 let table = if *table { "TABLE " } else { "" };
-                write!(f, "TRUNCATE {table}{table_name}")?;
+                write!(
+                    f,
+                    "TRUNCATE {table}{table_names}",
+                    table_names = display_comma_separated(table_names)
+                )?;
+                if let Some(identity) = identity {
+                    write!(f, " {identity}")?;
+                }
+                if let Some(cascade) = cascade {
+                    write!(f, " {cascade}")?;
+                }
                 if let Some(ref parts) = partitions {
                     if !parts.is_empty() {
                         write!(f, " PARTITION ({})", display_comma_separated(parts))?;
@@ -2991,9 +3260,11 @@ impl fmt::Display for Statement {
                     table,
                     on,
                     returning,
+                    returning_into,
                     replace_into,
                     priority,
                     insert_alias,
+                    assignments,
                 } = insert;
                 let table_name = if let Some(alias) = table_alias {
                     format!("{table_name} AS {alias}")
@@ -3001,27 +3272,31 @@ impl fmt::Display for Statement {
                     table_name.to_string()
                 };
 
-                if let Some(action) = or {
-                    write!(f, "INSERT OR {action} INTO {table_name} ")?;
-                } else {
-                    write!(
-                        f,
-                        "{start}",
-                        start = if *replace_into { "REPLACE" } else { "INSERT" },
-                    )?;
-                    if let Some(priority) = priority {
-                        write!(f, " {priority}",)?;
+                match or {
+                    // SQLite's `REPLACE INTO` also sets `or`, but is printed as written
+                    Some(action) if !*replace_into => {
+                        write!(f, "INSERT OR {action} INTO {table_name} ")?;
                     }
+                    _ => {
+                        write!(
+                            f,
+                            "{start}",
+                            start = if *replace_into { "REPLACE" } else { "INSERT" },
+                        )?;
+                        if let Some(priority) = priority {
+                            write!(f, " {priority}",)?;
+                        }
 
-                    write!(
-                        f,
-                        "{ignore}{over}{int}{tbl} {table_name} ",
-                        table_name = table_name,
-                        ignore = if *ignore { " IGNORE" } else { "" },
-                        over = if *overwrite { " OVERWRITE" } else { "" },
-                        int = if *into { " INTO" } else { "" },
-                        tbl = if *table { " TABLE" } else { "" },
-                    )?;
+                        write!(
+                            f,
+                            "{ignore}{over}{int}{tbl} {table_name} ",
+                            table_name = table_name,
+                            ignore = if *ignore { " IGNORE" } else { "" },
+                            over = if *overwrite { " OVERWRITE" } else { "" },
+                            int = if *into { " INTO" } else { "" },
+                            tbl = if *table { " TABLE" } else { "" },
+                        )?;
+                    }
                 }
                 if !columns.is_empty() {
                     write!(f, "({}) ", display_comma_separated(columns))?;
@@ -3037,9 +3312,11 @@ impl fmt::Display for Statement {
 
                 if let Some(source) = source {
                     write!(f, "{source}")?;
+                } else if !assignments.is_empty() {
+                    write!(f, "SET {}", display_comma_separated(assignments))?;
                 }
 
-                if source.is_none() && columns.is_empty() {
+                if source.is_none() && columns.is_empty() && assignments.is_empty() {
                     write!(f, "DEFAULT VALUES")?;
                 }
 
@@ -3060,6 +3337,9 @@ impl fmt::Display for Statement {
                 if let Some(returning) = returning {
                     write!(f, " RETURNING {}", display_comma_separated(returning))?;
                 }
+                if let Some(returning_into) = returning_into {
+                    write!(f, " {returning_into}")?;
+                }
 
                 Ok(())
             }
@@ -3077,6 +3357,7 @@ impl fmt::Display for Statement {
                 source,
                 to,
                 target,
+                with,
                 options,
                 legacy_options,
                 values,
@@ -3095,6 +3376,9 @@ impl fmt::Display for Statement {
                     }
                 }
                 write!(f, " {} {}", if *to { "TO" } else { "FROM" }, target)?;
+                if *with {
+                    write!(f, " WITH")?;
+                }
                 if !options.is_empty() {
                     write!(f, " ({})", display_comma_separated(options))?;
                 }
@@ -3123,6 +3407,7 @@ impl fmt::Display for Statement {
                 from,
                 selection,
                 returning,
+                returning_into,
             } => {
                 write!(f, "UPDATE {table}")?;
                 if !assignments.is_empty() {
@@ -3137,6 +3422,9 @@ impl fmt::Display for Statement {
                 if let Some(returning) = returning {
                     write!(f, " RETURNING {}", display_comma_separated(returning))?;
                 }
+                if let Some(returning_into) = returning_into {
+                    write!(f, " {returning_into}")?;
+                }
                 Ok(())
             }
             Statement::Delete(delete) => {
@@ -3146,6 +3434,7 @@ impl fmt::Display for Statement {
                     using,
                     selection,
                     returning,
+                    returning_into,
                     order_by,
                     limit,
                 } = delete;
@@ -3170,6 +3459,9 @@ impl fmt::Display for Statement {
                 if let Some(returning) = returning {
                     write!(f, " RETURNING {}", display_comma_separated(returning))?;
                 }
+                if let Some(returning_into) = returning_into {
+                    write!(f, " {returning_into}")?;
+                }
                 if !order_by.is_empty() {
                     write!(f, " ORDER BY {}", display_comma_separated(order_by))?;
                 }
@@ -3232,6 +3524,11 @@ impl fmt::Display for Statement {
                 if let Some(return_type) = return_type {
                     write!(f, " RETURNS {return_type}")?;
                 }
+                if let Some(CreateFunctionBody::AsBeforeCharacteristics(function_body)) =
+                    function_body
+                {
+                    write!(f, " AS {function_body}")?;
+                }
                 if let Some(determinism_specifier) = determinism_specifier {
                     write!(f, " {determinism_specifier}")?;
                 }
@@ -3329,6 +3626,8 @@ impl fmt::Display for Statement {
                 with_no_schema_binding,
                 if_not_exists,
                 temporary,
+                with_check_option,
+                with_data,
             } => {
                 write!(
                     f,
@@ -3346,12 +3645,12 @@ impl fmt::Display for Statement {
                         value::escape_single_quote_string(comment)
                     )?;
                 }
-                if matches!(options, CreateTableOptions::With(_)) {
-                    write!(f, " {options}")?;
-                }
                 if !columns.is_empty() {
                     write!(f, " ({})", display_comma_separated(columns))?;
                 }
+                if matches!(options, CreateTableOptions::With(_)) {
+                    write!(f, " {options}")?;
+                }
                 if !cluster_by.is_empty() {
                     write!(f, " CLUSTER BY ({})", display_comma_separated(cluster_by))?;
                 }
@@ -3359,6 +3658,14 @@ impl fmt::Display for Statement {
                     write!(f, " {options}")?;
                 }
                 write!(f, " AS {query}")?;
+                if let Some(check_option) = with_check_option {
+                    write!(f, " {check_option}")?;
+                }
+                match with_data {
+                    Some(true) => write!(f, " WITH DATA")?,
+                    Some(false) => write!(f, " WITH NO DATA")?,
+                    None => (),
+                }
                 if *with_no_schema_binding {
                     write!(f, " WITH NO SCHEMA BINDING")?;
                 }
@@ -3456,89 +3763,21 @@ impl fmt::Display for Statement {
             Statement::CreateRole {
                 names,
                 if_not_exists,
-                inherit,
-                login,
-                bypassrls,
-                password,
-                create_db,
-                create_role,
-                superuser,
-                replication,
-                connection_limit,
-                valid_until,
-                in_role,
-                in_group,
-                role,
-                user,
-                admin,
+                with,
+                options,
                 authorization_owner,
             } => {
                 write!(
                     f,
-                    "CREATE ROLE {if_not_exists}{names}{superuser}{create_db}{create_role}{inherit}{login}{replication}{bypassrls}",
+                    "CREATE ROLE {if_not_exists}{names}",
                     if_not_exists = if *if_not_exists { "IF NOT EXISTS " } else { "" },
                     names = display_separated(names, ", "),
-                    superuser = match *superuser {
-                        Some(true) => " SUPERUSER",
-                        Some(false) => " NOSUPERUSER",
-                        None => ""
-                    },
-                    create_db = match *create_db {
-                        Some(true) => " CREATEDB",
-                        Some(false) => " NOCREATEDB",
-                        None => ""
-                    },
-                    create_role = match *create_role {
-                        Some(true) => " CREATEROLE",
-                        Some(false) => " NOCREATEROLE",
-                        None => ""
-                    },
-                    inherit = match *inherit {
-                        Some(true) => " INHERIT",
-                        Some(false) => " NOINHERIT",
-                        None => ""
-                    },
-                    login = match *login {
-                        Some(true) => " LOGIN",
-                        Some(false) => " NOLOGIN",
-                        None => ""
-                    },
-                    replication = match *replication {
-                        Some(true) => " REPLICATION",
-                        Some(false) => " NOREPLICATION",
-                        None => ""
-                    },
-                    bypassrls = match *bypassrls {
-                        Some(true) => " BYPASSRLS",
-                        Some(false) => " NOBYPASSRLS",
-                        None => ""
-                    }
                 )?;
-                if let Some(limit) = connection_limit {
-                    write!(f, " CONNECTION LIMIT {limit}")?;
-                }
-                match password {
-                    Some(Password::Password(pass)) => write!(f, " PASSWORD {pass}"),
-                    Some(Password::NullPassword) => write!(f, " PASSWORD NULL"),
-                    None => Ok(()),
-                }?;
-                if let Some(until) = valid_until {
-                    write!(f, " VALID UNTIL {until}")?;
-                }
-                if !in_role.is_empty() {
-                    write!(f, " IN ROLE {}", display_comma_separated(in_role))?;
-                }
-                if !in_group.is_empty() {
-                    write!(f, " IN GROUP {}", display_comma_separated(in_group))?;
-                }
-                if !role.is_empty() {
-                    write!(f, " ROLE {}", display_comma_separated(role))?;
-                }
-                if !user.is_empty() {
-                    write!(f, " USER {}", display_comma_separated(user))?;
+                if *with {
+                    write!(f, " WITH")?;
                 }
-                if !admin.is_empty() {
-                    write!(f, " ADMIN {}", display_comma_separated(admin))?;
+                for option in options {
+                    write!(f, " {option}")?;
                 }
                 if let Some(owner) = authorization_owner {
                     write!(f, " AUTHORIZATION {owner}")?;
@@ -3684,6 +3923,23 @@ impl fmt::Display for Statement {
                 write!(f, "DISCARD {object_type}")?;
                 Ok(())
             }
+            Statement::RefreshMaterializedView {
+                name,
+                concurrently,
+                with_data,
+            } => {
+                write!(f, "REFRESH MATERIALIZED VIEW ")?;
+                if *concurrently {
+                    write!(f, "CONCURRENTLY ")?;
+                }
+                write!(f, "{name}")?;
+                match with_data {
+                    Some(true) => write!(f, " WITH DATA")?,
+                    Some(false) => write!(f, " WITH NO DATA")?,
+                    None => {}
+                }
+                Ok(())
+            }
             Self::SetRole {
                 context_modifier,
                 role_name,
@@ -3692,43 +3948,43 @@ impl fmt::Display for Statement {
                 write!(f, "SET{context_modifier} ROLE {role_name}")
             }
             Statement::SetVariable {
-                local,
+                scope,
                 variables,
                 hivevar,
                 value,
+                to_keyword,
             } => {
-                f.write_str("SET ")?;
-                if *local {
-                    f.write_str("LOCAL ")?;
-                }
                 let parenthesized = matches!(variables, OneOrManyWithParens::Many(_));
                 write!(
                     f,
-                    "{hivevar}{name} = {l_paren}{value}{r_paren}",
+                    "SET{scope} {hivevar}{name} {operator} {l_paren}{value}{r_paren}",
                     hivevar = if *hivevar { "HIVEVAR:" } else { "" },
                     name = variables,
-                    l_paren = parenthesized.then_some("(").unwrap_or_default(),
+                    operator = if *to_keyword { "TO" } else { "=" },
+                    l_paren = if parenthesized {
+                        "("
+                    } else {
+                        Default::default()
+                    },
                     value = display_comma_separated(value),
-                    r_paren = parenthesized.then_some(")").unwrap_or_default(),
+                    r_paren = if parenthesized {
+                        ")"
+                    } else {
+                        Default::default()
+                    },
                 )
             }
-            Statement::SetTimeZone { local, value } => {
-                f.write_str("SET ")?;
-                if *local {
-                    f.write_str("LOCAL ")?;
-                }
-                write!(f, "TIME ZONE {value}")
+            Statement::SetTimeZone { scope, value } => {
+                write!(f, "SET{scope} TIME ZONE {value}")
             }
             Statement::SetNames {
                 charset_name,
                 collation_name,
             } => {
-                f.write_str("SET NAMES ")?;
-                f.write_str(charset_name)?;
+                write!(f, "SET NAMES {charset_name}")?;
 
                 if let Some(collation) = collation_name {
-                    f.write_str(" COLLATE ")?;
-                    f.write_str(collation)?;
+                    write!(f, " COLLATE {collation}")?;
                 };
 
                 Ok(())
@@ -3830,10 +4086,7 @@ impl fmt::Display for Statement {
                 }
                 Ok(())
             }
-            Statement::Use { db_name } => {
-                write!(f, "USE {db_name}")?;
-                Ok(())
-            }
+            Statement::Use(use_expr) => use_expr.fmt(f),
             Statement::ShowCollation { filter } => {
                 write!(f, "SHOW COLLATION")?;
                 if let Some(filter) = filter {
@@ -3841,16 +4094,26 @@ impl fmt::Display for Statement {
                 }
                 Ok(())
             }
+            Statement::ShowDatabases { filter } => {
+                write!(f, "SHOW DATABASES")?;
+                if let Some(filter) = filter {
+                    write!(f, " {filter}")?;
+                }
+                Ok(())
+            }
             Statement::StartTransaction {
                 modes,
                 begin: syntax_begin,
+                transaction,
                 modifier,
             } => {
                 if *syntax_begin {
+                    write!(f, "BEGIN")?;
                     if let Some(modifier) = *modifier {
-                        write!(f, "BEGIN {} TRANSACTION", modifier)?;
-                    } else {
-                        write!(f, "BEGIN TRANSACTION")?;
+                        write!(f, " {modifier}")?;
+                    }
+                    if let Some(transaction) = transaction {
+                        write!(f, " {transaction}")?;
                     }
                 } else {
                     write!(f, "START TRANSACTION")?;
@@ -3881,15 +4144,28 @@ impl fmt::Display for Statement {
             Statement::Commit { chain } => {
                 write!(f, "COMMIT{}", if *chain { " AND CHAIN" } else { "" },)
             }
-            Statement::Rollback { chain, savepoint } => {
+            Statement::Rollback {
+                transaction,
+                chain,
+                savepoint,
+                savepoint_keyword,
+            } => {
                 write!(f, "ROLLBACK")?;
 
+                if let Some(transaction) = transaction {
+                    write!(f, " {transaction}")?;
+                }
+
                 if *chain {
                     write!(f, " AND CHAIN")?;
                 }
 
                 if let Some(savepoint) = savepoint {
-                    write!(f, " TO SAVEPOINT {savepoint}")?;
+                    write!(
+                        f,
+                        " TO {}{savepoint}",
+                        if *savepoint_keyword { "SAVEPOINT " } else { "" }
+                    )?;
                 }
 
                 Ok(())
@@ -3941,15 +4217,21 @@ impl fmt::Display for Statement {
                 if let Some(grantor) = granted_by {
                     write!(f, " GRANTED BY {grantor}")?;
                 }
-                write!(f, " {}", if *cascade { "CASCADE" } else { "RESTRICT" })?;
+                if let Some(cascade) = cascade {
+                    write!(f, " {cascade}")?;
+                }
                 Ok(())
             }
-            Statement::Deallocate { name, prepare } => write!(
-                f,
-                "DEALLOCATE {prepare}{name}",
-                prepare = if *prepare { "PREPARE " } else { "" },
-                name = name,
-            ),
+            Statement::Deallocate { name, prepare } => {
+                write!(f, "DEALLOCATE ")?;
+                if *prepare {
+                    write!(f, "PREPARE ")?;
+                }
+                match name {
+                    Some(name) => write!(f, "{name}"),
+                    None => write!(f, "ALL"),
+                }
+            }
             Statement::Execute {
                 name,
                 parameters,
@@ -3987,7 +4269,7 @@ impl fmt::Display for Statement {
                 };
                 write!(f, "ON {object_type} {object_name} IS ")?;
                 if let Some(c) = comment {
-                    write!(f, "'{c}'")
+                    write!(f, "'{}'", value::escape_single_quote_string(c))
                 } else {
                     write!(f, "NULL")
                 }
@@ -3996,8 +4278,15 @@ impl fmt::Display for Statement {
                 write!(f, "SAVEPOINT ")?;
                 write!(f, "{name}")
             }
-            Statement::ReleaseSavepoint { name } => {
-                write!(f, "RELEASE SAVEPOINT {name}")
+            Statement::ReleaseSavepoint {
+                name,
+                savepoint_keyword,
+            } => {
+                write!(
+                    f,
+                    "RELEASE {savepoint}{name}",
+                    savepoint = if *savepoint_keyword { "SAVEPOINT " } else { "" },
+                )
             }
             Statement::Merge {
                 into,
@@ -4197,6 +4486,26 @@ impl fmt::Display for Statement {
                 }
                 Ok(())
             }
+            Statement::Vacuum {
+                options,
+                parenthesized_options,
+                tables,
+                into,
+            } => {
+                write!(f, "VACUUM")?;
+                if *parenthesized_options {
+                    write!(f, " ({})", display_comma_separated(options))?;
+                } else if !options.is_empty() {
+                    write!(f, " {}", display_separated(options, " "))?;
+                }
+                if !tables.is_empty() {
+                    write!(f, " {}", display_comma_separated(tables))?;
+                }
+                if let Some(into) = into {
+                    write!(f, " INTO {into}")?;
+                }
+                Ok(())
+            }
             Statement::LockTables { tables } => {
                 write!(f, "LOCK TABLES {}", display_comma_separated(tables))
             }
@@ -4280,6 +4589,7 @@ impl fmt::Display for SequenceOptions {
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+#[allow(clippy::large_enum_variant)]
 pub enum MinMaxValue {
     // clause is not specified
     Empty,
@@ -4293,6 +4603,7 @@ pub enum MinMaxValue {
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 #[non_exhaustive]
+#[allow(clippy::large_enum_variant)]
 pub enum OnInsert {
     /// ON DUPLICATE KEY UPDATE (MySQL when the key already exists, then execute an update instead)
     DuplicateKeyUpdate(Vec<Assignment>),
@@ -4325,6 +4636,7 @@ pub enum ConflictTarget {
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+#[allow(clippy::large_enum_variant)]
 pub enum OnConflictAction {
     DoNothing,
     DoUpdate(DoUpdate),
@@ -4551,12 +4863,18 @@ pub enum GrantObjects {
     AllSequencesInSchema { schemas: Vec<ObjectName> },
     /// Grant privileges on `ALL TABLES IN SCHEMA <schema_name> [, ...]`
     AllTablesInSchema { schemas: Vec<ObjectName> },
+    /// Grant privileges on specific databases
+    Databases(Vec<ObjectName>),
     /// Grant privileges on specific schemas
     Schemas(Vec<ObjectName>),
     /// Grant privileges on specific sequences
     Sequences(Vec<ObjectName>),
     /// Grant privileges on specific tables
-    Tables(Vec<ObjectName>),
+    Tables {
+        /// Whether the optional `TABLE` keyword was given
+        table_keyword: bool,
+        tables: Vec<ObjectName>,
+    },
 }
 
 impl fmt::Display for GrantObjects {
@@ -4565,10 +4883,19 @@ impl fmt::Display for GrantObjects {
             GrantObjects::Sequences(sequences) => {
                 write!(f, "SEQUENCE {}", display_comma_separated(sequences))
             }
+            GrantObjects::Databases(databases) => {
+                write!(f, "DATABASE {}", display_comma_separated(databases))
+            }
             GrantObjects::Schemas(schemas) => {
                 write!(f, "SCHEMA {}", display_comma_separated(schemas))
             }
-            GrantObjects::Tables(tables) => {
+            GrantObjects::Tables {
+                table_keyword,
+                tables,
+            } => {
+                if *table_keyword {
+                    write!(f, "TABLE ")?;
+                }
                 write!(f, "{}", display_comma_separated(tables))
             }
             GrantObjects::AllSequencesInSchema { schemas } => {
@@ -4607,6 +4934,7 @@ impl fmt::Display for Assignment {
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+#[allow(clippy::large_enum_variant)]
 pub enum FunctionArgExpr {
     Expr(Expr),
     /// Qualified wildcard, e.g. `alias.*` or `schema.table.*`.
@@ -4720,6 +5048,15 @@ pub struct Function {
     ///
     /// [Snowflake](https://docs.snowflake.com/en/sql-reference/functions/first_value)
     pub null_treatment: Option<NullTreatment>,
+    /// The end of the window frame that `NTH_VALUE` counts from.
+    ///
+    /// Example:
+    /// ```plaintext
+    /// NTH_VALUE( <expr>, <n> ) FROM { FIRST | LAST } [ { IGNORE | RESPECT } NULLS ] OVER ...
+    /// ```
+    ///
+    /// [Snowflake](https://docs.snowflake.com/en/sql-reference/functions/nth_value)
+    pub nth_value_order: Option<NthValueOrder>,
     /// The `OVER` clause, indicating a window function call.
     pub over: Option<WindowType>,
     /// A clause used with certain aggregate functions to control the ordering
@@ -4736,6 +5073,10 @@ impl fmt::Display for Function {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}{}", self.name, self.args)?;
 
+        if let Some(nth_value_order) = &self.nth_value_order {
+            write!(f, " {nth_value_order}")?;
+        }
+
         if !self.within_group.is_empty() {
             write!(
                 f,
@@ -4893,6 +5234,8 @@ pub enum AnalyzeFormat {
     TEXT,
     GRAPHVIZ,
     JSON,
+    XML,
+    YAML,
 }
 
 impl fmt::Display for AnalyzeFormat {
@@ -4901,6 +5244,8 @@ impl fmt::Display for AnalyzeFormat {
             AnalyzeFormat::TEXT => "TEXT",
             AnalyzeFormat::GRAPHVIZ => "GRAPHVIZ",
             AnalyzeFormat::JSON => "JSON",
+            AnalyzeFormat::XML => "XML",
+            AnalyzeFormat::YAML => "YAML",
         })
     }
 }
@@ -5178,6 +5523,45 @@ pub struct HiveFormat {
     pub location: Option<String>,
 }
 
+/// An option of a [`Statement::Vacuum`], e.g. `FULL`, `VERBOSE false`
+/// or `INDEX_CLEANUP OFF`
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub struct VacuumOption {
+    pub name: Ident,
+    pub value: Option<Expr>,
+}
+
+impl fmt::Display for VacuumOption {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.name)?;
+        if let Some(value) = &self.value {
+            write!(f, " {value}")?;
+        }
+        Ok(())
+    }
+}
+
+/// A table to vacuum, with an optional column list: `t (a, b)`
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub struct VacuumTable {
+    pub name: ObjectName,
+    pub columns: Vec<Ident>,
+}
+
+impl fmt::Display for VacuumTable {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.name)?;
+        if !self.columns.is_empty() {
+            write!(f, " ({})", display_comma_separated(&self.columns))?;
+        }
+        Ok(())
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
@@ -5283,6 +5667,24 @@ impl fmt::Display for TransactionIsolationLevel {
     }
 }
 
+/// The keyword following `BEGIN` in a `BEGIN [ TRANSACTION | WORK ]` statement
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub enum BeginTransactionKind {
+    Transaction,
+    Work,
+}
+
+impl fmt::Display for BeginTransactionKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            BeginTransactionKind::Transaction => write!(f, "TRANSACTION"),
+            BeginTransactionKind::Work => write!(f, "WORK"),
+        }
+    }
+}
+
 /// SQLite specific syntax
 ///
 /// <https://sqlite.org/lang_transaction.html>
@@ -5309,6 +5711,7 @@ impl fmt::Display for TransactionModifier {
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+#[allow(clippy::large_enum_variant)]
 pub enum ShowStatementFilter {
     Like(String),
     ILike(String),
@@ -5320,12 +5723,79 @@ impl fmt::Display for ShowStatementFilter {
         use ShowStatementFilter::*;
         match self {
             Like(pattern) => write!(f, "LIKE '{}'", value::escape_single_quote_string(pattern)),
-            ILike(pattern) => write!(f, "ILIKE {}", value::escape_single_quote_string(pattern)),
+            ILike(pattern) => write!(f, "ILIKE '{}'", value::escape_single_quote_string(pattern)),
             Where(expr) => write!(f, "WHERE {expr}"),
         }
     }
 }
 
+/// A table named in a `TRUNCATE` statement
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub struct TruncateTableTarget {
+    /// `ONLY` - do not truncate descendant tables (PostgreSQL)
+    pub only: bool,
+    /// name of the table being truncated
+    #[cfg_attr(feature = "visitor", visit(with = "visit_relation"))]
+    pub name: ObjectName,
+    /// `*` after the name - explicitly truncate descendant tables (PostgreSQL)
+    pub descendants: bool,
+}
+
+impl fmt::Display for TruncateTableTarget {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.only {
+            write!(f, "ONLY ")?;
+        }
+        write!(f, "{}", self.name)?;
+        if self.descendants {
+            write!(f, "*")?;
+        }
+        Ok(())
+    }
+}
+
+/// PostgreSQL identity option for `TRUNCATE`
+///
+/// See <https://www.postgresql.org/docs/current/sql-truncate.html>
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub enum TruncateIdentityOption {
+    Restart,
+    Continue,
+}
+
+impl fmt::Display for TruncateIdentityOption {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            TruncateIdentityOption::Restart => write!(f, "RESTART IDENTITY"),
+            TruncateIdentityOption::Continue => write!(f, "CONTINUE IDENTITY"),
+        }
+    }
+}
+
+/// `CASCADE` or `RESTRICT` option of e.g. `TRUNCATE` and `REVOKE`
+///
+/// See <https://www.postgresql.org/docs/current/sql-truncate.html>
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub enum CascadeOption {
+    Cascade,
+    Restrict,
+}
+
+impl fmt::Display for CascadeOption {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CascadeOption::Cascade => write!(f, "CASCADE"),
+            CascadeOption::Restrict => write!(f, "RESTRICT"),
+        }
+    }
+}
+
 /// Sqlite specific syntax
 ///
 /// See [Sqlite documentation](https://sqlite.org/lang_conflict.html)
@@ -5413,7 +5883,7 @@ impl fmt::Display for CopyTarget {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         use CopyTarget::*;
         match self {
-            Stdin { .. } => write!(f, "STDIN"),
+            Stdin => write!(f, "STDIN"),
             Stdout => write!(f, "STDOUT"),
             File { filename } => write!(f, "'{}'", value::escape_single_quote_string(filename)),
             Program { command } => write!(
@@ -5449,14 +5919,20 @@ pub enum CopyOption {
     Delimiter(char),
     /// NULL 'null_string'
     Null(String),
-    /// HEADER \[ boolean \]
-    Header(bool),
+    /// HEADER \[ boolean \], holding the boolean if it was given
+    Header(Option<bool>),
+    /// HEADER MATCH
+    HeaderMatch,
     /// QUOTE 'quote_character'
     Quote(char),
     /// ESCAPE 'escape_character'
     Escape(char),
-    /// FORCE_QUOTE { ( column_name [, ...] ) | * }
+    /// DEFAULT 'default_string'
+    Default(String),
+    /// FORCE_QUOTE ( column_name [, ...] )
     ForceQuote(Vec<Ident>),
+    /// FORCE_QUOTE *
+    ForceQuoteAll,
     /// FORCE_NOT_NULL ( column_name [, ...] )
     ForceNotNull(Vec<Ident>),
     /// FORCE_NULL ( column_name [, ...] )
@@ -5474,11 +5950,15 @@ impl fmt::Display for CopyOption {
             Freeze(false) => write!(f, "FREEZE FALSE"),
             Delimiter(char) => write!(f, "DELIMITER '{char}'"),
             Null(string) => write!(f, "NULL '{}'", value::escape_single_quote_string(string)),
-            Header(true) => write!(f, "HEADER"),
-            Header(false) => write!(f, "HEADER FALSE"),
+            Header(None) => write!(f, "HEADER"),
+            Header(Some(true)) => write!(f, "HEADER TRUE"),
+            Header(Some(false)) => write!(f, "HEADER FALSE"),
+            HeaderMatch => write!(f, "HEADER MATCH"),
             Quote(char) => write!(f, "QUOTE '{char}'"),
             Escape(char) => write!(f, "ESCAPE '{char}'"),
+            Default(string) => write!(f, "DEFAULT '{}'", value::escape_single_quote_string(string)),
             ForceQuote(columns) => write!(f, "FORCE_QUOTE ({})", display_comma_separated(columns)),
+            ForceQuoteAll => write!(f, "FORCE_QUOTE *"),
             ForceNotNull(columns) => {
                 write!(f, "FORCE_NOT_NULL ({})", display_comma_separated(columns))
             }
@@ -5818,7 +6298,7 @@ impl fmt::Display for FlushLocation {
     }
 }
 
-/// Optional context modifier for statements that can be or `LOCAL`, or `SESSION`.
+/// Optional context modifier for statements that can be or `LOCAL`, `SESSION`, or `GLOBAL`.
 #[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
@@ -5829,6 +6309,8 @@ pub enum ContextModifier {
     Local,
     /// `SESSION` identifier
     Session,
+    /// `GLOBAL` identifier, e.g. MySQL's `SET GLOBAL`
+    Global,
 }
 
 impl fmt::Display for ContextModifier {
@@ -5843,6 +6325,42 @@ impl fmt::Display for ContextModifier {
             Self::Session => {
                 write!(f, " SESSION")
             }
+            Self::Global => {
+                write!(f, " GLOBAL")
+            }
+        }
+    }
+}
+
+/// The target of a `USE` statement.
+///
+/// See [Snowflake](https://docs.snowflake.com/en/sql-reference/sql/use) and
+/// [Databricks](https://docs.databricks.com/en/sql/language-manual/sql-ref-syntax-ddl-usedb.html).
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub enum Use {
+    /// `USE DATABASE <name>`
+    Database(ObjectName),
+    /// `USE SCHEMA <name>`
+    Schema(ObjectName),
+    /// `USE WAREHOUSE <name>`
+    Warehouse(ObjectName),
+    /// `USE ROLE <name>`
+    Role(ObjectName),
+    /// `USE <name>`
+    Object(ObjectName),
+}
+
+impl fmt::Display for Use {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("USE ")?;
+        match self {
+            Use::Database(name) => write!(f, "DATABASE {name}"),
+            Use::Schema(name) => write!(f, "SCHEMA {name}"),
+            Use::Warehouse(name) => write!(f, "WAREHOUSE {name}"),
+            Use::Role(name) => write!(f, "ROLE {name}"),
+            Use::Object(name) => write!(f, "{name}"),
         }
     }
 }
@@ -6069,6 +6587,18 @@ pub enum CreateFunctionBody {
     ///
     /// [BigQuery]: https://cloud.google.com/bigquery/docs/reference/standard-sql/data-definition-language#syntax_11
     AsAfterOptions(Expr),
+    /// A function body expression using the 'AS' keyword and shows up
+    /// before any other option such as `LANGUAGE`, as allowed by [Postgres].
+    ///
+    /// Example:
+    /// ```sql
+    /// CREATE FUNCTION myfunc() RETURNS INTEGER
+    /// AS $$ SELECT 1 $$
+    /// LANGUAGE sql IMMUTABLE;
+    /// ```
+    ///
+    /// [Postgres]: https://www.postgresql.org/docs/current/sql-createfunction.html
+    AsBeforeCharacteristics(Expr),
     /// Function body expression using the 'RETURN' keyword.
     ///
     /// Example:
@@ -6137,6 +6667,7 @@ impl fmt::Display for MacroArg {
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+#[allow(clippy::large_enum_variant)]
 pub enum MacroDefinition {
     Expr(Expr),
     Table(Query),
@@ -6183,6 +6714,26 @@ impl fmt::Display for SchemaName {
     }
 }
 
+/// The kind of an `MSSQL` full-text predicate, see [`Expr::FullTextPredicate`].
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub enum FullTextPredicateKind {
+    /// `CONTAINS`.
+    Contains,
+    /// `FREETEXT`.
+    Freetext,
+}
+
+impl fmt::Display for FullTextPredicateKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Contains => "CONTAINS",
+            Self::Freetext => "FREETEXT",
+        })
+    }
+}
+
 /// Fulltext search modifiers ([1]).
 ///
 /// [1]: https://dev.mysql.com/doc/refman/8.0/en/fulltext-search.html#function_match
@@ -6463,30 +7014,44 @@ mod tests {
     fn test_grouping_sets_display() {
         // a and b in different group
         let grouping_sets = Expr::GroupingSets(vec![
-            vec![Expr::Identifier(Ident::new("a"))],
-            vec![Expr::Identifier(Ident::new("b"))],
+            OneOrManyWithParens::Many(vec![Expr::Identifier(Ident::new("a"))]),
+            OneOrManyWithParens::Many(vec![Expr::Identifier(Ident::new("b"))]),
         ]);
         assert_eq!("GROUPING SETS ((a), (b))", format!("{grouping_sets}"));
 
         // a and b in the same group
-        let grouping_sets = Expr::GroupingSets(vec![vec![
+        let grouping_sets = Expr::GroupingSets(vec![OneOrManyWithParens::Many(vec![
             Expr::Identifier(Ident::new("a")),
             Expr::Identifier(Ident::new("b")),
-        ]]);
+        ])]);
         assert_eq!("GROUPING SETS ((a, b))", format!("{grouping_sets}"));
 
         // (a, b) and (c, d) in different group
         let grouping_sets = Expr::GroupingSets(vec![
-            vec![
+            OneOrManyWithParens::Many(vec![
                 Expr::Identifier(Ident::new("a")),
                 Expr::Identifier(Ident::new("b")),
-            ],
-            vec![
+            ]),
+            OneOrManyWithParens::Many(vec![
                 Expr::Identifier(Ident::new("c")),
                 Expr::Identifier(Ident::new("d")),
-            ],
+            ]),
         ]);
         assert_eq!("GROUPING SETS ((a, b), (c, d))", format!("{grouping_sets}"));
+
+        // bare sets, such as a nested ROLLUP, are not wrapped in parentheses
+        let grouping_sets = Expr::GroupingSets(vec![
+            OneOrManyWithParens::One(Expr::Identifier(Ident::new("a"))),
+            OneOrManyWithParens::One(Expr::Rollup(vec![
+                vec![Expr::Identifier(Ident::new("a"))],
+                vec![Expr::Identifier(Ident::new("b"))],
+            ])),
+            OneOrManyWithParens::Many(vec![]),
+        ]);
+        assert_eq!(
+            "GROUPING SETS (a, ROLLUP (a, b), ())",
+            format!("{grouping_sets}")
+        );
     }
 
     #[test]
@@ -99,6 +99,10 @@ pub enum BinaryOperator {
     And,
     /// Or, e.g. `a OR b`
     Or,
+    /// Or written as `a || b`, see [`Dialect::supports_pipes_as_or`]
+    ///
+    /// [`Dialect::supports_pipes_as_or`]: crate::dialect::Dialect::supports_pipes_as_or
+    PipesOr,
     /// XOR, e.g. `a XOR b`
     Xor,
     /// Bitwise or, e.g. `a | b`
@@ -266,6 +270,7 @@ impl fmt::Display for BinaryOperator {
             BinaryOperator::BitwiseXor => f.write_str("^"),
             BinaryOperator::DuckIntegerDivide => f.write_str("//"),
             BinaryOperator::MyIntegerDivide => f.write_str("DIV"),
+            BinaryOperator::PipesOr => f.write_str("||"),
             BinaryOperator::Custom(s) => f.write_str(s),
             BinaryOperator::PGBitwiseXor => f.write_str("#"),
             BinaryOperator::PGBitwiseShiftLeft => f.write_str("<<"),
@@ -105,6 +105,7 @@ pub enum SetExpr {
     Values(Values),
     Insert(Statement),
     Update(Statement),
+    Delete(Statement),
     Table(Box<Table>),
 }
 
@@ -127,6 +128,7 @@ impl fmt::Display for SetExpr {
             SetExpr::Values(v) => write!(f, "{v}"),
             SetExpr::Insert(v) => write!(f, "{v}"),
             SetExpr::Update(v) => write!(f, "{v}"),
+            SetExpr::Delete(v) => write!(f, "{v}"),
             SetExpr::Table(t) => write!(f, "{t}"),
             SetExpr::SetOperation {
                 left,
@@ -255,12 +257,12 @@ pub struct Select {
     /// WINDOW AS
     pub named_window: Vec<NamedWindowDefinition>,
     /// QUALIFY (Snowflake)
-    pub qualify: Option<Expr>,
+    ///
     /// The positioning of QUALIFY and WINDOW clauses differ between dialects.
     /// e.g. BigQuery requires that WINDOW comes after QUALIFY, while DUCKDB accepts
     /// WINDOW before QUALIFY.
-    /// We accept either positioning and flag the accepted variant.
-    pub window_before_qualify: bool,
+    /// We accept either positioning and always display QUALIFY first.
+    pub qualify: Option<Expr>,
     /// BigQuery syntax: `SELECT AS VALUE | SELECT AS STRUCT`
     pub value_table_mode: Option<ValueTableMode>,
     /// STARTING WITH .. CONNECT BY
@@ -271,13 +273,12 @@ impl fmt::Display for Select {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "SELECT")?;
 
-        if let Some(value_table_mode) = self.value_table_mode {
-            write!(f, " {value_table_mode}")?;
-        }
-
         if let Some(ref distinct) = self.distinct {
             write!(f, " {distinct}")?;
         }
+        if let Some(value_table_mode) = self.value_table_mode {
+            write!(f, " {value_table_mode}")?;
+        }
         if let Some(ref top) = self.top {
             write!(f, " {top}")?;
         }
@@ -299,12 +300,8 @@ impl fmt::Display for Select {
             write!(f, " WHERE {selection}")?;
         }
         match &self.group_by {
-            GroupByExpr::All => write!(f, " GROUP BY ALL")?,
-            GroupByExpr::Expressions(exprs) => {
-                if !exprs.is_empty() {
-                    write!(f, " GROUP BY {}", display_comma_separated(exprs))?;
-                }
-            }
+            GroupByExpr::Expressions(exprs) if exprs.is_empty() => {}
+            group_by => write!(f, " {group_by}")?,
         }
         if !self.cluster_by.is_empty() {
             write!(
@@ -326,20 +323,11 @@ impl fmt::Display for Select {
         if let Some(ref having) = self.having {
             write!(f, " HAVING {having}")?;
         }
-        if self.window_before_qualify {
-            if !self.named_window.is_empty() {
-                write!(f, " WINDOW {}", display_comma_separated(&self.named_window))?;
-            }
-            if let Some(ref qualify) = self.qualify {
-                write!(f, " QUALIFY {qualify}")?;
-            }
-        } else {
-            if let Some(ref qualify) = self.qualify {
-                write!(f, " QUALIFY {qualify}")?;
-            }
-            if !self.named_window.is_empty() {
-                write!(f, " WINDOW {}", display_comma_separated(&self.named_window))?;
-            }
+        if let Some(ref qualify) = self.qualify {
+            write!(f, " QUALIFY {qualify}")?;
+        }
+        if !self.named_window.is_empty() {
+            write!(f, " WINDOW {}", display_comma_separated(&self.named_window))?;
         }
         if let Some(ref connect_by) = self.connect_by {
             write!(f, " {connect_by}")?;
@@ -542,6 +530,9 @@ impl fmt::Display for IdentWithAlias {
 }
 
 /// Additional options for wildcards, e.g. Snowflake `EXCLUDE`/`RENAME` and Bigquery `EXCEPT`.
+///
+/// Several options may be combined; they are parsed and displayed in the
+/// order `ILIKE`, `EXCLUDE`, `EXCEPT`, `RENAME`, `REPLACE`.
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, Default)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
@@ -857,6 +848,7 @@ impl fmt::Display for ExprWithAlias {
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 #[cfg_attr(feature = "visitor", visit(with = "visit_table_factor"))]
+#[allow(clippy::large_enum_variant)]
 pub enum TableFactor {
     Table {
         #[cfg_attr(feature = "visitor", visit(with = "visit_relation"))]
@@ -965,7 +957,7 @@ pub enum TableFactor {
     ///
     /// Syntax:
     /// ```sql
-    /// table UNPIVOT(value FOR name IN (column1, [ column2, ... ])) [ alias ]
+    /// table UNPIVOT [ { INCLUDE | EXCLUDE } NULLS ] (value FOR name IN (column1, [ column2, ... ])) [ alias ]
     /// ```
     ///
     /// See <https://docs.snowflake.com/en/sql-reference/constructs/unpivot>.
@@ -974,6 +966,7 @@ pub enum TableFactor {
         value: Ident,
         name: Ident,
         columns: Vec<Ident>,
+        null_inclusion: Option<NullInclusion>,
         alias: Option<TableAlias>,
     },
     /// A `MATCH_RECOGNIZE` operation on a table.
@@ -1003,6 +996,7 @@ pub enum TableFactor {
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+#[allow(clippy::large_enum_variant)]
 pub enum PivotValueSource {
     /// Pivot on a static list of values.
     ///
@@ -1034,6 +1028,26 @@ impl fmt::Display for PivotValueSource {
     }
 }
 
+/// Whether an `UNPIVOT` operation keeps rows whose value is `NULL`.
+///
+/// See <https://docs.snowflake.com/en/sql-reference/constructs/unpivot#parameters>.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub enum NullInclusion {
+    IncludeNulls,
+    ExcludeNulls,
+}
+
+impl fmt::Display for NullInclusion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NullInclusion::IncludeNulls => write!(f, "INCLUDE NULLS"),
+            NullInclusion::ExcludeNulls => write!(f, "EXCLUDE NULLS"),
+        }
+    }
+}
+
 /// An item in the `MEASURES` subclause of a `MATCH_RECOGNIZE` operation.
 ///
 /// See <https://docs.snowflake.com/en/sql-reference/constructs/match_recognize#measures-specifying-additional-output-columns>.
@@ -1101,7 +1115,7 @@ impl fmt::Display for AfterMatchSkip {
         write!(f, "AFTER MATCH SKIP ")?;
         match self {
             AfterMatchSkip::PastLastRow => write!(f, "PAST LAST ROW"),
-            AfterMatchSkip::ToNextRow => write!(f, " TO NEXT ROW"),
+            AfterMatchSkip::ToNextRow => write!(f, "TO NEXT ROW"),
             AfterMatchSkip::ToFirst(symbol) => write!(f, "TO FIRST {symbol}"),
             AfterMatchSkip::ToLast(symbol) => write!(f, "TO LAST {symbol}"),
         }
@@ -1263,15 +1277,15 @@ impl fmt::Display for TableFactor {
                 if let Some(args) = args {
                     write!(f, "({})", display_comma_separated(args))?;
                 }
+                if let Some(version) = version {
+                    write!(f, "{version}")?;
+                }
                 if let Some(alias) = alias {
                     write!(f, " AS {alias}")?;
                 }
                 if !with_hints.is_empty() {
                     write!(f, " WITH ({})", display_comma_separated(with_hints))?;
                 }
-                if let Some(version) = version {
-                    write!(f, "{version}")?;
-                }
                 Ok(())
             }
             TableFactor::Derived {
@@ -1384,12 +1398,16 @@ impl fmt::Display for TableFactor {
                 value,
                 name,
                 columns,
+                null_inclusion,
                 alias,
             } => {
+                write!(f, "{table} UNPIVOT")?;
+                if let Some(null_inclusion) = null_inclusion {
+                    write!(f, " {null_inclusion} ")?;
+                }
                 write!(
                     f,
-                    "{} UNPIVOT({} FOR {} IN ({}))",
-                    table,
+                    "({} FOR {} IN ({}))",
                     value,
                     name,
                     display_comma_separated(columns)
@@ -1459,18 +1477,73 @@ impl fmt::Display for TableAlias {
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
 pub enum TableVersion {
+    /// `FOR SYSTEM_TIME AS OF <expr>`
     ForSystemTimeAsOf(Expr),
+    /// `FOR SYSTEM_TIME BETWEEN <start> AND <end>`
+    ForSystemTimeBetween(Expr, Expr),
+    /// `FOR SYSTEM_TIME FROM <start> TO <end>`
+    ForSystemTimeFromTo(Expr, Expr),
+    /// `FOR SYSTEM_TIME CONTAINED IN (<start>, <end>)`
+    ForSystemTimeContainedIn(Expr, Expr),
+    /// `FOR SYSTEM_TIME ALL`
+    ForSystemTimeAll,
+    /// Snowflake `AT(<point>)` time travel clause.
+    ///
+    /// See <https://docs.snowflake.com/en/sql-reference/constructs/at-before>.
+    At(TimeTravelPoint),
+    /// Snowflake `BEFORE(<point>)` time travel clause.
+    ///
+    /// See <https://docs.snowflake.com/en/sql-reference/constructs/at-before>.
+    Before(TimeTravelPoint),
 }
 
 impl Display for TableVersion {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
             TableVersion::ForSystemTimeAsOf(e) => write!(f, " FOR SYSTEM_TIME AS OF {e}")?,
+            TableVersion::ForSystemTimeBetween(start, end) => {
+                write!(f, " FOR SYSTEM_TIME BETWEEN {start} AND {end}")?
+            }
+            TableVersion::ForSystemTimeFromTo(start, end) => {
+                write!(f, " FOR SYSTEM_TIME FROM {start} TO {end}")?
+            }
+            TableVersion::ForSystemTimeContainedIn(start, end) => {
+                write!(f, " FOR SYSTEM_TIME CONTAINED IN ({start}, {end})")?
+            }
+            TableVersion::ForSystemTimeAll => write!(f, " FOR SYSTEM_TIME ALL")?,
+            TableVersion::At(point) => write!(f, " AT({point})")?,
+            TableVersion::Before(point) => write!(f, " BEFORE({point})")?,
         }
         Ok(())
     }
 }
 
+/// The point in time referenced by a Snowflake `AT` / `BEFORE` clause.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+pub enum TimeTravelPoint {
+    /// `TIMESTAMP => <timestamp>`
+    Timestamp(Expr),
+    /// `OFFSET => <time_difference>`
+    Offset(Expr),
+    /// `STATEMENT => <id>`
+    Statement(Expr),
+    /// `STREAM => '<name>'`
+    Stream(Expr),
+}
+
+impl Display for TimeTravelPoint {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            TimeTravelPoint::Timestamp(e) => write!(f, "TIMESTAMP => {e}"),
+            TimeTravelPoint::Offset(e) => write!(f, "OFFSET => {e}"),
+            TimeTravelPoint::Statement(e) => write!(f, "STATEMENT => {e}"),
+            TimeTravelPoint::Stream(e) => write!(f, "STREAM => {e}"),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
@@ -1578,6 +1651,7 @@ impl fmt::Display for Join {
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+#[allow(clippy::large_enum_variant)]
 pub enum JoinOperator {
     Inner(JoinConstraint),
     LeftOuter(JoinConstraint),
@@ -1609,6 +1683,7 @@ pub enum JoinOperator {
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+#[allow(clippy::large_enum_variant)]
 pub enum JoinConstraint {
     On(Expr),
     Using(Vec<Ident>),
@@ -1797,6 +1872,7 @@ pub struct Top {
 #[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "visitor", derive(Visit, VisitMut))]
+#[allow(clippy::large_enum_variant)]
 pub enum TopQuantity {
     // A parenthesized expression. MSSQL only.
     Expr(Expr),
@@ -1876,6 +1952,9 @@ pub enum GroupByExpr {
     All,
 
     /// Expressions
+    ///
+    /// Positional references such as `GROUP BY 1` are kept as plain
+    /// number literals. An empty list means there is no `GROUP BY` clause.
     Expressions(Vec<Expr>),
 }
 
@@ -1885,7 +1964,7 @@ impl fmt::Display for GroupByExpr {
             GroupByExpr::All => write!(f, "GROUP BY ALL"),
             GroupByExpr::Expressions(col_names) => {
                 let col_names = display_comma_separated(col_names);
-                write!(f, "GROUP BY ({col_names})")
+                write!(f, "GROUP BY {col_names}")
             }
         }
     }
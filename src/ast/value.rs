@@ -52,6 +52,15 @@ pub enum Value {
     /// See [Postgres docs](https://www.postgresql.org/docs/8.3/sql-syntax-lexical.html#SQL-SYNTAX-STRINGS)
     /// for more details.
     EscapedStringLiteral(String),
+    /// U&'string value' [UESCAPE 'c'] (postgres extension), holding the
+    /// string as written with its escapes left undecoded.
+    /// See [Postgres docs](https://www.postgresql.org/docs/current/sql-syntax-lexical.html#SQL-SYNTAX-STRINGS-UESCAPE)
+    /// for more details.
+    UnicodeStringLiteral {
+        value: String,
+        /// The character given by `UESCAPE`, if any
+        escape: Option<char>,
+    },
     /// B'string value'
     SingleQuotedByteStringLiteral(String),
     /// B"string value"
@@ -78,6 +87,8 @@ pub enum Value {
     NationalStringLiteral(String),
     /// X'hex value'
     HexStringLiteral(String),
+    /// MySQL bit-value literal, e.g. `0b1010`
+    BitValueLiteral(String),
 
     DoubleQuotedString(String),
     /// Boolean value true or false
@@ -86,6 +97,8 @@ pub enum Value {
     Null,
     /// `?` or `$` Prepared statement arg placeholder
     Placeholder(String),
+    /// Bare word used as a value, e.g. `ON` in SQLite's `PRAGMA foreign_keys = ON`
+    UnQuotedString(String),
 }
 
 impl fmt::Display for Value {
@@ -102,8 +115,20 @@ impl fmt::Display for Value {
             }
             Value::DollarQuotedString(v) => write!(f, "{v}"),
             Value::EscapedStringLiteral(v) => write!(f, "E'{}'", escape_escaped_string(v)),
+            Value::UnicodeStringLiteral { value, escape } => {
+                write!(f, "U&'{}'", escape_single_quote_string(value))?;
+                if let Some(escape) = escape {
+                    write!(
+                        f,
+                        " UESCAPE '{}'",
+                        escape_single_quote_string(escape.encode_utf8(&mut [0; 4]))
+                    )?;
+                }
+                Ok(())
+            }
             Value::NationalStringLiteral(v) => write!(f, "N'{v}'"),
             Value::HexStringLiteral(v) => write!(f, "X'{v}'"),
+            Value::BitValueLiteral(v) => write!(f, "0b{v}"),
             Value::Boolean(v) => write!(f, "{v}"),
             Value::SingleQuotedByteStringLiteral(v) => write!(f, "B'{v}'"),
             Value::DoubleQuotedByteStringLiteral(v) => write!(f, "B\"{v}\""),
@@ -115,6 +140,7 @@ impl fmt::Display for Value {
             Value::TripleDoubleQuotedRawStringLiteral(v) => write!(f, r#"R"""{v}""""#),
             Value::Null => write!(f, "NULL"),
             Value::Placeholder(v) => write!(f, "{v}"),
+            Value::UnQuotedString(v) => write!(f, "{v}"),
         }
     }
 }
@@ -108,7 +108,7 @@ macro_rules! visit_noop {
     };
 }
 
-visit_noop!(u8, u16, u32, u64, i8, i16, i32, i64, char, bool, String);
+visit_noop!(u8, u16, u32, u64, usize, i8, i16, i32, i64, char, bool, String);
 
 #[cfg(feature = "bigdecimal")]
 visit_noop!(bigdecimal::BigDecimal);
@@ -531,6 +531,7 @@ where
 ///               clauses: vec![],
 ///           }),
 ///           null_treatment: None,
+///           nth_value_order: None,
 ///           filter: None,
 ///           over: None,
 ///           within_group: vec![],
@@ -640,7 +641,8 @@ where
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::dialect::GenericDialect;
+    use crate::ast::Ident;
+    use crate::dialect::{Dialect, GenericDialect, MySqlDialect};
     use crate::parser::Parser;
     use crate::tokenizer::Tokenizer;
 
@@ -722,7 +724,7 @@ mod tests {
             .unwrap();
 
         let mut visitor = TestVisitor::default();
-        s.visit(&mut visitor);
+        let _ = s.visit(&mut visitor);
         visitor.visited
     }
 
@@ -878,4 +880,34 @@ mod tests {
             assert_eq!(actual, expected)
         }
     }
+
+    #[test]
+    fn requote_identifiers_for_dialect() {
+        let dialect = MySqlDialect {};
+        let sql = "SELECT id, u.name FROM db.users AS u WHERE id = 1";
+        let mut statements = Parser::parse_sql(&dialect, sql).unwrap();
+
+        let quote = |ident: &mut Ident| {
+            if ident.quote_style.is_none() {
+                ident.quote_style = dialect.identifier_quote_style(&ident.value);
+            }
+        };
+        let _ = visit_relations_mut(&mut statements, |name| {
+            name.0.iter_mut().for_each(quote);
+            ControlFlow::<()>::Continue(())
+        });
+        let _ = visit_expressions_mut(&mut statements, |expr| {
+            match expr {
+                Expr::Identifier(ident) => quote(ident),
+                Expr::CompoundIdentifier(idents) => idents.iter_mut().for_each(quote),
+                _ => {}
+            }
+            ControlFlow::<()>::Continue(())
+        });
+
+        assert_eq!(
+            statements[0].to_string(),
+            "SELECT `id`, `u`.`name` FROM `db`.`users` AS u WHERE `id` = 1"
+        );
+    }
 }
@@ -22,6 +22,10 @@ impl Dialect for BigQueryDialect {
         ch == '`'
     }
 
+    fn identifier_quote_style(&self, _identifier: &str) -> Option<char> {
+        Some('`')
+    }
+
     fn supports_projection_trailing_commas(&self) -> bool {
         true
     }
@@ -49,6 +53,21 @@ impl Dialect for BigQueryDialect {
         true
     }
 
+    // See https://cloud.google.com/bigquery/docs/reference/standard-sql/lexical#comments
+    fn supports_hash_comment(&self) -> bool {
+        true
+    }
+
+    /// See [doc](https://cloud.google.com/bigquery/docs/reference/standard-sql/query-syntax#group_by_all)
+    fn supports_group_by_all(&self) -> bool {
+        true
+    }
+
+    /// See [doc](https://cloud.google.com/bigquery/docs/reference/standard-sql/conversion_functions#safe_casting)
+    fn supports_safe_cast(&self) -> bool {
+        true
+    }
+
     /// See [doc](https://cloud.google.com/bigquery/docs/reference/standard-sql/window-function-calls#ref_named_window)
     fn supports_window_clause_named_window_reference(&self) -> bool {
         true
@@ -63,4 +82,8 @@ impl Dialect for BigQueryDialect {
     fn supports_select_wildcard_except(&self) -> bool {
         true
     }
+
+    fn supports_numeric_literal_underscores(&self) -> bool {
+        true
+    }
 }
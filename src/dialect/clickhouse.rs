@@ -33,4 +33,13 @@ impl Dialect for ClickHouseDialect {
     fn supports_select_wildcard_except(&self) -> bool {
         true
     }
+
+    fn supports_numeric_literal_underscores(&self) -> bool {
+        true
+    }
+
+    // See https://clickhouse.com/docs/en/sql-reference/statements/select/group-by#group-by-all
+    fn supports_group_by_all(&self) -> bool {
+        true
+    }
 }
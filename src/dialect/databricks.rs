@@ -13,6 +13,10 @@ impl Dialect for DatabricksDialect {
         matches!(ch, '`')
     }
 
+    fn identifier_quote_style(&self, _identifier: &str) -> Option<char> {
+        Some('`')
+    }
+
     fn is_identifier_start(&self, ch: char) -> bool {
         matches!(ch, 'a'..='z' | 'A'..='Z' | '_')
     }
@@ -30,6 +34,16 @@ impl Dialect for DatabricksDialect {
         true
     }
 
+    // https://docs.databricks.com/en/sql/language-manual/sql-ref-syntax-qry-select-groupby.html
+    fn supports_group_by_all(&self) -> bool {
+        true
+    }
+
+    /// <https://docs.databricks.com/en/sql/language-manual/functions/try_cast.html>
+    fn supports_try_cast(&self) -> bool {
+        true
+    }
+
     fn supports_lambda_functions(&self) -> bool {
         true
     }
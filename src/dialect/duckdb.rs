@@ -38,10 +38,25 @@ impl Dialect for DuckDbDialect {
         true
     }
 
+    // https://duckdb.org/docs/sql/query_syntax/groupby.html#group-by-all
+    fn supports_group_by_all(&self) -> bool {
+        true
+    }
+
+    /// <https://duckdb.org/docs/sql/expressions/cast.html#try_cast>
+    fn supports_try_cast(&self) -> bool {
+        true
+    }
+
     fn supports_named_fn_args_with_eq_operator(&self) -> bool {
         true
     }
 
+    // https://duckdb.org/docs/sql/data_types/numeric.html
+    fn supports_numeric_literal_underscores(&self) -> bool {
+        true
+    }
+
     // DuckDB uses this syntax for `STRUCT`s.
     //
     // https://duckdb.org/docs/sql/data_types/struct.html#creating-structs
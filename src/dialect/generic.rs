@@ -39,6 +39,18 @@ impl Dialect for GenericDialect {
         true
     }
 
+    fn supports_group_by_all(&self) -> bool {
+        true
+    }
+
+    fn supports_try_cast(&self) -> bool {
+        true
+    }
+
+    fn supports_safe_cast(&self) -> bool {
+        true
+    }
+
     fn supports_connect_by(&self) -> bool {
         true
     }
@@ -70,4 +82,8 @@ impl Dialect for GenericDialect {
     fn supports_select_wildcard_except(&self) -> bool {
         true
     }
+
+    fn supports_numeric_literal_underscores(&self) -> bool {
+        true
+    }
 }
@@ -105,12 +105,20 @@ pub trait Dialect: Debug + Any {
     /// Determine if a character starts a quoted identifier. The default
     /// implementation, accepting "double quoted" ids is both ANSI-compliant
     /// and appropriate for most dialects (with the notable exception of
-    /// MySQL, MS SQL, and sqlite). You can accept one of characters listed
-    /// in `Word::matching_end_quote` here
+    /// MySQL, MS SQL, and sqlite). The tokenizer only reads delimited
+    /// identifiers that start with a character accepted here, which may be
+    /// one of `"`, `` ` `` or `[`. A doubled closing quote inside the
+    /// identifier stands for the quote character itself.
     fn is_delimited_identifier_start(&self, ch: char) -> bool {
         ch == '"' || ch == '`'
     }
-    /// Return the character used to quote identifiers.
+    /// Return the character this dialect uses to quote `identifier`, if any.
+    ///
+    /// The parser does not use this; it is meant for callers that re-quote
+    /// an AST (e.g. by setting [`Ident::quote_style`]) before printing it
+    /// for a particular target dialect.
+    ///
+    /// [`Ident::quote_style`]: crate::ast::Ident::quote_style
     fn identifier_quote_style(&self, _identifier: &str) -> Option<char> {
         None
     }
@@ -171,6 +179,19 @@ pub trait Dialect: Debug + Any {
     fn supports_group_by_expr(&self) -> bool {
         false
     }
+    /// Returns true if the dialect supports `GROUP BY ALL`, grouping by every
+    /// non-aggregate item of the projection.
+    fn supports_group_by_all(&self) -> bool {
+        false
+    }
+    /// Returns true if the dialect supports `TRY_CAST(<expr> AS <datatype>)`.
+    fn supports_try_cast(&self) -> bool {
+        false
+    }
+    /// Returns true if the dialect supports BigQuery's `SAFE_CAST(<expr> AS <datatype>)`.
+    fn supports_safe_cast(&self) -> bool {
+        false
+    }
     /// Returns true if the dialect supports CONNECT BY.
     fn supports_connect_by(&self) -> bool {
         false
@@ -196,6 +217,11 @@ pub trait Dialect: Debug + Any {
     fn supports_numeric_prefix(&self) -> bool {
         false
     }
+    /// Returns true if the dialect allows `_` as a digit separator in numeric
+    /// literals, e.g. `1_000_000`
+    fn supports_numeric_literal_underscores(&self) -> bool {
+        false
+    }
     /// Returns true if the dialects supports specifying null treatment
     /// as part of a window function's parameter list. As opposed
     /// to after the parameter list.
@@ -247,6 +273,18 @@ pub trait Dialect: Debug + Any {
     fn convert_type_before_value(&self) -> bool {
         false
     }
+    /// Returns true if the dialect treats `#` as the start of a single-line
+    /// comment, in addition to `--`
+    fn supports_hash_comment(&self) -> bool {
+        false
+    }
+    /// Returns true if `||` is the logical `OR` operator rather than string
+    /// concatenation, e.g. in MySQL without the [`PIPES_AS_CONCAT`] SQL mode
+    ///
+    /// [`PIPES_AS_CONCAT`]: https://dev.mysql.com/doc/refman/8.0/en/sql-mode.html#sqlmode_pipes_as_concat
+    fn supports_pipes_as_or(&self) -> bool {
+        false
+    }
     /// Returns true if the dialect supports triple quoted string
     /// e.g. `"""abc"""`
     fn supports_triple_quoted_string(&self) -> bool {
@@ -380,6 +418,11 @@ mod tests {
             (&GenericDialect {}, "id", None),
             (&SQLiteDialect {}, "id", Some('`')),
             (&PostgreSqlDialect {}, "id", Some('"')),
+            (&MySqlDialect {}, "id", Some('`')),
+            (&MsSqlDialect {}, "id", Some('[')),
+            (&BigQueryDialect {}, "id", Some('`')),
+            (&HiveDialect {}, "id", Some('`')),
+            (&DatabricksDialect {}, "id", Some('`')),
         ];
 
         for (dialect, ident, expected) in tests {
@@ -418,6 +461,10 @@ mod tests {
                 self.0.supports_string_literal_backslash_escape()
             }
 
+            fn supports_hash_comment(&self) -> bool {
+                self.0.supports_hash_comment()
+            }
+
             fn is_proper_identifier_inside_quotes(
                 &self,
                 chars: std::iter::Peekable<std::str::Chars<'_>>,
@@ -21,6 +21,10 @@ impl Dialect for MsSqlDialect {
         ch == '"' || ch == '['
     }
 
+    fn identifier_quote_style(&self, _identifier: &str) -> Option<char> {
+        Some('[')
+    }
+
     fn is_identifier_start(&self, ch: char) -> bool {
         // See https://docs.microsoft.com/en-us/sql/relational-databases/databases/database-identifiers?view=sql-server-2017#rules-for-regular-identifiers
         ch.is_alphabetic() || ch == '_' || ch == '#' || ch == '@'
@@ -44,4 +48,9 @@ impl Dialect for MsSqlDialect {
     fn supports_connect_by(&self) -> bool {
         true
     }
+
+    /// <https://learn.microsoft.com/en-us/sql/t-sql/functions/try-cast-transact-sql>
+    fn supports_try_cast(&self) -> bool {
+        true
+    }
 }
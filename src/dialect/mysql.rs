@@ -57,6 +57,20 @@ impl Dialect for MySqlDialect {
         true
     }
 
+    fn supports_numeric_literal_underscores(&self) -> bool {
+        true
+    }
+
+    // See https://dev.mysql.com/doc/refman/8.0/en/comments.html
+    fn supports_hash_comment(&self) -> bool {
+        true
+    }
+
+    // See https://dev.mysql.com/doc/refman/8.0/en/logical-operators.html#operator_or
+    fn supports_pipes_as_or(&self) -> bool {
+        true
+    }
+
     fn parse_infix(
         &self,
         parser: &mut crate::parser::Parser,
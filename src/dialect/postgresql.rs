@@ -78,6 +78,11 @@ impl Dialect for PostgreSqlDialect {
     fn supports_group_by_expr(&self) -> bool {
         true
     }
+
+    // https://www.postgresql.org/docs/current/sql-syntax-lexical.html#SQL-SYNTAX-CONSTANTS-NUMERIC
+    fn supports_numeric_literal_underscores(&self) -> bool {
+        true
+    }
 }
 
 pub fn parse_comment(parser: &mut Parser) -> Result<Statement, ParserError> {
@@ -86,17 +91,28 @@ pub fn parse_comment(parser: &mut Parser) -> Result<Statement, ParserError> {
     parser.expect_keyword(Keyword::ON)?;
     let token = parser.next_token();
 
-    let (object_type, object_name) = match token.token {
-        Token::Word(w) if w.keyword == Keyword::COLUMN => {
-            let object_name = parser.parse_object_name(false)?;
-            (CommentObject::Column, object_name)
-        }
-        Token::Word(w) if w.keyword == Keyword::TABLE => {
-            let object_name = parser.parse_object_name(false)?;
-            (CommentObject::Table, object_name)
-        }
+    let object_type = match &token.token {
+        Token::Word(w) => match w.keyword {
+            Keyword::COLUMN => CommentObject::Column,
+            Keyword::DATABASE => CommentObject::Database,
+            Keyword::EXTENSION => CommentObject::Extension,
+            Keyword::INDEX => CommentObject::Index,
+            Keyword::MATERIALIZED => {
+                parser.expect_keyword(Keyword::VIEW)?;
+                CommentObject::MaterializedView
+            }
+            Keyword::ROLE => CommentObject::Role,
+            Keyword::SCHEMA => CommentObject::Schema,
+            Keyword::SEQUENCE => CommentObject::Sequence,
+            Keyword::TABLE => CommentObject::Table,
+            Keyword::TYPE => CommentObject::Type,
+            Keyword::VIEW => CommentObject::View,
+            _ => parser.expected("comment object_type", token)?,
+        },
         _ => parser.expected("comment object_type", token)?,
     };
+    // `COLUMN` names may be qualified up to `schema.table.column`
+    let object_name = parser.parse_object_name(false)?;
 
     parser.expect_keyword(Keyword::IS)?;
     let comment = if parser.parse_keyword(Keyword::NULL) {
@@ -18,7 +18,8 @@ use crate::ast::helpers::stmt_data_loading::{
     StageParamsObject,
 };
 use crate::ast::{
-    CommentDef, Ident, ObjectName, RowAccessPolicy, Statement, Tag, WrappedCollection,
+    CommentDef, CreateTableLike, CreateTableLikeKind, Ident, ObjectName, RowAccessPolicy,
+    Statement, Tag, WrappedCollection,
 };
 use crate::dialect::Dialect;
 use crate::keywords::Keyword;
@@ -58,6 +59,11 @@ impl Dialect for SnowflakeDialect {
         true
     }
 
+    // Snowflake accepts `#` as a single-line comment, in addition to `--` and `//`
+    fn supports_hash_comment(&self) -> bool {
+        true
+    }
+
     fn supports_within_after_array_aggregation(&self) -> bool {
         true
     }
@@ -66,6 +72,16 @@ impl Dialect for SnowflakeDialect {
         true
     }
 
+    /// See [doc](https://docs.snowflake.com/en/sql-reference/constructs/group-by#label-group-by-all-columns)
+    fn supports_group_by_all(&self) -> bool {
+        true
+    }
+
+    /// See [doc](https://docs.snowflake.com/en/sql-reference/functions/try_cast)
+    fn supports_try_cast(&self) -> bool {
+        true
+    }
+
     fn supports_match_recognize(&self) -> bool {
         true
     }
@@ -203,7 +219,12 @@ pub fn parse_create_table(
                     break;
                 }
                 Keyword::LIKE => {
-                    let like = parser.parse_object_name(false).ok();
+                    let like = parser.parse_object_name(false).ok().map(|name| {
+                        CreateTableLikeKind::Plain(CreateTableLike {
+                            name,
+                            options: vec![],
+                        })
+                    });
                     builder = builder.like(like);
                     break;
                 }
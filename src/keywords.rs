@@ -13,14 +13,14 @@
 //! This module defines
 //! 1) a list of constants for every keyword
 //! 2) an `ALL_KEYWORDS` array with every keyword in it
-//!     This is not a list of *reserved* keywords: some of these can be
-//!     parsed as identifiers if the parser decides so. This means that
-//!     new keywords can be added here without affecting the parse result.
+//!    This is not a list of *reserved* keywords: some of these can be
+//!    parsed as identifiers if the parser decides so. This means that
+//!    new keywords can be added here without affecting the parse result.
 //!
-//!     As a matter of fact, most of these keywords are not used at all
-//!     and could be removed.
+//!    As a matter of fact, most of these keywords are not used at all
+//!    and could be removed.
 //! 3) a `RESERVED_FOR_TABLE_ALIAS` array with keywords reserved in a
-//! "table alias" context.
+//!    "table alias" context.
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -69,6 +69,7 @@ macro_rules! define_keywords {
 define_keywords!(
     ABORT,
     ABS,
+    ABSENT,
     ABSOLUTE,
     ACCESS,
     ACTION,
@@ -107,6 +108,7 @@ define_keywords!(
     AVRO,
     BACKWARD,
     BASE64,
+    BEFORE,
     BEGIN,
     BEGIN_FRAME,
     BEGIN_PARTITION,
@@ -163,6 +165,7 @@ define_keywords!(
     COMMENT,
     COMMIT,
     COMMITTED,
+    COMPOUND,
     COMPRESSION,
     COMPUTE,
     CONCURRENTLY,
@@ -171,7 +174,9 @@ define_keywords!(
     CONNECT,
     CONNECTION,
     CONSTRAINT,
+    CONTAINED,
     CONTAINS,
+    CONTINUE,
     CONVERT,
     COPY,
     COPY_OPTIONS,
@@ -204,11 +209,13 @@ define_keywords!(
     CYCLE,
     DATA,
     DATABASE,
+    DATABASES,
     DATA_RETENTION_TIME_IN_DAYS,
     DATE,
     DATE32,
     DATETIME,
     DATETIME64,
+    DATE_PART,
     DAY,
     DAYOFWEEK,
     DAYOFYEAR,
@@ -240,7 +247,9 @@ define_keywords!(
     DISCARD,
     DISCONNECT,
     DISTINCT,
+    DISTKEY,
     DISTRIBUTE,
+    DISTSTYLE,
     DIV,
     DO,
     DOUBLE,
@@ -257,6 +266,7 @@ define_keywords!(
     EMPTY,
     ENABLE,
     ENABLE_SCHEMA_EVOLUTION,
+    ENCODE,
     ENCODING,
     ENCRYPTION,
     END,
@@ -272,11 +282,13 @@ define_keywords!(
     ERROR,
     ESCAPE,
     ESCAPED,
+    EVEN,
     EVENT,
     EVERY,
     EXCEPT,
     EXCEPTION,
     EXCLUDE,
+    EXCLUDING,
     EXCLUSIVE,
     EXEC,
     EXECUTE,
@@ -320,6 +332,7 @@ define_keywords!(
     FORWARD,
     FRAME_ROW,
     FREE,
+    FREETEXT,
     FREEZE,
     FROM,
     FSCK,
@@ -360,10 +373,12 @@ define_keywords!(
     IN,
     INCLUDE,
     INCLUDE_NULL_VALUES,
+    INCLUDING,
     INCREMENT,
     INDEX,
     INDICATOR,
     INHERIT,
+    INHERITS,
     INITIALLY,
     INNER,
     INOUT,
@@ -382,10 +397,12 @@ define_keywords!(
     INT64,
     INT8,
     INTEGER,
+    INTERLEAVED,
     INTERSECT,
     INTERSECTION,
     INTERVAL,
     INTO,
+    INVISIBLE,
     IS,
     ISODOW,
     ISOLATION,
@@ -397,6 +414,8 @@ define_keywords!(
     JSON,
     JSONB,
     JSONFILE,
+    JSON_ARRAY,
+    JSON_OBJECT,
     JSON_TABLE,
     JULIAN,
     KEY,
@@ -416,6 +435,7 @@ define_keywords!(
     LIKE_REGEX,
     LIMIT,
     LINES,
+    LIST,
     LN,
     LOAD,
     LOCAL,
@@ -461,6 +481,7 @@ define_keywords!(
     MODIFIES,
     MODIFY,
     MODULE,
+    MODULUS,
     MONTH,
     MSCK,
     MULTISET,
@@ -530,6 +551,7 @@ define_keywords!(
     PARALLEL,
     PARAMETER,
     PARQUET,
+    PARTIAL,
     PARTITION,
     PARTITIONED,
     PARTITIONS,
@@ -580,6 +602,7 @@ define_keywords!(
     REF,
     REFERENCES,
     REFERENCING,
+    REFRESH,
     REGCLASS,
     REGEXP,
     REGR_AVGX,
@@ -594,6 +617,7 @@ define_keywords!(
     RELATIVE,
     RELAY,
     RELEASE,
+    REMAINDER,
     REMOTE,
     RENAME,
     REORG,
@@ -653,12 +677,14 @@ define_keywords!(
     SHARE,
     SHOW,
     SIMILAR,
+    SIMPLE,
     SKIP,
     SLOW,
     SMALLINT,
     SNAPSHOT,
     SOME,
     SORT,
+    SORTKEY,
     SOURCE,
     SPATIAL,
     SPECIFIC,
@@ -671,6 +697,7 @@ define_keywords!(
     STABLE,
     STAGE,
     START,
+    STATEMENT,
     STATIC,
     STATISTICS,
     STATUS,
@@ -680,6 +707,7 @@ define_keywords!(
     STDOUT,
     STORAGE_INTEGRATION,
     STORED,
+    STREAM,
     STRICT,
     STRING,
     STRUCT,
@@ -784,7 +812,9 @@ define_keywords!(
     VERSIONING,
     VIEW,
     VIRTUAL,
+    VISIBLE,
     VOLATILE,
+    WAREHOUSE,
     WEEK,
     WHEN,
     WHENEVER,
@@ -799,6 +829,7 @@ define_keywords!(
     WRITE,
     XML,
     XOR,
+    YAML,
     YEAR,
     ZONE,
     ZORDER
@@ -146,7 +146,7 @@ impl<'a> Parser<'a> {
         })
     }
 
-    fn parse_pg_role_option(&mut self) -> Result<RoleOption, ParserError> {
+    pub(super) fn parse_pg_role_option(&mut self) -> Result<RoleOption, ParserError> {
         let option = match self.parse_one_of_keywords(&[
             Keyword::BYPASSRLS,
             Keyword::NOBYPASSRLS,
@@ -202,3 +202,20 @@ impl<'a> Parser<'a> {
         Ok(option)
     }
 }
+
+/// The keyword(s) introducing a [`RoleOption`], used to report options that
+/// were specified more than once.
+pub(super) fn role_option_keywords(option: &RoleOption) -> &'static str {
+    match option {
+        RoleOption::BypassRLS(_) => "BYPASSRLS or NOBYPASSRLS",
+        RoleOption::ConnectionLimit(_) => "CONNECTION LIMIT",
+        RoleOption::CreateDB(_) => "CREATEDB or NOCREATEDB",
+        RoleOption::CreateRole(_) => "CREATEROLE or NOCREATEROLE",
+        RoleOption::Inherit(_) => "INHERIT or NOINHERIT",
+        RoleOption::Login(_) => "LOGIN or NOLOGIN",
+        RoleOption::Password(_) => "PASSWORD",
+        RoleOption::Replication(_) => "REPLICATION or NOREPLICATION",
+        RoleOption::SuperUser(_) => "SUPERUSER or NOSUPERUSER",
+        RoleOption::ValidUntil(_) => "VALID UNTIL",
+    }
+}
@@ -27,7 +27,9 @@ use log::debug;
 use IsLateral::*;
 use IsOptional::*;
 
-use crate::ast::helpers::stmt_create_table::{BigQueryTableConfiguration, CreateTableBuilder};
+use crate::ast::helpers::stmt_create_table::{
+    BigQueryTableConfiguration, CreateTableBuilder, RedshiftTableConfiguration,
+};
 use crate::ast::*;
 use crate::dialect::*;
 use crate::keywords::{Keyword, ALL_KEYWORDS};
@@ -156,6 +158,7 @@ pub enum IsLateral {
     NotLateral,
 }
 
+#[allow(clippy::large_enum_variant)]
 pub enum WildcardExpr {
     Expr(Expr),
     QualifiedWildcard(ObjectName),
@@ -424,10 +427,10 @@ impl<'a> Parser<'a> {
                 Token::EOF => break,
 
                 // end of statement
-                Token::Word(word) => {
-                    if expecting_statement_delimiter && word.keyword == Keyword::END {
-                        break;
-                    }
+                Token::Word(word)
+                    if expecting_statement_delimiter && word.keyword == Keyword::END =>
+                {
+                    break;
                 }
                 _ => {}
             }
@@ -443,6 +446,70 @@ impl<'a> Parser<'a> {
         Ok(stmts)
     }
 
+    /// Parse potentially multiple statements, recovering from errors
+    ///
+    /// Unlike [`Parser::parse_statements`], an error in one statement does
+    /// not abort the parse: the error is recorded, the remaining tokens of
+    /// the statement are skipped up to the next `;`, and parsing resumes
+    /// with the following statement. Returns the statements that parsed
+    /// successfully along with every error encountered.
+    ///
+    /// Example
+    /// ```
+    /// # use sqlparser::{parser::{Parser, ParserError}, dialect::GenericDialect};
+    /// # fn main() -> Result<(), ParserError> {
+    /// let dialect = GenericDialect{};
+    /// let (statements, errors) = Parser::new(&dialect)
+    ///   .try_with_sql("SELECT * FROM foo; SELECT * FRM bar; SELECT 1")?
+    ///   .try_parse_statements();
+    /// assert_eq!(statements.len(), 2);
+    /// assert_eq!(errors.len(), 1);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn try_parse_statements(&mut self) -> (Vec<Statement>, Vec<ParserError>) {
+        let mut stmts = Vec::new();
+        let mut errors = Vec::new();
+        loop {
+            // ignore empty statements (between successive statement delimiters)
+            while self.consume_token(&Token::SemiColon) {}
+
+            if self.peek_token().token == Token::EOF {
+                break;
+            }
+
+            let result = self.parse_statement().and_then(|statement| {
+                // a statement is only complete once its delimiter is reached
+                match self.peek_token().token {
+                    Token::SemiColon | Token::EOF => Ok(statement),
+                    _ => self.expected("end of statement", self.peek_token()),
+                }
+            });
+
+            match result {
+                Ok(statement) => stmts.push(statement),
+                Err(err) => {
+                    errors.push(err);
+                    self.skip_to_statement_delimiter();
+                }
+            }
+        }
+        (stmts, errors)
+    }
+
+    /// Advances past the remaining tokens of the current statement, leaving
+    /// the parser positioned at the next `;` (or at the end of input).
+    fn skip_to_statement_delimiter(&mut self) {
+        loop {
+            match self.peek_token().token {
+                Token::SemiColon | Token::EOF => break,
+                _ => {
+                    self.next_token();
+                }
+            }
+        }
+    }
+
     /// Convenience method to parse a string with one or more SQL
     /// statements into produce an Abstract Syntax Tree (AST).
     ///
@@ -493,14 +560,18 @@ impl<'a> Parser<'a> {
                         Ok(self.parse_attach_database()?)
                     }
                 }
-                Keyword::DETACH if dialect_of!(self is DuckDbDialect | GenericDialect) => {
+                Keyword::DETACH if dialect_of!(self is DuckDbDialect | SQLiteDialect | GenericDialect) => {
                     Ok(self.parse_detach_duckdb_database()?)
                 }
                 Keyword::MSCK => Ok(self.parse_msck()?),
+                Keyword::VACUUM if dialect_of!(self is PostgreSqlDialect | SQLiteDialect | GenericDialect) => {
+                    Ok(self.parse_vacuum()?)
+                }
                 Keyword::CREATE => Ok(self.parse_create()?),
                 Keyword::CACHE => Ok(self.parse_cache_table()?),
                 Keyword::DROP => Ok(self.parse_drop()?),
                 Keyword::DISCARD => Ok(self.parse_discard()?),
+                Keyword::REFRESH => Ok(self.parse_refresh()?),
                 Keyword::DECLARE => Ok(self.parse_declare()?),
                 Keyword::FETCH => Ok(self.parse_fetch_statement()?),
                 Keyword::DELETE => Ok(self.parse_delete()?),
@@ -537,8 +608,11 @@ impl<'a> Parser<'a> {
                 Keyword::EXECUTE => Ok(self.parse_execute()?),
                 Keyword::PREPARE => Ok(self.parse_prepare()?),
                 Keyword::MERGE => Ok(self.parse_merge()?),
-                // `PRAGMA` is sqlite specific https://www.sqlite.org/pragma.html
-                Keyword::PRAGMA => Ok(self.parse_pragma()?),
+                // `PRAGMA` is sqlite and duckdb specific https://www.sqlite.org/pragma.html
+                // https://duckdb.org/docs/configuration/pragmas
+                Keyword::PRAGMA if dialect_of!(self is SQLiteDialect | DuckDbDialect | GenericDialect) => {
+                    Ok(self.parse_pragma()?)
+                }
                 Keyword::UNLOAD => Ok(self.parse_unload()?),
                 // `INSTALL` is duckdb specific https://duckdb.org/docs/extensions/overview
                 Keyword::INSTALL if dialect_of!(self is DuckDbDialect | GenericDialect) => {
@@ -674,7 +748,27 @@ impl<'a> Parser<'a> {
 
     pub fn parse_truncate(&mut self) -> Result<Statement, ParserError> {
         let table = self.parse_keyword(Keyword::TABLE);
-        let table_name = self.parse_object_name(false)?;
+        let table_names = self.parse_comma_separated(Parser::parse_truncate_table_target)?;
+
+        let mut identity = None;
+        let mut cascade = None;
+        if dialect_of!(self is PostgreSqlDialect | GenericDialect) {
+            identity = if self.parse_keywords(&[Keyword::RESTART, Keyword::IDENTITY]) {
+                Some(TruncateIdentityOption::Restart)
+            } else if self.parse_keywords(&[Keyword::CONTINUE, Keyword::IDENTITY]) {
+                Some(TruncateIdentityOption::Continue)
+            } else {
+                None
+            };
+            cascade = if self.parse_keyword(Keyword::CASCADE) {
+                Some(CascadeOption::Cascade)
+            } else if self.parse_keyword(Keyword::RESTRICT) {
+                Some(CascadeOption::Restrict)
+            } else {
+                None
+            };
+        }
+
         let mut partitions = None;
         if self.parse_keyword(Keyword::PARTITION) {
             self.expect_token(&Token::LParen)?;
@@ -682,9 +776,25 @@ impl<'a> Parser<'a> {
             self.expect_token(&Token::RParen)?;
         }
         Ok(Statement::Truncate {
-            table_name,
+            table_names,
             partitions,
             table,
+            identity,
+            cascade,
+        })
+    }
+
+    /// Parse a table named in a `TRUNCATE` statement, along with the
+    /// PostgreSQL `ONLY` and `*` descendant table markers
+    fn parse_truncate_table_target(&mut self) -> Result<TruncateTableTarget, ParserError> {
+        let pg = dialect_of!(self is PostgreSqlDialect | GenericDialect);
+        let only = pg && self.parse_keyword(Keyword::ONLY);
+        let name = self.parse_object_name(false)?;
+        let descendants = pg && !only && self.consume_token(&Token::Mul);
+        Ok(TruncateTableTarget {
+            only,
+            name,
+            descendants,
         })
     }
 
@@ -745,7 +855,9 @@ impl<'a> Parser<'a> {
 
     pub fn parse_detach_duckdb_database(&mut self) -> Result<Statement, ParserError> {
         let database = self.parse_keyword(Keyword::DATABASE);
-        let if_exists = self.parse_keywords(&[Keyword::IF, Keyword::EXISTS]);
+        // SQLite has no `IF EXISTS` here
+        let if_exists = !dialect_of!(self is SQLiteDialect)
+            && self.parse_keywords(&[Keyword::IF, Keyword::EXISTS]);
         let database_alias = self.parse_identifier(false)?;
         Ok(Statement::DetachDuckDBDatabase {
             if_exists,
@@ -766,6 +878,67 @@ impl<'a> Parser<'a> {
         })
     }
 
+    /// Parse a `VACUUM` statement, assuming the `VACUUM` keyword was consumed
+    pub fn parse_vacuum(&mut self) -> Result<Statement, ParserError> {
+        let parenthesized_options = self.consume_token(&Token::LParen);
+        let options = if parenthesized_options {
+            let options = self.parse_comma_separated(|p| {
+                let name = p.parse_identifier(false)?;
+                let value = match p.peek_token().token {
+                    Token::Comma | Token::RParen => None,
+                    _ => Some(p.parse_expr()?),
+                };
+                Ok(VacuumOption { name, value })
+            })?;
+            self.expect_token(&Token::RParen)?;
+            options
+        } else {
+            // The legacy form only allows these keywords, in this order
+            let mut options = vec![];
+            for keyword in [
+                Keyword::FULL,
+                Keyword::FREEZE,
+                Keyword::VERBOSE,
+                Keyword::ANALYZE,
+            ] {
+                match self.peek_token().token {
+                    Token::Word(w) if w.keyword == keyword => {
+                        self.next_token();
+                        options.push(VacuumOption {
+                            name: w.to_ident(),
+                            value: None,
+                        });
+                    }
+                    _ => {}
+                }
+            }
+            options
+        };
+
+        let tables = match self.peek_token().token {
+            Token::EOF | Token::SemiColon => vec![],
+            Token::Word(w) if w.keyword == Keyword::INTO => vec![],
+            _ => self.parse_comma_separated(|p| {
+                let name = p.parse_object_name(false)?;
+                let columns = p.parse_parenthesized_column_list(Optional, false)?;
+                Ok(VacuumTable { name, columns })
+            })?,
+        };
+
+        let into = if self.parse_keyword(Keyword::INTO) {
+            Some(self.parse_expr()?)
+        } else {
+            None
+        };
+
+        Ok(Statement::Vacuum {
+            options,
+            parenthesized_options,
+            tables,
+            into,
+        })
+    }
+
     pub fn parse_analyze(&mut self) -> Result<Statement, ParserError> {
         self.expect_keyword(Keyword::TABLE)?;
         let table_name = self.parse_object_name(false)?;
@@ -828,29 +1001,28 @@ impl<'a> Parser<'a> {
 
         let next_token = self.next_token();
         match next_token.token {
-            t @ (Token::Word(_) | Token::SingleQuotedString(_)) => {
-                if self.peek_token().token == Token::Period {
-                    let mut id_parts: Vec<Ident> = vec![match t {
-                        Token::Word(w) => w.to_ident(),
-                        Token::SingleQuotedString(s) => Ident::with_quote('\'', s),
-                        _ => unreachable!(), // We matched above
-                    }];
-
-                    while self.consume_token(&Token::Period) {
-                        let next_token = self.next_token();
-                        match next_token.token {
-                            Token::Word(w) => id_parts.push(w.to_ident()),
-                            Token::SingleQuotedString(s) => {
-                                // SQLite has single-quoted identifiers
-                                id_parts.push(Ident::with_quote('\'', s))
-                            }
-                            Token::Mul => {
-                                return Ok(Expr::QualifiedWildcard(ObjectName(id_parts)));
-                            }
-                            _ => {
-                                return self
-                                    .expected("an identifier or a '*' after '.'", next_token);
-                            }
+            t @ (Token::Word(_) | Token::SingleQuotedString(_))
+                if self.peek_token().token == Token::Period =>
+            {
+                let mut id_parts: Vec<Ident> = vec![match t {
+                    Token::Word(w) => w.to_ident(),
+                    Token::SingleQuotedString(s) => Ident::with_quote('\'', s),
+                    _ => unreachable!(), // We matched above
+                }];
+
+                while self.consume_token(&Token::Period) {
+                    let next_token = self.next_token();
+                    match next_token.token {
+                        Token::Word(w) => id_parts.push(w.to_ident()),
+                        Token::SingleQuotedString(s) => {
+                            // SQLite has single-quoted identifiers
+                            id_parts.push(Ident::with_quote('\'', s))
+                        }
+                        Token::Mul => {
+                            return Ok(Expr::QualifiedWildcard(ObjectName(id_parts)));
+                        }
+                        _ => {
+                            return self.expected("an identifier or a '*' after '.'", next_token);
                         }
                     }
                 }
@@ -936,10 +1108,13 @@ impl<'a> Parser<'a> {
     }
 
     pub fn parse_release(&mut self) -> Result<Statement, ParserError> {
-        let _ = self.parse_keyword(Keyword::SAVEPOINT);
+        let savepoint_keyword = self.parse_keyword(Keyword::SAVEPOINT);
         let name = self.parse_identifier(false)?;
 
-        Ok(Statement::ReleaseSavepoint { name })
+        Ok(Statement::ReleaseSavepoint {
+            name,
+            savepoint_keyword,
+        })
     }
 
     /// Parse an expression prefix
@@ -1001,6 +1176,7 @@ impl<'a> Parser<'a> {
                         name: ObjectName(vec![w.to_ident()]),
                         args: FunctionArguments::None,
                         null_treatment: None,
+                        nth_value_order: None,
                         filter: None,
                         over: None,
                         within_group: vec![],
@@ -1016,8 +1192,12 @@ impl<'a> Parser<'a> {
                 Keyword::CASE => self.parse_case_expr(),
                 Keyword::CONVERT => self.parse_convert_expr(),
                 Keyword::CAST => self.parse_cast_expr(CastKind::Cast),
-                Keyword::TRY_CAST => self.parse_cast_expr(CastKind::TryCast),
-                Keyword::SAFE_CAST => self.parse_cast_expr(CastKind::SafeCast),
+                Keyword::TRY_CAST if self.dialect.supports_try_cast() => {
+                    self.parse_cast_expr(CastKind::TryCast)
+                }
+                Keyword::SAFE_CAST if self.dialect.supports_safe_cast() => {
+                    self.parse_cast_expr(CastKind::SafeCast)
+                }
                 Keyword::EXISTS
                     // Support parsing Databricks has a function named `exists`.
                     if !dialect_of!(self is DatabricksDialect)
@@ -1032,6 +1212,16 @@ impl<'a> Parser<'a> {
                     self.parse_exists_expr(false)
                 }
                 Keyword::EXTRACT => self.parse_extract_expr(),
+                Keyword::DATE_PART
+                    if dialect_of!(self is PostgreSqlDialect | GenericDialect)
+                        && self.peek_token() == Token::LParen
+                        && matches!(
+                            self.peek_nth_token(1).token,
+                            Token::SingleQuotedString(_)
+                        ) =>
+                {
+                    self.parse_date_part_expr()
+                }
                 Keyword::CEIL => self.parse_ceil_floor_expr(true),
                 Keyword::FLOOR => self.parse_ceil_floor_expr(false),
                 Keyword::POSITION if self.peek_token().token == Token::LParen => {
@@ -1041,6 +1231,17 @@ impl<'a> Parser<'a> {
                 Keyword::OVERLAY => self.parse_overlay_expr(),
                 Keyword::TRIM => self.parse_trim_expr(),
                 Keyword::INTERVAL => self.parse_interval(),
+                Keyword::JSON_OBJECT if self.peek_token().token == Token::LParen => {
+                    // MySQL spells `JSON_OBJECT` as a plain function taking
+                    // alternating keys and values, so fall back to that.
+                    match self.maybe_parse(|parser| parser.parse_json_object()) {
+                        Some(expr) => Ok(expr),
+                        None => self.parse_function(ObjectName(vec![w.to_ident()])),
+                    }
+                }
+                Keyword::JSON_ARRAY if self.peek_token().token == Token::LParen => {
+                    self.parse_json_array()
+                }
                 // Treat ARRAY[1,2,3] as an array [1,2,3], otherwise try as subquery or a function call
                 Keyword::ARRAY if self.peek_token() == Token::LBracket => {
                     self.expect_token(&Token::LBracket)?;
@@ -1058,6 +1259,32 @@ impl<'a> Parser<'a> {
                         args: FunctionArguments::Subquery(query),
                         filter: None,
                         null_treatment: None,
+                        nth_value_order: None,
+                        over: None,
+                        within_group: vec![],
+                    }))
+                }
+                // `MULTISET(SELECT ...)` is a multiset value constructor, like `ARRAY(SELECT ...)`
+                Keyword::MULTISET
+                    if dialect_of!(self is GenericDialect | AnsiDialect)
+                        && self.peek_token() == Token::LParen
+                        && matches!(
+                            self.peek_nth_token(1).token,
+                            Token::Word(Word {
+                                keyword: Keyword::SELECT | Keyword::WITH,
+                                ..
+                            })
+                        ) =>
+                {
+                    self.expect_token(&Token::LParen)?;
+                    let query = self.parse_boxed_query()?;
+                    self.expect_token(&Token::RParen)?;
+                    Ok(Expr::Function(Function {
+                        name: ObjectName(vec![w.to_ident()]),
+                        args: FunctionArguments::Subquery(query),
+                        filter: None,
+                        null_treatment: None,
+                        nth_value_order: None,
                         over: None,
                         within_group: vec![],
                     }))
@@ -1066,6 +1293,16 @@ impl<'a> Parser<'a> {
                 Keyword::MATCH if dialect_of!(self is MySqlDialect | GenericDialect) => {
                     self.parse_match_against()
                 }
+                Keyword::CONTAINS | Keyword::FREETEXT
+                    if dialect_of!(self is MsSqlDialect) && self.peek_token() == Token::LParen =>
+                {
+                    let kind = if w.keyword == Keyword::CONTAINS {
+                        FullTextPredicateKind::Contains
+                    } else {
+                        FullTextPredicateKind::Freetext
+                    };
+                    self.parse_full_text_predicate(kind)
+                }
                 Keyword::STRUCT if dialect_of!(self is BigQueryDialect | GenericDialect) => {
                     self.prev_token();
                     self.parse_bigquery_struct_literal()
@@ -1179,7 +1416,7 @@ impl<'a> Parser<'a> {
                     expr: Box::new(self.parse_subexpr(Self::PLUS_MINUS_PREC)?),
                 })
             }
-            Token::EscapedStringLiteral(_) if dialect_of!(self is PostgreSqlDialect | GenericDialect) =>
+            Token::EscapedStringLiteral(_) | Token::UnicodeStringLiteral(_) if dialect_of!(self is PostgreSqlDialect | GenericDialect) =>
             {
                 self.prev_token();
                 Ok(Expr::Value(self.parse_value()?))
@@ -1199,7 +1436,8 @@ impl<'a> Parser<'a> {
             | Token::TripleSingleQuotedRawStringLiteral(_)
             | Token::TripleDoubleQuotedRawStringLiteral(_)
             | Token::NationalStringLiteral(_)
-            | Token::HexStringLiteral(_) => {
+            | Token::HexStringLiteral(_)
+            | Token::BitValueLiteral(_) => {
                 self.prev_token();
                 Ok(Expr::Value(self.parse_value()?))
             }
@@ -1222,20 +1460,7 @@ impl<'a> Parser<'a> {
                 if !self.consume_token(&Token::Period) {
                     Ok(expr)
                 } else {
-                    let tok = self.next_token();
-                    let key = match tok.token {
-                        Token::Word(word) => word.to_ident(),
-                        _ => {
-                            return parser_err!(
-                                format!("Expected identifier, found: {tok}"),
-                                tok.location
-                            )
-                        }
-                    };
-                    Ok(Expr::CompositeAccess {
-                        expr: Box::new(expr),
-                        key,
-                    })
+                    self.parse_composite_access(expr)
                 }
             }
             Token::Placeholder(_) | Token::Colon | Token::AtSign => {
@@ -1249,14 +1474,7 @@ impl<'a> Parser<'a> {
             _ => self.expected("an expression:", next_token),
         }?;
 
-        if self.parse_keyword(Keyword::COLLATE) {
-            Ok(Expr::Collate {
-                expr: Box::new(expr),
-                collation: self.parse_object_name(false)?,
-            })
-        } else {
-            Ok(expr)
-        }
+        Ok(expr)
     }
 
     fn try_parse_lambda(&mut self) -> Option<Expr> {
@@ -1293,12 +1511,14 @@ impl<'a> Parser<'a> {
                 args: FunctionArguments::Subquery(subquery),
                 filter: None,
                 null_treatment: None,
+                nth_value_order: None,
                 over: None,
                 within_group: vec![],
             }));
         }
 
         let args = self.parse_function_argument_list()?;
+        let nth_value_order = self.parse_nth_value_order(&name);
 
         let within_group = if self.parse_keywords(&[Keyword::WITHIN, Keyword::GROUP]) {
             self.expect_token(&Token::LParen)?;
@@ -1349,12 +1569,48 @@ impl<'a> Parser<'a> {
             name,
             args: FunctionArguments::List(args),
             null_treatment,
+            nth_value_order,
             filter,
             over,
             within_group,
         }))
     }
 
+    /// Optionally parses the `FROM { FIRST | LAST }` modifier of `NTH_VALUE`.
+    ///
+    /// The modifier is only consumed when a null treatment or `OVER` follows,
+    /// so that e.g. `SELECT NTH_VALUE(a, 2) FROM last` still reads `last` as
+    /// a table name.
+    fn parse_nth_value_order(&mut self, name: &ObjectName) -> Option<NthValueOrder> {
+        if !matches!(
+            name.0.last(),
+            Some(ident) if ident.quote_style.is_none() && ident.value.eq_ignore_ascii_case("NTH_VALUE")
+        ) {
+            return None;
+        }
+        let order = match (self.peek_token().token, self.peek_nth_token(1).token) {
+            (Token::Word(w), Token::Word(w2)) if w.keyword == Keyword::FROM => match w2.keyword {
+                Keyword::FIRST => NthValueOrder::FromFirst,
+                Keyword::LAST => NthValueOrder::FromLast,
+                _ => return None,
+            },
+            _ => return None,
+        };
+        match self.peek_nth_token(2).token {
+            Token::Word(w)
+                if matches!(
+                    w.keyword,
+                    Keyword::OVER | Keyword::IGNORE | Keyword::RESPECT
+                ) =>
+            {
+                self.next_token();
+                self.next_token();
+                Some(order)
+            }
+            _ => None,
+        }
+    }
+
     /// Optionally parses a null treatment clause.
     fn parse_null_treatment(&mut self) -> Result<Option<NullTreatment>, ParserError> {
         match self.parse_one_of_keywords(&[Keyword::RESPECT, Keyword::IGNORE]) {
@@ -1383,6 +1639,7 @@ impl<'a> Parser<'a> {
             filter: None,
             over: None,
             null_treatment: None,
+            nth_value_order: None,
             within_group: vec![],
         }))
     }
@@ -1446,7 +1703,7 @@ impl<'a> Parser<'a> {
         if self.dialect.supports_group_by_expr() {
             if self.parse_keywords(&[Keyword::GROUPING, Keyword::SETS]) {
                 self.expect_token(&Token::LParen)?;
-                let result = self.parse_comma_separated(|p| p.parse_tuple(false, true))?;
+                let result = self.parse_comma_separated(Parser::parse_grouping_set)?;
                 self.expect_token(&Token::RParen)?;
                 Ok(Expr::GroupingSets(result))
             } else if self.parse_keyword(Keyword::CUBE) {
@@ -1459,6 +1716,9 @@ impl<'a> Parser<'a> {
                 let result = self.parse_comma_separated(|p| p.parse_tuple(true, true))?;
                 self.expect_token(&Token::RParen)?;
                 Ok(Expr::Rollup(result))
+            } else if self.consume_tokens(&[Token::LParen, Token::RParen]) {
+                // the empty grouping set, i.e. the grand total
+                Ok(Expr::Tuple(vec![]))
             } else {
                 self.parse_expr()
             }
@@ -1468,6 +1728,17 @@ impl<'a> Parser<'a> {
         }
     }
 
+    /// Parse a single element of `GROUPING SETS (...)`: a parenthesized,
+    /// possibly empty, expression list, a bare expression, or a nested
+    /// `GROUPING SETS`, `CUBE` or `ROLLUP`.
+    fn parse_grouping_set(&mut self) -> Result<OneOrManyWithParens<Expr>, ParserError> {
+        if self.peek_token().token == Token::LParen {
+            Ok(OneOrManyWithParens::Many(self.parse_tuple(false, true)?))
+        } else {
+            Ok(OneOrManyWithParens::One(self.parse_group_by_expr()?))
+        }
+    }
+
     /// parse a tuple with `(` and `)`.
     /// If `lift_singleton` is true, then a singleton tuple is lifted to a tuple of length 1, otherwise it will fail.
     /// If `allow_empty` is true, then an empty tuple is allowed.
@@ -1650,6 +1921,31 @@ impl<'a> Parser<'a> {
         })
     }
 
+    /// Parse PostgreSQL `DATE_PART('field', <expr>)`, with the field given as a string
+    pub fn parse_date_part_expr(&mut self) -> Result<Expr, ParserError> {
+        self.expect_token(&Token::LParen)?;
+        let next_token = self.next_token();
+        let field = match &next_token.token {
+            Token::SingleQuotedString(s) => {
+                // The string names one of the fields accepted by `EXTRACT`
+                let mut parser =
+                    Parser::new(self.dialect).with_tokens(vec![Token::make_word(s, None)]);
+                match parser.parse_date_time_field() {
+                    Ok(field) if parser.peek_token() == Token::EOF => field,
+                    _ => return self.expected("date/time field", next_token),
+                }
+            }
+            _ => return self.expected("date/time field", next_token),
+        };
+        self.expect_token(&Token::Comma)?;
+        let expr = self.parse_expr()?;
+        self.expect_token(&Token::RParen)?;
+        Ok(Expr::DatePart {
+            field,
+            expr: Box::new(expr),
+        })
+    }
+
     pub fn parse_ceil_floor_expr(&mut self, is_ceil: bool) -> Result<Expr, ParserError> {
         self.expect_token(&Token::LParen)?;
         let expr = self.parse_expr()?;
@@ -1750,10 +2046,7 @@ impl<'a> Parser<'a> {
         self.expect_token(&Token::LParen)?;
         let mut trim_where = None;
         if let Token::Word(word) = self.peek_token().token {
-            if [Keyword::BOTH, Keyword::LEADING, Keyword::TRAILING]
-                .iter()
-                .any(|d| word.keyword == *d)
-            {
+            if [Keyword::BOTH, Keyword::LEADING, Keyword::TRAILING].contains(&word.keyword) {
                 trim_where = Some(self.parse_trim_where()?);
             }
         }
@@ -1988,6 +2281,40 @@ impl<'a> Parser<'a> {
         })
     }
 
+    /// Parse the arguments of an `MSSQL` `CONTAINS` or `FREETEXT` predicate,
+    /// the keyword having already been consumed.
+    pub fn parse_full_text_predicate(
+        &mut self,
+        kind: FullTextPredicateKind,
+    ) -> Result<Expr, ParserError> {
+        self.expect_token(&Token::LParen)?;
+        let columns = if self.consume_token(&Token::Mul) {
+            vec![]
+        } else if self.consume_token(&Token::LParen) {
+            let columns = self.parse_comma_separated(|p| p.parse_object_name(false))?;
+            self.expect_token(&Token::RParen)?;
+            columns
+        } else {
+            vec![self.parse_object_name(false)?]
+        };
+        self.expect_token(&Token::Comma)?;
+        let search_condition = Box::new(self.parse_expr()?);
+        let language = if self.consume_token(&Token::Comma) {
+            self.expect_keyword(Keyword::LANGUAGE)?;
+            Some(Box::new(self.parse_expr()?))
+        } else {
+            None
+        };
+        self.expect_token(&Token::RParen)?;
+
+        Ok(Expr::FullTextPredicate {
+            kind,
+            columns,
+            search_condition,
+            language,
+        })
+    }
+
     /// Parse an INTERVAL expression.
     ///
     /// Some syntactically valid intervals:
@@ -2047,8 +2374,7 @@ impl<'a> Parser<'a> {
                     Keyword::TIMEZONE_HOUR,
                     Keyword::TIMEZONE_MINUTE,
                 ]
-                .iter()
-                .any(|d| kw.keyword == *d) =>
+                .contains(&kw.keyword) =>
             {
                 Some(self.parse_date_time_field()?)
             }
@@ -2269,6 +2595,90 @@ impl<'a> Parser<'a> {
         })
     }
 
+    /// Parse a SQL:2016 `JSON_OBJECT` constructor, assuming the `JSON_OBJECT`
+    /// keyword has already been consumed.
+    ///
+    /// Syntax:
+    /// ```sql
+    /// JSON_OBJECT([[KEY] <key> VALUE <value> | <key> : <value>, ...] [{NULL | ABSENT} ON NULL])
+    /// ```
+    pub fn parse_json_object(&mut self) -> Result<Expr, ParserError> {
+        self.expect_token(&Token::LParen)?;
+        let mut entries = vec![];
+        let mut null_clause = self.parse_json_null_clause();
+        if null_clause.is_none() && self.peek_token().token != Token::RParen {
+            entries = self.parse_comma_separated(Parser::parse_json_key_value)?;
+            null_clause = self.parse_json_null_clause();
+        }
+        self.expect_token(&Token::RParen)?;
+        Ok(Expr::JsonObject {
+            entries,
+            null_clause,
+        })
+    }
+
+    /// Parse a single `[KEY] <key> VALUE <value>` or `<key> : <value>` entry
+    /// of a `JSON_OBJECT` constructor.
+    fn parse_json_key_value(&mut self) -> Result<JsonKeyValue, ParserError> {
+        // `KEY` may also be a column name, e.g. `JSON_OBJECT(key VALUE 1)`
+        let explicit_key = !matches!(
+            self.peek_nth_token(1).token,
+            Token::Colon
+                | Token::Word(Word {
+                    keyword: Keyword::VALUE,
+                    ..
+                })
+        ) && self.parse_keyword(Keyword::KEY);
+        let key = self.parse_expr()?;
+        let separator = if self.parse_keyword(Keyword::VALUE) {
+            JsonKeyValueSeparator::Value
+        } else if !explicit_key && self.consume_token(&Token::Colon) {
+            JsonKeyValueSeparator::Colon
+        } else {
+            return self.expected("VALUE or :", self.peek_token());
+        };
+        let value = self.parse_expr()?;
+        Ok(JsonKeyValue {
+            explicit_key,
+            key,
+            value,
+            separator,
+        })
+    }
+
+    /// Parse a SQL:2016 `JSON_ARRAY` constructor, assuming the `JSON_ARRAY`
+    /// keyword has already been consumed.
+    ///
+    /// Syntax:
+    /// ```sql
+    /// JSON_ARRAY([<value>, ...] [{NULL | ABSENT} ON NULL])
+    /// ```
+    pub fn parse_json_array(&mut self) -> Result<Expr, ParserError> {
+        self.expect_token(&Token::LParen)?;
+        let mut values = vec![];
+        let mut null_clause = self.parse_json_null_clause();
+        if null_clause.is_none() && self.peek_token().token != Token::RParen {
+            values = self.parse_comma_separated(Parser::parse_expr)?;
+            null_clause = self.parse_json_null_clause();
+        }
+        self.expect_token(&Token::RParen)?;
+        Ok(Expr::JsonArray {
+            values,
+            null_clause,
+        })
+    }
+
+    /// Optionally parse a `{ NULL | ABSENT } ON NULL` clause.
+    fn parse_json_null_clause(&mut self) -> Option<JsonNullClause> {
+        if self.parse_keywords(&[Keyword::ABSENT, Keyword::ON, Keyword::NULL]) {
+            Some(JsonNullClause::AbsentOnNull)
+        } else if self.parse_keywords(&[Keyword::NULL, Keyword::ON, Keyword::NULL]) {
+            Some(JsonNullClause::NullOnNull)
+        } else {
+            None
+        }
+    }
+
     /// Parse clickhouse map [1]
     /// Syntax
     /// ```sql
@@ -2358,6 +2768,9 @@ impl<'a> Parser<'a> {
             Token::Minus => Some(BinaryOperator::Minus),
             Token::Mul => Some(BinaryOperator::Multiply),
             Token::Mod => Some(BinaryOperator::Modulo),
+            Token::StringConcat if self.dialect.supports_pipes_as_or() => {
+                Some(BinaryOperator::PipesOr)
+            }
             Token::StringConcat => Some(BinaryOperator::StringConcat),
             Token::Pipe => Some(BinaryOperator::BitwiseOr),
             Token::Caret => {
@@ -2374,10 +2787,18 @@ impl<'a> Parser<'a> {
             Token::DuckIntDiv if dialect_of!(self is DuckDbDialect | GenericDialect) => {
                 Some(BinaryOperator::DuckIntegerDivide)
             }
-            Token::ShiftLeft if dialect_of!(self is PostgreSqlDialect | DuckDbDialect | GenericDialect) => {
+            Token::ShiftLeft
+                if dialect_of!(
+                    self is PostgreSqlDialect | DuckDbDialect | MySqlDialect | BigQueryDialect | GenericDialect
+                ) =>
+            {
                 Some(BinaryOperator::PGBitwiseShiftLeft)
             }
-            Token::ShiftRight if dialect_of!(self is PostgreSqlDialect | DuckDbDialect | GenericDialect) => {
+            Token::ShiftRight
+                if dialect_of!(
+                    self is PostgreSqlDialect | DuckDbDialect | MySqlDialect | BigQueryDialect | GenericDialect
+                ) =>
+            {
                 Some(BinaryOperator::PGBitwiseShiftRight)
             }
             Token::Sharp if dialect_of!(self is PostgreSqlDialect) => {
@@ -2507,11 +2928,27 @@ impl<'a> Parser<'a> {
                         Ok(Expr::IsNotDistinctFrom(Box::new(expr), Box::new(expr2)))
                     } else {
                         self.expected(
-                            "[NOT] NULL or TRUE|FALSE or [NOT] DISTINCT FROM after IS",
+                            "[NOT] NULL or TRUE|FALSE|UNKNOWN or [NOT] DISTINCT FROM after IS",
                             self.peek_token(),
                         )
                     }
                 }
+                Keyword::OVERLAPS => {
+                    let loc = self.peek_token().location;
+                    let right = self.parse_subexpr(precedence)?;
+                    let is_period = |e: &Expr| matches!(e, Expr::Tuple(v) if v.len() == 2);
+                    if !is_period(&expr) || !is_period(&right) {
+                        return parser_err!("OVERLAPS requires (start, end) periods", loc);
+                    }
+                    Ok(Expr::Overlaps {
+                        left: Box::new(expr),
+                        right: Box::new(right),
+                    })
+                }
+                Keyword::COLLATE => Ok(Expr::Collate {
+                    expr: Box::new(expr),
+                    collation: self.parse_object_name(false)?,
+                }),
                 Keyword::AT => {
                     self.expect_keywords(&[Keyword::TIME, Keyword::ZONE])?;
                     Ok(Expr::AtTimeZone {
@@ -2598,6 +3035,8 @@ impl<'a> Parser<'a> {
         } else if dialect_of!(self is SnowflakeDialect | GenericDialect) && Token::Colon == tok {
             self.prev_token();
             self.parse_json_access(expr)
+        } else if Token::Period == tok {
+            self.parse_composite_access(expr)
         } else {
             // Can only happen if `get_next_precedence` got out of sync with this function
             parser_err!(
@@ -2607,6 +3046,22 @@ impl<'a> Parser<'a> {
         }
     }
 
+    /// Parses the field name of a composite access such as `(expr).field`
+    /// or `func().field`
+    ///
+    /// Parser is right after `.`
+    pub fn parse_composite_access(&mut self, expr: Expr) -> Result<Expr, ParserError> {
+        let tok = self.next_token();
+        let key = match tok.token {
+            Token::Word(word) => word.to_ident(),
+            _ => return parser_err!(format!("Expected identifier, found: {tok}"), tok.location),
+        };
+        Ok(Expr::CompositeAccess {
+            expr: Box::new(expr),
+            key,
+        })
+    }
+
     /// parse the ESCAPE CHAR portion of LIKE, ILIKE, and SIMILAR TO
     pub fn parse_escape_char(&mut self) -> Result<Option<String>, ParserError> {
         if self.parse_keyword(Keyword::ESCAPE) {
@@ -2924,6 +3379,12 @@ impl<'a> Parser<'a> {
             Token::Word(w) if w.keyword == Keyword::RLIKE => Ok(Self::LIKE_PREC),
             Token::Word(w) if w.keyword == Keyword::REGEXP => Ok(Self::LIKE_PREC),
             Token::Word(w) if w.keyword == Keyword::SIMILAR => Ok(Self::LIKE_PREC),
+            Token::Word(w)
+                if w.keyword == Keyword::OVERLAPS
+                    && self.peek_nth_token(1).token == Token::LParen =>
+            {
+                Ok(Self::BETWEEN_PREC)
+            }
             Token::Word(w) if w.keyword == Keyword::OPERATOR => Ok(Self::BETWEEN_PREC),
             Token::Word(w) if w.keyword == Keyword::DIV => Ok(Self::MUL_DIV_MOD_OP_PREC),
             Token::Eq
@@ -2942,17 +3403,37 @@ impl<'a> Parser<'a> {
             | Token::ExclamationMarkDoubleTilde
             | Token::ExclamationMarkDoubleTildeAsterisk
             | Token::Spaceship => Ok(20),
-            Token::Pipe => Ok(21),
+            // PostgreSQL evaluates its bitwise operators at one level, left to right
+            // https://www.postgresql.org/docs/current/sql-syntax-lexical.html#SQL-PRECEDENCE
+            Token::Pipe
+            | Token::Ampersand
+            | Token::Sharp
+            | Token::ShiftRight
+            | Token::ShiftLeft
+                if dialect_of!(self is PostgreSqlDialect) =>
+            {
+                Ok(22)
+            }
+            Token::Pipe => Ok(21),
             Token::Caret | Token::Sharp | Token::ShiftRight | Token::ShiftLeft => Ok(22),
             Token::Ampersand => Ok(23),
             Token::Plus | Token::Minus => Ok(Self::PLUS_MINUS_PREC),
+            Token::StringConcat if self.dialect.supports_pipes_as_or() => Ok(Self::OR_PREC),
             Token::Mul | Token::Div | Token::DuckIntDiv | Token::Mod | Token::StringConcat => {
                 Ok(Self::MUL_DIV_MOD_OP_PREC)
             }
             Token::DoubleColon => Ok(50),
+            // `COLLATE` binds as tightly as casts and subscripts, so that
+            // `a = b COLLATE "C"` applies the collation to `b` only.
+            Token::Word(w) if w.keyword == Keyword::COLLATE => Ok(50),
             Token::Colon if dialect_of!(self is SnowflakeDialect) => Ok(50),
             Token::ExclamationMark => Ok(50),
             Token::LBracket | Token::Overlap | Token::CaretAt => Ok(50),
+            // Field access on a non-identifier expression, e.g. `func().field`.
+            // Dots between identifiers are consumed when parsing the prefix.
+            Token::Period if dialect_of!(self is BigQueryDialect | DuckDbDialect | GenericDialect) => {
+                Ok(50)
+            }
             Token::Arrow
             | Token::LongArrow
             | Token::HashArrow
@@ -3291,9 +3772,7 @@ impl<'a> Parser<'a> {
             } else if self.options.trailing_commas {
                 match self.peek_token().token {
                     Token::Word(kw)
-                        if keywords::RESERVED_FOR_COLUMN_ALIAS
-                            .iter()
-                            .any(|d| kw.keyword == *d) =>
+                        if keywords::RESERVED_FOR_COLUMN_ALIAS.contains(&kw.keyword) =>
                     {
                         break;
                     }
@@ -3355,6 +3834,20 @@ impl<'a> Parser<'a> {
         }
     }
 
+    /// Parse BigQuery's `AS VALUE` or `AS STRUCT` select modifier, if present
+    fn parse_value_table_mode(&mut self) -> Result<Option<ValueTableMode>, ParserError> {
+        if !dialect_of!(self is BigQueryDialect) || !self.parse_keyword(Keyword::AS) {
+            return Ok(None);
+        }
+        if self.parse_keyword(Keyword::VALUE) {
+            Ok(Some(ValueTableMode::AsValue))
+        } else if self.parse_keyword(Keyword::STRUCT) {
+            Ok(Some(ValueTableMode::AsStruct))
+        } else {
+            self.expected("VALUE or STRUCT", self.peek_token())
+        }
+    }
+
     /// Parse either `ALL`, `DISTINCT` or `DISTINCT ON (...)`. Returns `None` if `ALL` is parsed
     /// and results in a `ParserError` if both `ALL` and `DISTINCT` are found.
     pub fn parse_all_or_distinct(&mut self) -> Result<Option<Distinct>, ParserError> {
@@ -3716,6 +4209,8 @@ impl<'a> Parser<'a> {
             self.parse_hive_create_function(or_replace, temporary)
         } else if dialect_of!(self is PostgreSqlDialect | GenericDialect) {
             self.parse_postgres_create_function(or_replace, temporary)
+        } else if dialect_of!(self is SnowflakeDialect) {
+            self.parse_snowflake_create_function(or_replace, temporary)
         } else if dialect_of!(self is DuckDbDialect) {
             self.parse_create_macro(or_replace, temporary)
         } else if dialect_of!(self is BigQueryDialect) {
@@ -3736,13 +4231,7 @@ impl<'a> Parser<'a> {
     ) -> Result<Statement, ParserError> {
         let name = self.parse_object_name(false)?;
         self.expect_token(&Token::LParen)?;
-        let args = if self.consume_token(&Token::RParen) {
-            self.prev_token();
-            None
-        } else {
-            Some(self.parse_comma_separated(Parser::parse_function_arg)?)
-        };
-
+        let args = self.parse_comma_separated0(Parser::parse_function_arg)?;
         self.expect_token(&Token::RParen)?;
 
         let return_type = if self.parse_keyword(Keyword::RETURNS) {
@@ -3760,6 +4249,8 @@ impl<'a> Parser<'a> {
             parallel: Option<FunctionParallel>,
         }
         let mut body = Body::default();
+        // Whether the `AS` body was given before any other option
+        let mut as_first = false;
         loop {
             fn ensure_not_set<T>(field: &Option<T>, name: &str) -> Result<(), ParserError> {
                 if field.is_some() {
@@ -3771,6 +4262,10 @@ impl<'a> Parser<'a> {
             }
             if self.parse_keyword(Keyword::AS) {
                 ensure_not_set(&body.function_body, "AS")?;
+                as_first = body.language.is_none()
+                    && body.behavior.is_none()
+                    && body.called_on_null.is_none()
+                    && body.parallel.is_none();
                 body.function_body = Some(CreateFunctionBody::AsBeforeOptions(
                     self.parse_create_function_body_string()?,
                 ));
@@ -3834,11 +4329,23 @@ impl<'a> Parser<'a> {
             }
         }
 
+        let has_options = body.language.is_some()
+            || body.behavior.is_some()
+            || body.called_on_null.is_some()
+            || body.parallel.is_some();
+        // Keep an `AS` body that preceded the other options in its place
+        body.function_body = match body.function_body.take() {
+            Some(CreateFunctionBody::AsBeforeOptions(expr)) if as_first && has_options => {
+                Some(CreateFunctionBody::AsBeforeCharacteristics(expr))
+            }
+            function_body => function_body,
+        };
+
         Ok(Statement::CreateFunction {
             or_replace,
             temporary,
             name,
-            args,
+            args: Some(args),
             return_type,
             behavior: body.behavior,
             called_on_null: body.called_on_null,
@@ -3853,6 +4360,62 @@ impl<'a> Parser<'a> {
         })
     }
 
+    /// Parse `CREATE FUNCTION` for [Snowflake]
+    ///
+    /// The clauses are accepted in the documented order, with the body last:
+    /// ```sql
+    /// CREATE FUNCTION <name>( ... ) RETURNS <type>
+    ///   [ LANGUAGE <language> ] [ { VOLATILE | IMMUTABLE } ] AS <body>
+    /// ```
+    ///
+    /// [Snowflake]: https://docs.snowflake.com/en/sql-reference/sql/create-function
+    fn parse_snowflake_create_function(
+        &mut self,
+        or_replace: bool,
+        temporary: bool,
+    ) -> Result<Statement, ParserError> {
+        let name = self.parse_object_name(false)?;
+        self.expect_token(&Token::LParen)?;
+        let args = self.parse_comma_separated0(Parser::parse_function_arg)?;
+        self.expect_token(&Token::RParen)?;
+
+        self.expect_keyword(Keyword::RETURNS)?;
+        let return_type = self.parse_data_type()?;
+
+        let language = if self.parse_keyword(Keyword::LANGUAGE) {
+            Some(self.parse_identifier(false)?)
+        } else {
+            None
+        };
+
+        let behavior = match self.parse_one_of_keywords(&[Keyword::VOLATILE, Keyword::IMMUTABLE]) {
+            Some(Keyword::VOLATILE) => Some(FunctionBehavior::Volatile),
+            Some(Keyword::IMMUTABLE) => Some(FunctionBehavior::Immutable),
+            _ => None,
+        };
+
+        self.expect_keyword(Keyword::AS)?;
+        let function_body = self.parse_create_function_body_string()?;
+
+        Ok(Statement::CreateFunction {
+            or_replace,
+            temporary,
+            name,
+            args: Some(args),
+            return_type: Some(return_type),
+            behavior,
+            called_on_null: None,
+            parallel: None,
+            language,
+            function_body: Some(CreateFunctionBody::AsBeforeOptions(function_body)),
+            if_not_exists: false,
+            using: None,
+            determinism_specifier: None,
+            options: None,
+            remote_connection: None,
+        })
+    }
+
     /// Parse `CREATE FUNCTION` for [Hive]
     ///
     /// [Hive]: https://cwiki.apache.org/confluence/display/hive/languagemanual+ddl#LanguageManualDDL-Create/Drop/ReloadFunction
@@ -4116,6 +4679,8 @@ impl<'a> Parser<'a> {
                 Keyword::TEXT => Ok(AnalyzeFormat::TEXT),
                 Keyword::GRAPHVIZ => Ok(AnalyzeFormat::GRAPHVIZ),
                 Keyword::JSON => Ok(AnalyzeFormat::JSON),
+                Keyword::XML => Ok(AnalyzeFormat::XML),
+                Keyword::YAML => Ok(AnalyzeFormat::YAML),
                 _ => self.expected("fileformat", next_token),
             },
             _ => self.expected("fileformat", next_token),
@@ -4129,7 +4694,8 @@ impl<'a> Parser<'a> {
     ) -> Result<Statement, ParserError> {
         let materialized = self.parse_keyword(Keyword::MATERIALIZED);
         self.expect_keyword(Keyword::VIEW)?;
-        let if_not_exists = dialect_of!(self is BigQueryDialect|SQLiteDialect|GenericDialect)
+        let if_not_exists = (dialect_of!(self is BigQueryDialect | SQLiteDialect | SnowflakeDialect | GenericDialect)
+            || (materialized && dialect_of!(self is PostgreSqlDialect)))
             && self.parse_keywords(&[Keyword::IF, Keyword::NOT, Keyword::EXISTS]);
         // Many dialects support `OR ALTER` right after `CREATE`, but we don't (yet).
         // ANSI SQL and Postgres support RECURSIVE here, but we don't support it either.
@@ -4172,7 +4738,12 @@ impl<'a> Parser<'a> {
 
         self.expect_keyword(Keyword::AS)?;
         let query = self.parse_boxed_query()?;
-        // Optional `WITH [ CASCADED | LOCAL ] CHECK OPTION` is widely supported here.
+        let with_check_option = self.parse_view_check_option();
+        let with_data = if materialized {
+            self.parse_with_data()
+        } else {
+            None
+        };
 
         let with_no_schema_binding = dialect_of!(self is RedshiftSqlDialect | GenericDialect)
             && self.parse_keywords(&[
@@ -4194,14 +4765,39 @@ impl<'a> Parser<'a> {
             with_no_schema_binding,
             if_not_exists,
             temporary,
+            with_check_option,
+            with_data,
         })
     }
 
+    /// Parse an optional `WITH [ CASCADED | LOCAL ] CHECK OPTION` clause
+    fn parse_view_check_option(&mut self) -> Option<CheckOption> {
+        if self.parse_keywords(&[Keyword::WITH, Keyword::CHECK, Keyword::OPTION]) {
+            Some(CheckOption::Unqualified)
+        } else if self.parse_keywords(&[
+            Keyword::WITH,
+            Keyword::CASCADED,
+            Keyword::CHECK,
+            Keyword::OPTION,
+        ]) {
+            Some(CheckOption::Cascaded)
+        } else if self.parse_keywords(&[
+            Keyword::WITH,
+            Keyword::LOCAL,
+            Keyword::CHECK,
+            Keyword::OPTION,
+        ]) {
+            Some(CheckOption::Local)
+        } else {
+            None
+        }
+    }
+
     pub fn parse_create_role(&mut self) -> Result<Statement, ParserError> {
         let if_not_exists = self.parse_keywords(&[Keyword::IF, Keyword::NOT, Keyword::EXISTS]);
         let names = self.parse_comma_separated(|p| p.parse_object_name(false))?;
 
-        let _ = self.parse_keyword(Keyword::WITH); // [ WITH ]
+        let with = self.parse_keyword(Keyword::WITH);
 
         let optional_keywords = if dialect_of!(self is MsSqlDialect) {
             vec![Keyword::AUTHORIZATION]
@@ -4236,21 +4832,7 @@ impl<'a> Parser<'a> {
         // MSSQL
         let mut authorization_owner = None;
         // Postgres
-        let mut login = None;
-        let mut inherit = None;
-        let mut bypassrls = None;
-        let mut password = None;
-        let mut create_db = None;
-        let mut create_role = None;
-        let mut superuser = None;
-        let mut replication = None;
-        let mut connection_limit = None;
-        let mut valid_until = None;
-        let mut in_role = vec![];
-        let mut in_group = vec![];
-        let mut role = vec![];
-        let mut user = vec![];
-        let mut admin = vec![];
+        let mut options: Vec<CreateRoleOption> = vec![];
 
         while let Some(keyword) = self.parse_one_of_keywords(&optional_keywords) {
             let loc = self
@@ -4266,157 +4848,65 @@ impl<'a> Parser<'a> {
                         Ok(())
                     }
                 }
-                Keyword::LOGIN | Keyword::NOLOGIN => {
-                    if login.is_some() {
-                        parser_err!("Found multiple LOGIN or NOLOGIN", loc)
-                    } else {
-                        login = Some(keyword == Keyword::LOGIN);
-                        Ok(())
-                    }
-                }
-                Keyword::INHERIT | Keyword::NOINHERIT => {
-                    if inherit.is_some() {
-                        parser_err!("Found multiple INHERIT or NOINHERIT", loc)
-                    } else {
-                        inherit = Some(keyword == Keyword::INHERIT);
-                        Ok(())
-                    }
-                }
-                Keyword::BYPASSRLS | Keyword::NOBYPASSRLS => {
-                    if bypassrls.is_some() {
-                        parser_err!("Found multiple BYPASSRLS or NOBYPASSRLS", loc)
-                    } else {
-                        bypassrls = Some(keyword == Keyword::BYPASSRLS);
-                        Ok(())
-                    }
-                }
-                Keyword::CREATEDB | Keyword::NOCREATEDB => {
-                    if create_db.is_some() {
-                        parser_err!("Found multiple CREATEDB or NOCREATEDB", loc)
-                    } else {
-                        create_db = Some(keyword == Keyword::CREATEDB);
-                        Ok(())
-                    }
-                }
-                Keyword::CREATEROLE | Keyword::NOCREATEROLE => {
-                    if create_role.is_some() {
-                        parser_err!("Found multiple CREATEROLE or NOCREATEROLE", loc)
-                    } else {
-                        create_role = Some(keyword == Keyword::CREATEROLE);
-                        Ok(())
-                    }
-                }
-                Keyword::SUPERUSER | Keyword::NOSUPERUSER => {
-                    if superuser.is_some() {
-                        parser_err!("Found multiple SUPERUSER or NOSUPERUSER", loc)
-                    } else {
-                        superuser = Some(keyword == Keyword::SUPERUSER);
-                        Ok(())
-                    }
-                }
-                Keyword::REPLICATION | Keyword::NOREPLICATION => {
-                    if replication.is_some() {
-                        parser_err!("Found multiple REPLICATION or NOREPLICATION", loc)
-                    } else {
-                        replication = Some(keyword == Keyword::REPLICATION);
-                        Ok(())
-                    }
-                }
-                Keyword::PASSWORD => {
-                    if password.is_some() {
-                        parser_err!("Found multiple PASSWORD", loc)
-                    } else {
-                        password = if self.parse_keyword(Keyword::NULL) {
-                            Some(Password::NullPassword)
-                        } else {
-                            Some(Password::Password(Expr::Value(self.parse_value()?)))
-                        };
-                        Ok(())
-                    }
-                }
-                Keyword::CONNECTION => {
-                    self.expect_keyword(Keyword::LIMIT)?;
-                    if connection_limit.is_some() {
-                        parser_err!("Found multiple CONNECTION LIMIT", loc)
-                    } else {
-                        connection_limit = Some(Expr::Value(self.parse_number_value()?));
-                        Ok(())
-                    }
-                }
-                Keyword::VALID => {
-                    self.expect_keyword(Keyword::UNTIL)?;
-                    if valid_until.is_some() {
-                        parser_err!("Found multiple VALID UNTIL", loc)
-                    } else {
-                        valid_until = Some(Expr::Value(self.parse_value()?));
-                        Ok(())
-                    }
-                }
-                Keyword::IN => {
-                    if self.parse_keyword(Keyword::ROLE) {
-                        if !in_role.is_empty() {
-                            parser_err!("Found multiple IN ROLE", loc)
-                        } else {
-                            in_role = self.parse_comma_separated(|p| p.parse_identifier(false))?;
-                            Ok(())
+                _ => {
+                    let option = match keyword {
+                        Keyword::IN if self.parse_keyword(Keyword::ROLE) => {
+                            CreateRoleOption::InRole(
+                                self.parse_comma_separated(|p| p.parse_identifier(false))?,
+                            )
                         }
-                    } else if self.parse_keyword(Keyword::GROUP) {
-                        if !in_group.is_empty() {
-                            parser_err!("Found multiple IN GROUP", loc)
-                        } else {
-                            in_group = self.parse_comma_separated(|p| p.parse_identifier(false))?;
-                            Ok(())
+                        Keyword::IN if self.parse_keyword(Keyword::GROUP) => {
+                            CreateRoleOption::InGroup(
+                                self.parse_comma_separated(|p| p.parse_identifier(false))?,
+                            )
                         }
+                        Keyword::IN => {
+                            return self.expected("ROLE or GROUP after IN", self.peek_token())
+                        }
+                        Keyword::ROLE => CreateRoleOption::Role(
+                            self.parse_comma_separated(|p| p.parse_identifier(false))?,
+                        ),
+                        Keyword::USER => CreateRoleOption::User(
+                            self.parse_comma_separated(|p| p.parse_identifier(false))?,
+                        ),
+                        Keyword::ADMIN => CreateRoleOption::Admin(
+                            self.parse_comma_separated(|p| p.parse_identifier(false))?,
+                        ),
+                        _ => {
+                            self.prev_token();
+                            CreateRoleOption::Option(Box::new(self.parse_pg_role_option()?))
+                        }
+                    };
+                    let duplicate = options.iter().any(|o| match (o, &option) {
+                        (CreateRoleOption::Option(a), CreateRoleOption::Option(b)) => {
+                            core::mem::discriminant(a.as_ref())
+                                == core::mem::discriminant(b.as_ref())
+                        }
+                        (a, b) => core::mem::discriminant(a) == core::mem::discriminant(b),
+                    });
+                    if duplicate {
+                        let keywords = match &option {
+                            CreateRoleOption::Option(option) => alter::role_option_keywords(option),
+                            CreateRoleOption::InRole(_) => "IN ROLE",
+                            CreateRoleOption::InGroup(_) => "IN GROUP",
+                            CreateRoleOption::Role(_) => "ROLE",
+                            CreateRoleOption::User(_) => "USER",
+                            CreateRoleOption::Admin(_) => "ADMIN",
+                        };
+                        parser_err!(format!("Found multiple {keywords}"), loc)
                     } else {
-                        self.expected("ROLE or GROUP after IN", self.peek_token())
-                    }
-                }
-                Keyword::ROLE => {
-                    if !role.is_empty() {
-                        parser_err!("Found multiple ROLE", loc)
-                    } else {
-                        role = self.parse_comma_separated(|p| p.parse_identifier(false))?;
-                        Ok(())
-                    }
-                }
-                Keyword::USER => {
-                    if !user.is_empty() {
-                        parser_err!("Found multiple USER", loc)
-                    } else {
-                        user = self.parse_comma_separated(|p| p.parse_identifier(false))?;
-                        Ok(())
-                    }
-                }
-                Keyword::ADMIN => {
-                    if !admin.is_empty() {
-                        parser_err!("Found multiple ADMIN", loc)
-                    } else {
-                        admin = self.parse_comma_separated(|p| p.parse_identifier(false))?;
+                        options.push(option);
                         Ok(())
                     }
                 }
-                _ => break,
             }?
         }
 
         Ok(Statement::CreateRole {
             names,
             if_not_exists,
-            login,
-            inherit,
-            bypassrls,
-            password,
-            create_db,
-            create_role,
-            replication,
-            superuser,
-            connection_limit,
-            valid_until,
-            in_role,
-            in_group,
-            role,
-            user,
-            admin,
+            with,
+            options,
             authorization_owner,
         })
     }
@@ -4965,6 +5455,30 @@ impl<'a> Parser<'a> {
         Ok(Statement::Discard { object_type })
     }
 
+    /// Parse `REFRESH MATERIALIZED VIEW [ CONCURRENTLY ] name [ WITH [ NO ] DATA ]`
+    pub fn parse_refresh(&mut self) -> Result<Statement, ParserError> {
+        self.expect_keywords(&[Keyword::MATERIALIZED, Keyword::VIEW])?;
+        let concurrently = self.parse_keyword(Keyword::CONCURRENTLY);
+        let name = self.parse_object_name(false)?;
+        let with_data = self.parse_with_data();
+        Ok(Statement::RefreshMaterializedView {
+            name,
+            concurrently,
+            with_data,
+        })
+    }
+
+    /// Parse an optional PostgreSQL `WITH [ NO ] DATA` clause
+    fn parse_with_data(&mut self) -> Option<bool> {
+        if self.parse_keywords(&[Keyword::WITH, Keyword::DATA]) {
+            Some(true)
+        } else if self.parse_keywords(&[Keyword::WITH, Keyword::NO, Keyword::DATA]) {
+            Some(false)
+        } else {
+            None
+        }
+    }
+
     pub fn parse_create_index(&mut self, unique: bool) -> Result<Statement, ParserError> {
         let concurrently = self.parse_keyword(Keyword::CONCURRENTLY);
         let if_not_exists = self.parse_keywords(&[Keyword::IF, Keyword::NOT, Keyword::EXISTS]);
@@ -5133,70 +5647,60 @@ impl<'a> Parser<'a> {
                         Keyword::LINES,
                         Keyword::NULL,
                     ]) {
-                        Some(Keyword::FIELDS) => {
-                            if self.parse_keywords(&[Keyword::TERMINATED, Keyword::BY]) {
+                        Some(Keyword::FIELDS)
+                            if self.parse_keywords(&[Keyword::TERMINATED, Keyword::BY]) =>
+                        {
+                            row_delimiters.push(HiveRowDelimiter {
+                                delimiter: HiveDelimiter::FieldsTerminatedBy,
+                                char: self.parse_identifier(false)?,
+                            });
+
+                            if self.parse_keywords(&[Keyword::ESCAPED, Keyword::BY]) {
                                 row_delimiters.push(HiveRowDelimiter {
-                                    delimiter: HiveDelimiter::FieldsTerminatedBy,
+                                    delimiter: HiveDelimiter::FieldsEscapedBy,
                                     char: self.parse_identifier(false)?,
                                 });
-
-                                if self.parse_keywords(&[Keyword::ESCAPED, Keyword::BY]) {
-                                    row_delimiters.push(HiveRowDelimiter {
-                                        delimiter: HiveDelimiter::FieldsEscapedBy,
-                                        char: self.parse_identifier(false)?,
-                                    });
-                                }
-                            } else {
-                                break;
                             }
                         }
-                        Some(Keyword::COLLECTION) => {
+                        Some(Keyword::COLLECTION)
                             if self.parse_keywords(&[
                                 Keyword::ITEMS,
                                 Keyword::TERMINATED,
                                 Keyword::BY,
-                            ]) {
-                                row_delimiters.push(HiveRowDelimiter {
-                                    delimiter: HiveDelimiter::CollectionItemsTerminatedBy,
-                                    char: self.parse_identifier(false)?,
-                                });
-                            } else {
-                                break;
-                            }
+                            ]) =>
+                        {
+                            row_delimiters.push(HiveRowDelimiter {
+                                delimiter: HiveDelimiter::CollectionItemsTerminatedBy,
+                                char: self.parse_identifier(false)?,
+                            });
                         }
-                        Some(Keyword::MAP) => {
+                        Some(Keyword::MAP)
                             if self.parse_keywords(&[
                                 Keyword::KEYS,
                                 Keyword::TERMINATED,
                                 Keyword::BY,
-                            ]) {
-                                row_delimiters.push(HiveRowDelimiter {
-                                    delimiter: HiveDelimiter::MapKeysTerminatedBy,
-                                    char: self.parse_identifier(false)?,
-                                });
-                            } else {
-                                break;
-                            }
+                            ]) =>
+                        {
+                            row_delimiters.push(HiveRowDelimiter {
+                                delimiter: HiveDelimiter::MapKeysTerminatedBy,
+                                char: self.parse_identifier(false)?,
+                            });
                         }
-                        Some(Keyword::LINES) => {
-                            if self.parse_keywords(&[Keyword::TERMINATED, Keyword::BY]) {
-                                row_delimiters.push(HiveRowDelimiter {
-                                    delimiter: HiveDelimiter::LinesTerminatedBy,
-                                    char: self.parse_identifier(false)?,
-                                });
-                            } else {
-                                break;
-                            }
+                        Some(Keyword::LINES)
+                            if self.parse_keywords(&[Keyword::TERMINATED, Keyword::BY]) =>
+                        {
+                            row_delimiters.push(HiveRowDelimiter {
+                                delimiter: HiveDelimiter::LinesTerminatedBy,
+                                char: self.parse_identifier(false)?,
+                            });
                         }
-                        Some(Keyword::NULL) => {
-                            if self.parse_keywords(&[Keyword::DEFINED, Keyword::AS]) {
-                                row_delimiters.push(HiveRowDelimiter {
-                                    delimiter: HiveDelimiter::NullDefinedAs,
-                                    char: self.parse_identifier(false)?,
-                                });
-                            } else {
-                                break;
-                            }
+                        Some(Keyword::NULL)
+                            if self.parse_keywords(&[Keyword::DEFINED, Keyword::AS]) =>
+                        {
+                            row_delimiters.push(HiveRowDelimiter {
+                                delimiter: HiveDelimiter::NullDefinedAs,
+                                char: self.parse_identifier(false)?,
+                            });
                         }
                         _ => {
                             break;
@@ -5234,8 +5738,24 @@ impl<'a> Parser<'a> {
             None
         };
 
+        // PostgreSQL declarative partitioning: `PARTITION OF <parent>`
+        let partition_parent = if dialect_of!(self is PostgreSqlDialect | GenericDialect)
+            && self.parse_keywords(&[Keyword::PARTITION, Keyword::OF])
+        {
+            Some(self.parse_object_name(allow_unquoted_hyphen)?)
+        } else {
+            None
+        };
+
         let like = if self.parse_keyword(Keyword::LIKE) || self.parse_keyword(Keyword::ILIKE) {
-            self.parse_object_name(allow_unquoted_hyphen).ok()
+            self.parse_object_name(allow_unquoted_hyphen)
+                .ok()
+                .map(|name| {
+                    CreateTableLikeKind::Plain(CreateTableLike {
+                        name,
+                        options: vec![],
+                    })
+                })
         } else {
             None
         };
@@ -5246,12 +5766,59 @@ impl<'a> Parser<'a> {
             None
         };
 
+        // `CREATE TABLE t (a, b) AS <query>` names the query's columns without types
+        let column_names = if like.is_none() && !dialect_of!(self is SQLiteDialect) {
+            self.maybe_parse(|p| {
+                let column_names = p.parse_parenthesized_column_list(Mandatory, false)?;
+                match p.peek_token().token {
+                    Token::Word(w) if w.keyword == Keyword::AS || w.keyword == Keyword::WITH => {
+                        Ok(column_names)
+                    }
+                    _ => p.expected("AS after column names", p.peek_token()),
+                }
+            })
+            .unwrap_or_default()
+        } else {
+            vec![]
+        };
+
         // parse optional column list (schema)
-        let (columns, constraints) = self.parse_columns()?;
+        let mut like_elements = vec![];
+        let (columns, constraints) =
+            self.parse_table_elements(like.is_none().then_some(&mut like_elements))?;
+        let like = if like_elements.is_empty() {
+            like
+        } else {
+            Some(CreateTableLikeKind::Parenthesized(like_elements))
+        };
+
+        let partition_of = match partition_parent {
+            Some(parent) => Some(PartitionOf {
+                parent,
+                bound: self.parse_partition_bound()?,
+            }),
+            None => None,
+        };
 
         // SQLite supports `WITHOUT ROWID` at the end of `CREATE TABLE`
         let without_rowid = self.parse_keywords(&[Keyword::WITHOUT, Keyword::ROWID]);
 
+        let inherits =
+            if dialect_of!(self is PostgreSqlDialect | GenericDialect)
+                && self.parse_keyword(Keyword::INHERITS)
+            {
+                Some(self.parse_parenthesized(|p| {
+                    p.parse_comma_separated(|p| p.parse_object_name(false))
+                })?)
+            } else {
+                None
+            };
+        let partitioning = if dialect_of!(self is PostgreSqlDialect | GenericDialect) {
+            self.parse_optional_table_partitioning()?
+        } else {
+            None
+        };
+
         let hive_distribution = self.parse_hive_distribution()?;
         let hive_formats = self.parse_hive_formats()?;
         // PostgreSQL supports `WITH ( options )`, before `AS`
@@ -5314,6 +5881,12 @@ impl<'a> Parser<'a> {
             None
         };
 
+        let redshift_config = if dialect_of!(self is RedshiftSqlDialect | GenericDialect) {
+            self.parse_optional_redshift_create_table_config()?
+        } else {
+            Default::default()
+        };
+
         let big_query_config = if dialect_of!(self is BigQueryDialect | GenericDialect) {
             self.parse_optional_big_query_create_table_config()?
         } else {
@@ -5326,6 +5899,11 @@ impl<'a> Parser<'a> {
         } else {
             None
         };
+        let with_data = if query.is_none() {
+            None
+        } else {
+            self.parse_with_data()
+        };
 
         let default_charset = if self.parse_keywords(&[Keyword::DEFAULT, Keyword::CHARSET]) {
             self.expect_token(&Token::Eq)?;
@@ -5392,9 +5970,14 @@ impl<'a> Parser<'a> {
             .hive_formats(Some(hive_formats))
             .global(global)
             .query(query)
+            .column_names(column_names)
+            .with_data(with_data)
             .without_rowid(without_rowid)
             .like(like)
             .clone_clause(clone)
+            .partition_of(partition_of)
+            .inherits(inherits)
+            .partitioning(partitioning)
             .engine(engine)
             .comment(comment)
             .auto_increment_offset(auto_increment_offset)
@@ -5408,9 +5991,150 @@ impl<'a> Parser<'a> {
             .options(big_query_config.options)
             .primary_key(primary_key)
             .strict(strict)
+            .dist_style(redshift_config.dist_style)
+            .dist_key(redshift_config.dist_key)
+            .sort_key(redshift_config.sort_key)
             .build())
     }
 
+    /// Parse the `{ INCLUDING | EXCLUDING } <property>` options of `CREATE TABLE (LIKE src ...)`
+    fn parse_create_table_like_options(
+        &mut self,
+    ) -> Result<Vec<CreateTableLikeOption>, ParserError> {
+        let mut options = vec![];
+        loop {
+            let including = if self.parse_keyword(Keyword::INCLUDING) {
+                true
+            } else if self.parse_keyword(Keyword::EXCLUDING) {
+                false
+            } else {
+                return Ok(options);
+            };
+            let property = self.parse_identifier(false)?;
+            options.push(CreateTableLikeOption {
+                including,
+                property,
+            });
+        }
+    }
+
+    /// Parse PostgreSQL's `PARTITION BY { RANGE | LIST | HASH } ( <key> [, ...] )`,
+    /// leaving other `PARTITION BY` clauses to dialect-specific parsing
+    fn parse_optional_table_partitioning(
+        &mut self,
+    ) -> Result<Option<TablePartitioning>, ParserError> {
+        let method = match self.peek_tokens() {
+            [Token::Word(partition), Token::Word(by), Token::Word(method)]
+                if partition.keyword == Keyword::PARTITION && by.keyword == Keyword::BY =>
+            {
+                match method.keyword {
+                    Keyword::RANGE => PartitionMethod::Range,
+                    Keyword::LIST => PartitionMethod::List,
+                    Keyword::HASH => PartitionMethod::Hash,
+                    _ => return Ok(None),
+                }
+            }
+            _ => return Ok(None),
+        };
+        self.expect_keywords(&[Keyword::PARTITION, Keyword::BY])?;
+        self.next_token();
+        let keys = self.parse_parenthesized(|p| p.parse_comma_separated(Parser::parse_expr))?;
+        Ok(Some(TablePartitioning { method, keys }))
+    }
+
+    /// Parse the bound of a PostgreSQL partition, following `PARTITION OF <parent>`
+    pub fn parse_partition_bound(&mut self) -> Result<PartitionBound, ParserError> {
+        if self.parse_keyword(Keyword::DEFAULT) {
+            return Ok(PartitionBound::Default);
+        }
+        self.expect_keywords(&[Keyword::FOR, Keyword::VALUES])?;
+        if self.parse_keyword(Keyword::IN) {
+            let values =
+                self.parse_parenthesized(|p| p.parse_comma_separated(Parser::parse_expr))?;
+            Ok(PartitionBound::In(values))
+        } else if self.parse_keyword(Keyword::FROM) {
+            let from = self.parse_parenthesized(|p| {
+                p.parse_comma_separated(Parser::parse_partition_range_value)
+            })?;
+            self.expect_keyword(Keyword::TO)?;
+            let to = self.parse_parenthesized(|p| {
+                p.parse_comma_separated(Parser::parse_partition_range_value)
+            })?;
+            Ok(PartitionBound::FromTo { from, to })
+        } else if self.parse_keyword(Keyword::WITH) {
+            self.expect_token(&Token::LParen)?;
+            self.expect_keyword(Keyword::MODULUS)?;
+            let modulus = self.parse_literal_uint()?;
+            self.expect_token(&Token::Comma)?;
+            self.expect_keyword(Keyword::REMAINDER)?;
+            let remainder = self.parse_literal_uint()?;
+            self.expect_token(&Token::RParen)?;
+            Ok(PartitionBound::WithModulus { modulus, remainder })
+        } else {
+            self.expected("IN, FROM or WITH after FOR VALUES", self.peek_token())
+        }
+    }
+
+    fn parse_partition_range_value(&mut self) -> Result<PartitionRangeValue, ParserError> {
+        if self.parse_keyword(Keyword::MINVALUE) {
+            Ok(PartitionRangeValue::MinValue)
+        } else if self.parse_keyword(Keyword::MAXVALUE) {
+            Ok(PartitionRangeValue::MaxValue)
+        } else {
+            Ok(PartitionRangeValue::Expr(Box::new(self.parse_expr()?)))
+        }
+    }
+
+    /// Parse the Redshift `DISTSTYLE`, `DISTKEY` and `SORTKEY` table attributes.
+    /// <https://docs.aws.amazon.com/redshift/latest/dg/r_CREATE_TABLE_NEW.html>
+    fn parse_optional_redshift_create_table_config(
+        &mut self,
+    ) -> Result<RedshiftTableConfiguration, ParserError> {
+        let dist_style = if self.parse_keyword(Keyword::DISTSTYLE) {
+            let next_token = self.next_token();
+            match &next_token.token {
+                Token::Word(w) => match w.keyword {
+                    Keyword::AUTO => Some(DistStyle::Auto),
+                    Keyword::EVEN => Some(DistStyle::Even),
+                    Keyword::KEY => Some(DistStyle::Key),
+                    Keyword::ALL => Some(DistStyle::All),
+                    _ => self.expected("AUTO, EVEN, KEY or ALL", next_token)?,
+                },
+                _ => self.expected("AUTO, EVEN, KEY or ALL", next_token)?,
+            }
+        } else {
+            None
+        };
+
+        let dist_key = if self.parse_keyword(Keyword::DISTKEY) {
+            Some(self.parse_parenthesized(|p| p.parse_identifier(false))?)
+        } else {
+            None
+        };
+
+        let kind = if self.parse_keywords(&[Keyword::COMPOUND, Keyword::SORTKEY]) {
+            Some(SortKeyKind::Compound)
+        } else if self.parse_keywords(&[Keyword::INTERLEAVED, Keyword::SORTKEY]) {
+            Some(SortKeyKind::Interleaved)
+        } else {
+            None
+        };
+        let sort_key = if kind.is_some() || self.parse_keyword(Keyword::SORTKEY) {
+            Some(SortKey {
+                kind,
+                columns: self.parse_parenthesized_column_list(Mandatory, false)?,
+            })
+        } else {
+            None
+        };
+
+        Ok(RedshiftTableConfiguration {
+            dist_style,
+            dist_key,
+            sort_key,
+        })
+    }
+
     /// Parse configuration like partitioning, clustering information during big-query table creation.
     /// <https://cloud.google.com/bigquery/docs/reference/standard-sql/data-definition-language#syntax_2>
     fn parse_optional_big_query_create_table_config(
@@ -5465,6 +6189,15 @@ impl<'a> Parser<'a> {
     }
 
     pub fn parse_columns(&mut self) -> Result<(Vec<ColumnDef>, Vec<TableConstraint>), ParserError> {
+        self.parse_table_elements(None)
+    }
+
+    /// Parse an optional parenthesized list of column definitions and table constraints,
+    /// also accepting `LIKE <table> [options]` elements when `like_elements` is given
+    fn parse_table_elements(
+        &mut self,
+        mut like_elements: Option<&mut Vec<CreateTableLikeElement>>,
+    ) -> Result<(Vec<ColumnDef>, Vec<TableConstraint>), ParserError> {
         let mut columns = vec![];
         let mut constraints = vec![];
         if !self.consume_token(&Token::LParen) || self.consume_token(&Token::RParen) {
@@ -5472,7 +6205,17 @@ impl<'a> Parser<'a> {
         }
 
         loop {
-            if let Some(constraint) = self.parse_optional_table_constraint()? {
+            if let Some(like_elements) = like_elements
+                .as_deref_mut()
+                .filter(|_| self.parse_keyword(Keyword::LIKE))
+            {
+                let name = self.parse_object_name(false)?;
+                let options = self.parse_create_table_like_options()?;
+                like_elements.push(CreateTableLikeElement {
+                    column_index: columns.len(),
+                    like: CreateTableLike { name, options },
+                });
+            } else if let Some(constraint) = self.parse_optional_table_constraint()? {
                 constraints.push(constraint);
             } else if let Token::Word(_) = self.peek_token().token {
                 columns.push(self.parse_column_def()?);
@@ -5601,11 +6344,14 @@ impl<'a> Parser<'a> {
             // PostgreSQL allows omitting the column list and
             // uses the primary key column of the foreign table by default
             let referred_columns = self.parse_parenthesized_column_list(Optional, false)?;
+            let match_kind = self.parse_optional_constraint_reference_match_kind()?;
             let mut on_delete = None;
             let mut on_update = None;
+            let mut on_update_first = false;
             loop {
                 if on_delete.is_none() && self.parse_keywords(&[Keyword::ON, Keyword::DELETE]) {
                     on_delete = Some(self.parse_referential_action()?);
+                    on_update_first = on_update.is_some();
                 } else if on_update.is_none()
                     && self.parse_keywords(&[Keyword::ON, Keyword::UPDATE])
                 {
@@ -5619,15 +6365,19 @@ impl<'a> Parser<'a> {
             Ok(Some(ColumnOption::ForeignKey {
                 foreign_table,
                 referred_columns,
+                match_kind,
                 on_delete,
                 on_update,
+                on_update_first,
                 characteristics,
             }))
         } else if self.parse_keyword(Keyword::CHECK) {
             self.expect_token(&Token::LParen)?;
             let expr = self.parse_expr()?;
             self.expect_token(&Token::RParen)?;
-            Ok(Some(ColumnOption::Check(expr)))
+            let no_inherit = dialect_of!(self is PostgreSqlDialect | GenericDialect)
+                && self.parse_keywords(&[Keyword::NO, Keyword::INHERIT]);
+            Ok(Some(ColumnOption::Check { expr, no_inherit }))
         } else if self.parse_keyword(Keyword::AUTO_INCREMENT)
             && dialect_of!(self is MySqlDialect | GenericDialect)
         {
@@ -5656,6 +6406,18 @@ impl<'a> Parser<'a> {
             Ok(Some(ColumnOption::Options(
                 self.parse_options(Keyword::OPTIONS)?,
             )))
+        } else if dialect_of!(self is RedshiftSqlDialect | GenericDialect)
+            && self.parse_keyword(Keyword::ENCODE)
+        {
+            Ok(Some(ColumnOption::Encode(self.parse_identifier(false)?)))
+        } else if dialect_of!(self is MySqlDialect | GenericDialect)
+            && self.parse_keyword(Keyword::VISIBLE)
+        {
+            Ok(Some(ColumnOption::Visible(true)))
+        } else if dialect_of!(self is MySqlDialect | GenericDialect)
+            && self.parse_keyword(Keyword::INVISIBLE)
+        {
+            Ok(Some(ColumnOption::Visible(false)))
         } else if self.parse_keyword(Keyword::AS)
             && dialect_of!(self is MySqlDialect | SQLiteDialect | DuckDbDialect | GenericDialect)
         {
@@ -5757,6 +6519,20 @@ impl<'a> Parser<'a> {
         }))
     }
 
+    fn parse_optional_constraint_reference_match_kind(
+        &mut self,
+    ) -> Result<Option<ConstraintReferenceMatchKind>, ParserError> {
+        if !self.parse_keyword(Keyword::MATCH) {
+            return Ok(None);
+        }
+        match self.expect_one_of_keywords(&[Keyword::FULL, Keyword::PARTIAL, Keyword::SIMPLE])? {
+            Keyword::FULL => Ok(Some(ConstraintReferenceMatchKind::Full)),
+            Keyword::PARTIAL => Ok(Some(ConstraintReferenceMatchKind::Partial)),
+            Keyword::SIMPLE => Ok(Some(ConstraintReferenceMatchKind::Simple)),
+            _ => unreachable!(),
+        }
+    }
+
     pub fn parse_referential_action(&mut self) -> Result<ReferentialAction, ParserError> {
         if self.parse_keyword(Keyword::RESTRICT) {
             Ok(ReferentialAction::Restrict)
@@ -5879,12 +6655,17 @@ impl<'a> Parser<'a> {
                 let columns = self.parse_parenthesized_column_list(Mandatory, false)?;
                 self.expect_keyword(Keyword::REFERENCES)?;
                 let foreign_table = self.parse_object_name(false)?;
-                let referred_columns = self.parse_parenthesized_column_list(Mandatory, false)?;
+                // as with the column option, the referenced columns default to
+                // the primary key of the foreign table when omitted
+                let referred_columns = self.parse_parenthesized_column_list(Optional, false)?;
+                let match_kind = self.parse_optional_constraint_reference_match_kind()?;
                 let mut on_delete = None;
                 let mut on_update = None;
+                let mut on_update_first = false;
                 loop {
                     if on_delete.is_none() && self.parse_keywords(&[Keyword::ON, Keyword::DELETE]) {
                         on_delete = Some(self.parse_referential_action()?);
+                        on_update_first = on_update.is_some();
                     } else if on_update.is_none()
                         && self.parse_keywords(&[Keyword::ON, Keyword::UPDATE])
                     {
@@ -5901,8 +6682,10 @@ impl<'a> Parser<'a> {
                     columns,
                     foreign_table,
                     referred_columns,
+                    match_kind,
                     on_delete,
                     on_update,
+                    on_update_first,
                     characteristics,
                 }))
             }
@@ -5910,7 +6693,13 @@ impl<'a> Parser<'a> {
                 self.expect_token(&Token::LParen)?;
                 let expr = Box::new(self.parse_expr()?);
                 self.expect_token(&Token::RParen)?;
-                Ok(Some(TableConstraint::Check { name, expr }))
+                let no_inherit = dialect_of!(self is PostgreSqlDialect | GenericDialect)
+                    && self.parse_keywords(&[Keyword::NO, Keyword::INHERIT]);
+                Ok(Some(TableConstraint::Check {
+                    name,
+                    expr,
+                    no_inherit,
+                }))
             }
             Token::Word(w)
                 if (w.keyword == Keyword::INDEX || w.keyword == Keyword::KEY)
@@ -6458,6 +7247,7 @@ impl<'a> Parser<'a> {
                 over: None,
                 filter: None,
                 null_treatment: None,
+                nth_value_order: None,
                 within_group: vec![],
             }))
         }
@@ -6504,7 +7294,7 @@ impl<'a> Parser<'a> {
                 filename: self.parse_literal_string()?,
             }
         };
-        let _ = self.parse_keyword(Keyword::WITH); // [ WITH ]
+        let with = self.parse_keyword(Keyword::WITH);
         let mut options = vec![];
         if self.consume_token(&Token::LParen) {
             options = self.parse_comma_separated(Parser::parse_copy_option)?;
@@ -6524,6 +7314,7 @@ impl<'a> Parser<'a> {
             source,
             to,
             target,
+            with,
             options,
             legacy_options,
             values,
@@ -6551,6 +7342,7 @@ impl<'a> Parser<'a> {
             Keyword::HEADER,
             Keyword::QUOTE,
             Keyword::ESCAPE,
+            Keyword::DEFAULT,
             Keyword::FORCE_QUOTE,
             Keyword::FORCE_NOT_NULL,
             Keyword::FORCE_NULL,
@@ -6563,12 +7355,20 @@ impl<'a> Parser<'a> {
             )),
             Some(Keyword::DELIMITER) => CopyOption::Delimiter(self.parse_literal_char()?),
             Some(Keyword::NULL) => CopyOption::Null(self.parse_literal_string()?),
-            Some(Keyword::HEADER) => CopyOption::Header(!matches!(
-                self.parse_one_of_keywords(&[Keyword::TRUE, Keyword::FALSE]),
-                Some(Keyword::FALSE)
-            )),
+            Some(Keyword::HEADER) => {
+                match self.parse_one_of_keywords(&[Keyword::TRUE, Keyword::FALSE, Keyword::MATCH]) {
+                    Some(Keyword::MATCH) => CopyOption::HeaderMatch,
+                    Some(Keyword::TRUE) => CopyOption::Header(Some(true)),
+                    Some(Keyword::FALSE) => CopyOption::Header(Some(false)),
+                    _ => CopyOption::Header(None),
+                }
+            }
             Some(Keyword::QUOTE) => CopyOption::Quote(self.parse_literal_char()?),
             Some(Keyword::ESCAPE) => CopyOption::Escape(self.parse_literal_char()?),
+            Some(Keyword::DEFAULT) => CopyOption::Default(self.parse_literal_string()?),
+            Some(Keyword::FORCE_QUOTE) if self.consume_token(&Token::Mul) => {
+                CopyOption::ForceQuoteAll
+            }
             Some(Keyword::FORCE_QUOTE) => {
                 CopyOption::ForceQuote(self.parse_parenthesized_column_list(Mandatory, false)?)
             }
@@ -6725,6 +7525,12 @@ impl<'a> Parser<'a> {
             // The call to n.parse() returns a bigdecimal when the
             // bigdecimal feature is enabled, and is otherwise a no-op
             // (i.e., it returns the input string).
+            #[cfg(feature = "bigdecimal")]
+            Token::Number(ref n, l) if n.contains('_') => match n.replace('_', "").parse() {
+                // A bigdecimal can't keep the digit separators, so drop them
+                Ok(n) => Ok(Value::Number(n, l)),
+                Err(e) => parser_err!(format!("Could not parse '{n}' as number: {e}"), location),
+            },
             Token::Number(ref n, l) => match n.parse() {
                 Ok(n) => Ok(Value::Number(n, l)),
                 Err(e) => parser_err!(format!("Could not parse '{n}' as number: {e}"), location),
@@ -6764,7 +7570,21 @@ impl<'a> Parser<'a> {
             }
             Token::NationalStringLiteral(ref s) => Ok(Value::NationalStringLiteral(s.to_string())),
             Token::EscapedStringLiteral(ref s) => Ok(Value::EscapedStringLiteral(s.to_string())),
+            Token::UnicodeStringLiteral(ref s) => {
+                let escape = if self.parse_keyword(Keyword::UESCAPE) {
+                    Some(self.parse_unicode_escape_character()?)
+                } else {
+                    None
+                };
+                // validate the escapes, but keep the literal as written
+                self.unescape_unicode_string(s, escape.unwrap_or('\\'))?;
+                Ok(Value::UnicodeStringLiteral {
+                    value: s.to_string(),
+                    escape,
+                })
+            }
             Token::HexStringLiteral(ref s) => Ok(Value::HexStringLiteral(s.to_string())),
+            Token::BitValueLiteral(ref s) => Ok(Value::BitValueLiteral(s.to_string())),
             Token::Placeholder(ref s) => Ok(Value::Placeholder(s.to_string())),
             tok @ Token::Colon | tok @ Token::AtSign => {
                 // Not calling self.parse_identifier(false)? because only in placeholder we want to check numbers as idfentifies
@@ -6788,6 +7608,94 @@ impl<'a> Parser<'a> {
         }
     }
 
+    /// Parse the escape character of a `UESCAPE 'c'` clause
+    fn parse_unicode_escape_character(&mut self) -> Result<char, ParserError> {
+        let next_token = self.next_token();
+        match &next_token.token {
+            Token::SingleQuotedString(s) => {
+                let mut chars = s.chars();
+                match (chars.next(), chars.next()) {
+                    (Some(c), None)
+                        if !c.is_ascii_hexdigit()
+                            && !c.is_whitespace()
+                            && !matches!(c, '+' | '\'' | '"') =>
+                    {
+                        Ok(c)
+                    }
+                    _ => parser_err!(
+                        format!("Invalid Unicode escape character: '{s}'"),
+                        next_token.location
+                    ),
+                }
+            }
+            _ => self.expected("Unicode escape character", next_token),
+        }
+    }
+
+    /// Decode the `\XXXX` and `\+XXXXXX` escapes of a `U&'...'` string literal,
+    /// using `escape` in place of the backslash
+    fn unescape_unicode_string(&self, s: &str, escape: char) -> Result<String, ParserError> {
+        fn code_point(chars: &mut core::str::Chars, digits: usize) -> Option<u32> {
+            let hex: String = chars.take(digits).collect();
+            if hex.len() != digits || !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+                return None;
+            }
+            u32::from_str_radix(&hex, 16).ok()
+        }
+
+        let mut unescaped = String::new();
+        let mut chars = s.chars();
+        let mut high_surrogate = None;
+        while let Some(c) = chars.next() {
+            let code = match (c, chars.clone().next()) {
+                (c, _) if c != escape => None,
+                (_, Some(next)) if next == escape => {
+                    chars.next();
+                    None
+                }
+                (_, Some('+')) => {
+                    chars.next();
+                    Some(code_point(&mut chars, 6))
+                }
+                _ => Some(code_point(&mut chars, 4)),
+            };
+            let decoded = match (high_surrogate.take(), code) {
+                (None, None) => Some(c),
+                (_, Some(None)) => {
+                    return parser_err!(
+                        format!("Invalid Unicode escape in U&'{s}'"),
+                        self.peek_token().location
+                    );
+                }
+                (None, Some(Some(code @ 0xD800..=0xDBFF))) => {
+                    high_surrogate = Some(code);
+                    continue;
+                }
+                (Some(high), Some(Some(low @ 0xDC00..=0xDFFF))) => {
+                    char::from_u32(0x10000 + ((high - 0xD800) << 10) + (low - 0xDC00))
+                }
+                (None, Some(Some(code))) => char::from_u32(code).filter(|c| *c != '\0'),
+                (Some(_), _) => None,
+            };
+            match decoded {
+                Some(c) => unescaped.push(c),
+                None => {
+                    return parser_err!(
+                        format!("Invalid Unicode code point in U&'{s}'"),
+                        self.peek_token().location
+                    );
+                }
+            }
+        }
+        if high_surrogate.is_some() {
+            return parser_err!(
+                format!("Invalid Unicode surrogate pair in U&'{s}'"),
+                self.peek_token().location
+            );
+        }
+        Ok(unescaped)
+    }
+
     pub fn parse_number_value(&mut self) -> Result<Value, ParserError> {
         match self.parse_value()? {
             v @ Value::Number(_, _) => Ok(v),
@@ -6832,7 +7740,7 @@ impl<'a> Parser<'a> {
     fn parse_create_function_body_string(&mut self) -> Result<Expr, ParserError> {
         let peek_token = self.peek_token();
         match peek_token.token {
-            Token::DollarQuotedString(s) if dialect_of!(self is PostgreSqlDialect | GenericDialect) =>
+            Token::DollarQuotedString(s) if dialect_of!(self is PostgreSqlDialect | SnowflakeDialect | GenericDialect) =>
             {
                 self.next_token();
                 Ok(Expr::Value(Value::DollarQuotedString(s)))
@@ -7160,6 +8068,11 @@ impl<'a> Parser<'a> {
             self.expect_token(&Token::RBracket)?;
             data = DataType::Array(ArrayElemTypeDef::SquareBracket(Box::new(data), size))
         }
+        if dialect_of!(self is GenericDialect | AnsiDialect) {
+            while self.parse_keyword(Keyword::MULTISET) {
+                data = DataType::Multiset(Box::new(data));
+            }
+        }
         Ok((data, trailing_bracket))
     }
 
@@ -7241,6 +8154,15 @@ impl<'a> Parser<'a> {
         &mut self,
         reserved_kwds: &[Keyword],
     ) -> Result<Option<TableAlias>, ParserError> {
+        // PostgreSQL reserves `RETURNING`, which may follow the table in e.g.
+        // `DELETE FROM t RETURNING *`
+        if dialect_of!(self is PostgreSqlDialect | GenericDialect) {
+            if let Token::Word(w) = self.peek_token().token {
+                if w.keyword == Keyword::RETURNING {
+                    return Ok(None);
+                }
+            }
+        }
         match self.parse_optional_alias(reserved_kwds)? {
             Some(name) => {
                 let columns = self.parse_parenthesized_column_list(Optional, false)?;
@@ -7657,12 +8579,32 @@ impl<'a> Parser<'a> {
         Ok(parent_type(inside_type.into()))
     }
 
+    /// Parse a DELETE statement, returning a `Box`ed SetExpr
+    ///
+    /// This is used to reduce the size of the stack frames in debug builds
+    fn parse_delete_setexpr_boxed(&mut self) -> Result<Box<SetExpr>, ParserError> {
+        Ok(Box::new(SetExpr::Delete(self.parse_delete()?)))
+    }
+
     pub fn parse_delete(&mut self) -> Result<Statement, ParserError> {
         let (tables, with_from_keyword) = if !self.parse_keyword(Keyword::FROM) {
             // `FROM` keyword is optional in BigQuery SQL.
             // https://cloud.google.com/bigquery/docs/reference/standard-sql/dml-syntax#delete_statement
             if dialect_of!(self is BigQueryDialect | GenericDialect) {
-                (vec![], false)
+                // MySQL multi-table `DELETE t1, t2 FROM ...` is tried first in the generic dialect
+                let tables = if dialect_of!(self is GenericDialect) {
+                    self.maybe_parse(|p| {
+                        let tables = p.parse_comma_separated(|p| p.parse_object_name(false))?;
+                        p.expect_keyword(Keyword::FROM)?;
+                        Ok(tables)
+                    })
+                } else {
+                    None
+                };
+                match tables {
+                    Some(tables) => (tables, true),
+                    None => (vec![], false),
+                }
             } else {
                 let tables = self.parse_comma_separated(|p| p.parse_object_name(false))?;
                 self.expect_keyword(Keyword::FROM)?;
@@ -7688,6 +8630,7 @@ impl<'a> Parser<'a> {
         } else {
             None
         };
+        let returning_into = self.parse_returning_into(&returning)?;
         let order_by = if self.parse_keywords(&[Keyword::ORDER, Keyword::BY]) {
             self.parse_comma_separated(Parser::parse_order_by_expr)?
         } else {
@@ -7709,6 +8652,7 @@ impl<'a> Parser<'a> {
             using,
             selection,
             returning,
+            returning_into,
             order_by,
             limit,
         }))
@@ -7803,22 +8747,21 @@ impl<'a> Parser<'a> {
         } else {
             None
         };
-        if self.parse_keyword(Keyword::INSERT) {
-            Ok(Query {
-                with,
-                body: self.parse_insert_setexpr_boxed()?,
-                limit: None,
-                limit_by: vec![],
-                order_by: vec![],
-                offset: None,
-                fetch: None,
-                locks: vec![],
-                for_clause: None,
-            })
+        let dml_body = if self.parse_keyword(Keyword::INSERT) {
+            Some(self.parse_insert_setexpr_boxed()?)
         } else if self.parse_keyword(Keyword::UPDATE) {
+            Some(self.parse_update_setexpr_boxed()?)
+        } else if dialect_of!(self is PostgreSqlDialect | GenericDialect)
+            && self.parse_keyword(Keyword::DELETE)
+        {
+            Some(self.parse_delete_setexpr_boxed()?)
+        } else {
+            None
+        };
+        if let Some(body) = dml_body {
             Ok(Query {
                 with,
-                body: self.parse_update_setexpr_boxed()?,
+                body,
                 limit: None,
                 limit_by: vec![],
                 order_by: vec![],
@@ -8171,20 +9114,13 @@ impl<'a> Parser<'a> {
     /// Parse a restricted `SELECT` statement (no CTEs / `UNION` / `ORDER BY`),
     /// assuming the initial `SELECT` was already consumed
     pub fn parse_select(&mut self) -> Result<Select, ParserError> {
-        let value_table_mode =
-            if dialect_of!(self is BigQueryDialect) && self.parse_keyword(Keyword::AS) {
-                if self.parse_keyword(Keyword::VALUE) {
-                    Some(ValueTableMode::AsValue)
-                } else if self.parse_keyword(Keyword::STRUCT) {
-                    Some(ValueTableMode::AsStruct)
-                } else {
-                    self.expected("VALUE or STRUCT", self.peek_token())?
-                }
-            } else {
-                None
-            };
-
+        let value_table_mode = self.parse_value_table_mode()?;
         let distinct = self.parse_all_or_distinct()?;
+        // BigQuery places `AS { VALUE | STRUCT }` after `DISTINCT`
+        let value_table_mode = match value_table_mode {
+            Some(mode) => Some(mode),
+            None => self.parse_value_table_mode()?,
+        };
 
         let top = if self.parse_keyword(Keyword::TOP) {
             Some(self.parse_top()?)
@@ -8261,6 +9197,10 @@ impl<'a> Parser<'a> {
 
         let group_by = if self.parse_keywords(&[Keyword::GROUP, Keyword::BY]) {
             if self.parse_keyword(Keyword::ALL) {
+                if !self.dialect.supports_group_by_all() {
+                    self.prev_token();
+                    return self.expected("an expression after GROUP BY", self.peek_token());
+                }
                 GroupByExpr::All
             } else {
                 GroupByExpr::Expressions(self.parse_comma_separated(Parser::parse_group_by_expr)?)
@@ -8293,14 +9233,13 @@ impl<'a> Parser<'a> {
             None
         };
 
-        // Accept QUALIFY and WINDOW in any order and flag accordingly.
-        let (named_windows, qualify, window_before_qualify) = if self.parse_keyword(Keyword::WINDOW)
-        {
+        // Accept QUALIFY and WINDOW in any order.
+        let (named_windows, qualify) = if self.parse_keyword(Keyword::WINDOW) {
             let named_windows = self.parse_comma_separated(Parser::parse_named_window)?;
             if self.parse_keyword(Keyword::QUALIFY) {
-                (named_windows, Some(self.parse_expr()?), true)
+                (named_windows, Some(self.parse_expr()?))
             } else {
-                (named_windows, None, true)
+                (named_windows, None)
             }
         } else if self.parse_keyword(Keyword::QUALIFY) {
             let qualify = Some(self.parse_expr()?);
@@ -8308,10 +9247,9 @@ impl<'a> Parser<'a> {
                 (
                     self.parse_comma_separated(Parser::parse_named_window)?,
                     qualify,
-                    false,
                 )
             } else {
-                (Default::default(), qualify, false)
+                (Default::default(), qualify)
             }
         } else {
             Default::default()
@@ -8342,7 +9280,6 @@ impl<'a> Parser<'a> {
             sort_by,
             having,
             named_window: named_windows,
-            window_before_qualify,
             qualify,
             value_table_mode,
             connect_by,
@@ -8431,18 +9368,39 @@ impl<'a> Parser<'a> {
         }
     }
 
+    /// Parse the character set or collation of `SET NAMES`, which may be a word,
+    /// a quoted identifier or a string, keeping the quotes as written
+    fn parse_set_names_name(&mut self, expected: &str) -> Result<Ident, ParserError> {
+        let next_token = self.next_token();
+        match next_token.token {
+            Token::Word(w) => Ok(w.to_ident()),
+            Token::SingleQuotedString(s) => Ok(Ident::with_quote('\'', s)),
+            Token::DoubleQuotedString(s) => Ok(Ident::with_quote('"', s)),
+            _ => self.expected(expected, next_token),
+        }
+    }
+
     pub fn parse_set(&mut self) -> Result<Statement, ParserError> {
-        let modifier =
-            self.parse_one_of_keywords(&[Keyword::SESSION, Keyword::LOCAL, Keyword::HIVEVAR]);
+        let modifiers: &[Keyword] = if dialect_of!(self is MySqlDialect | GenericDialect) {
+            &[
+                Keyword::SESSION,
+                Keyword::LOCAL,
+                Keyword::GLOBAL,
+                Keyword::HIVEVAR,
+            ]
+        } else {
+            &[Keyword::SESSION, Keyword::LOCAL, Keyword::HIVEVAR]
+        };
+        let modifier = self.parse_one_of_keywords(modifiers);
+        let context_modifier = match modifier {
+            Some(Keyword::LOCAL) => ContextModifier::Local,
+            Some(Keyword::SESSION) => ContextModifier::Session,
+            Some(Keyword::GLOBAL) => ContextModifier::Global,
+            _ => ContextModifier::None,
+        };
         if let Some(Keyword::HIVEVAR) = modifier {
             self.expect_token(&Token::Colon)?;
         } else if self.parse_keyword(Keyword::ROLE) {
-            let context_modifier = match modifier {
-                Some(Keyword::LOCAL) => ContextModifier::Local,
-                Some(Keyword::SESSION) => ContextModifier::Session,
-                _ => ContextModifier::None,
-            };
-
             let role_name = if self.parse_keyword(Keyword::NONE) {
                 None
             } else {
@@ -8474,15 +9432,15 @@ impl<'a> Parser<'a> {
         };
 
         if matches!(&variables, OneOrManyWithParens::One(variable) if variable.to_string().eq_ignore_ascii_case("NAMES")
-            && dialect_of!(self is MySqlDialect | GenericDialect))
+            && dialect_of!(self is MySqlDialect | PostgreSqlDialect | GenericDialect))
         {
             if self.parse_keyword(Keyword::DEFAULT) {
                 return Ok(Statement::SetNamesDefault {});
             }
 
-            let charset_name = self.parse_literal_string()?;
-            let collation_name = if self.parse_one_of_keywords(&[Keyword::COLLATE]).is_some() {
-                Some(self.parse_literal_string()?)
+            let charset_name = self.parse_set_names_name("character set name")?;
+            let collation_name = if self.parse_keyword(Keyword::COLLATE) {
+                Some(self.parse_set_names_name("collation name")?)
             } else {
                 None
             };
@@ -8495,7 +9453,14 @@ impl<'a> Parser<'a> {
 
         let parenthesized_assignment = matches!(&variables, OneOrManyWithParens::Many(_));
 
-        if self.consume_token(&Token::Eq) || self.parse_keyword(Keyword::TO) {
+        let assignment = if self.consume_token(&Token::Eq) {
+            Some(false)
+        } else if self.parse_keyword(Keyword::TO) {
+            Some(true)
+        } else {
+            None
+        };
+        if let Some(to_keyword) = assignment {
             if parenthesized_assignment {
                 self.expect_token(&Token::LParen)?;
             }
@@ -8517,10 +9482,11 @@ impl<'a> Parser<'a> {
                     self.expect_token(&Token::RParen)?;
                 }
                 return Ok(Statement::SetVariable {
-                    local: modifier == Some(Keyword::LOCAL),
+                    scope: context_modifier,
                     hivevar: Some(Keyword::HIVEVAR) == modifier,
                     variables,
                     value: values,
+                    to_keyword,
                 });
             }
         }
@@ -8533,7 +9499,7 @@ impl<'a> Parser<'a> {
             // for some db (e.g. postgresql), SET TIME ZONE <value> is an alias for SET TIMEZONE [TO|=] <value>
             match self.parse_expr() {
                 Ok(expr) => Ok(Statement::SetTimeZone {
-                    local: modifier == Some(Keyword::LOCAL),
+                    scope: context_modifier,
                     value: expr,
                 }),
                 _ => self.expected("timezone value", self.peek_token())?,
@@ -8586,16 +9552,20 @@ impl<'a> Parser<'a> {
             Ok(self.parse_show_create()?)
         } else if self.parse_keyword(Keyword::COLLATION) {
             Ok(self.parse_show_collation()?)
-        } else if self.parse_keyword(Keyword::VARIABLES)
-            && dialect_of!(self is MySqlDialect | GenericDialect)
+        } else if dialect_of!(self is MySqlDialect | GenericDialect)
+            && self.parse_keyword(Keyword::VARIABLES)
         {
             Ok(Statement::ShowVariables {
                 filter: self.parse_show_statement_filter()?,
                 session,
                 global,
             })
-        } else if self.parse_keyword(Keyword::STATUS)
-            && dialect_of!(self is MySqlDialect | GenericDialect)
+        } else if dialect_of!(self is MySqlDialect | GenericDialect)
+            && self.parse_keyword(Keyword::DATABASES)
+        {
+            Ok(self.parse_show_databases()?)
+        } else if dialect_of!(self is MySqlDialect | GenericDialect)
+            && self.parse_keyword(Keyword::STATUS)
         {
             Ok(Statement::ShowStatus {
                 filter: self.parse_show_statement_filter()?,
@@ -8682,6 +9652,11 @@ impl<'a> Parser<'a> {
         Ok(Statement::ShowFunctions { filter })
     }
 
+    pub fn parse_show_databases(&mut self) -> Result<Statement, ParserError> {
+        let filter = self.parse_show_statement_filter()?;
+        Ok(Statement::ShowDatabases { filter })
+    }
+
     pub fn parse_show_collation(&mut self) -> Result<Statement, ParserError> {
         let filter = self.parse_show_statement_filter()?;
         Ok(Statement::ShowCollation { filter })
@@ -8706,8 +9681,34 @@ impl<'a> Parser<'a> {
     }
 
     pub fn parse_use(&mut self) -> Result<Statement, ParserError> {
-        let db_name = self.parse_identifier(false)?;
-        Ok(Statement::Use { db_name })
+        let keywords: &[Keyword] = if dialect_of!(self is SnowflakeDialect | GenericDialect) {
+            &[
+                Keyword::DATABASE,
+                Keyword::SCHEMA,
+                Keyword::WAREHOUSE,
+                Keyword::ROLE,
+            ]
+        } else if dialect_of!(self is DatabricksDialect | MySqlDialect) {
+            &[Keyword::DATABASE, Keyword::SCHEMA]
+        } else {
+            &[]
+        };
+
+        // The object kind is optional, e.g. `USE schema` names an object called `schema`
+        let kind = match self.peek_nth_token(1).token {
+            Token::EOF | Token::SemiColon => None,
+            _ => self.parse_one_of_keywords(keywords),
+        };
+        let name = self.parse_object_name(false)?;
+
+        let use_expr = match kind {
+            Some(Keyword::DATABASE) => Use::Database(name),
+            Some(Keyword::SCHEMA) => Use::Schema(name),
+            Some(Keyword::WAREHOUSE) => Use::Warehouse(name),
+            Some(Keyword::ROLE) => Use::Role(name),
+            _ => Use::Object(name),
+        };
+        Ok(Statement::Use(use_expr))
     }
 
     pub fn parse_table_and_joins(&mut self) -> Result<TableWithJoins, ParserError> {
@@ -9067,9 +10068,6 @@ impl<'a> Parser<'a> {
                 vec![]
             };
 
-            // Parse potential version qualifier
-            let version = self.parse_table_version()?;
-
             // Postgres, MSSQL: table-valued functions:
             let args = if self.consume_token(&Token::LParen) {
                 Some(self.parse_optional_args()?)
@@ -9077,6 +10075,9 @@ impl<'a> Parser<'a> {
                 None
             };
 
+            // Parse potential version qualifier
+            let version = self.parse_table_version()?;
+
             let alias = self.parse_optional_table_alias(keywords::RESERVED_FOR_TABLE_ALIAS)?;
 
             // MSSQL-specific table hints:
@@ -9348,18 +10349,76 @@ impl<'a> Parser<'a> {
 
     /// Parse a given table version specifier.
     ///
-    /// For now it only supports timestamp versioning for BigQuery and MSSQL dialects.
+    /// Supports the `FOR SYSTEM_TIME` temporal queries of BigQuery and MSSQL and
+    /// Snowflake's `AT` / `BEFORE` time travel clauses.
     pub fn parse_table_version(&mut self) -> Result<Option<TableVersion>, ParserError> {
-        if dialect_of!(self is BigQueryDialect | MsSqlDialect)
-            && self.parse_keywords(&[Keyword::FOR, Keyword::SYSTEM_TIME, Keyword::AS, Keyword::OF])
+        if dialect_of!(self is BigQueryDialect | MsSqlDialect | GenericDialect)
+            && self.parse_keywords(&[Keyword::FOR, Keyword::SYSTEM_TIME])
         {
-            let expr = self.parse_expr()?;
-            Ok(Some(TableVersion::ForSystemTimeAsOf(expr)))
+            let version = if self.parse_keywords(&[Keyword::AS, Keyword::OF]) {
+                TableVersion::ForSystemTimeAsOf(self.parse_expr()?)
+            } else if self.parse_keyword(Keyword::BETWEEN) {
+                let start = self.parse_subexpr(Self::BETWEEN_PREC)?;
+                self.expect_keyword(Keyword::AND)?;
+                let end = self.parse_subexpr(Self::BETWEEN_PREC)?;
+                TableVersion::ForSystemTimeBetween(start, end)
+            } else if self.parse_keyword(Keyword::FROM) {
+                let start = self.parse_expr()?;
+                self.expect_keyword(Keyword::TO)?;
+                let end = self.parse_expr()?;
+                TableVersion::ForSystemTimeFromTo(start, end)
+            } else if self.parse_keywords(&[Keyword::CONTAINED, Keyword::IN]) {
+                self.expect_token(&Token::LParen)?;
+                let start = self.parse_expr()?;
+                self.expect_token(&Token::Comma)?;
+                let end = self.parse_expr()?;
+                self.expect_token(&Token::RParen)?;
+                TableVersion::ForSystemTimeContainedIn(start, end)
+            } else if self.parse_keyword(Keyword::ALL) {
+                TableVersion::ForSystemTimeAll
+            } else {
+                return self.expected(
+                    "AS OF, BETWEEN, FROM, CONTAINED IN or ALL after FOR SYSTEM_TIME",
+                    self.peek_token(),
+                );
+            };
+            Ok(Some(version))
+        } else if dialect_of!(self is SnowflakeDialect | GenericDialect)
+            && self.peek_nth_token(1) == Token::LParen
+        {
+            match self.parse_one_of_keywords(&[Keyword::AT, Keyword::BEFORE]) {
+                Some(Keyword::AT) => Ok(Some(TableVersion::At(self.parse_time_travel_point()?))),
+                Some(Keyword::BEFORE) => {
+                    Ok(Some(TableVersion::Before(self.parse_time_travel_point()?)))
+                }
+                _ => Ok(None),
+            }
         } else {
             Ok(None)
         }
     }
 
+    /// Parses the parenthesized `<kind> => <expr>` of a Snowflake `AT` / `BEFORE` clause.
+    fn parse_time_travel_point(&mut self) -> Result<TimeTravelPoint, ParserError> {
+        self.expect_token(&Token::LParen)?;
+        let kind = self.expect_one_of_keywords(&[
+            Keyword::TIMESTAMP,
+            Keyword::OFFSET,
+            Keyword::STATEMENT,
+            Keyword::STREAM,
+        ])?;
+        self.expect_token(&Token::RArrow)?;
+        let expr = self.parse_expr()?;
+        self.expect_token(&Token::RParen)?;
+        Ok(match kind {
+            Keyword::TIMESTAMP => TimeTravelPoint::Timestamp(expr),
+            Keyword::OFFSET => TimeTravelPoint::Offset(expr),
+            Keyword::STATEMENT => TimeTravelPoint::Statement(expr),
+            Keyword::STREAM => TimeTravelPoint::Stream(expr),
+            _ => unreachable!(),
+        })
+    }
+
     /// Parses MySQL's JSON_TABLE column definition.
     /// For example: `id INT EXISTS PATH '$' DEFAULT '0' ON EMPTY ERROR ON ERROR`
     pub fn parse_json_table_column_def(&mut self) -> Result<JsonTableColumn, ParserError> {
@@ -9502,6 +10561,13 @@ impl<'a> Parser<'a> {
         &mut self,
         table: TableFactor,
     ) -> Result<TableFactor, ParserError> {
+        let null_inclusion = if self.parse_keywords(&[Keyword::INCLUDE, Keyword::NULLS]) {
+            Some(NullInclusion::IncludeNulls)
+        } else if self.parse_keywords(&[Keyword::EXCLUDE, Keyword::NULLS]) {
+            Some(NullInclusion::ExcludeNulls)
+        } else {
+            None
+        };
         self.expect_token(&Token::LParen)?;
         let value = self.parse_identifier(false)?;
         self.expect_keyword(Keyword::FOR)?;
@@ -9515,6 +10581,7 @@ impl<'a> Parser<'a> {
             value,
             name,
             columns,
+            null_inclusion,
             alias,
         })
     }
@@ -9544,9 +10611,11 @@ impl<'a> Parser<'a> {
         let with_grant_option =
             self.parse_keywords(&[Keyword::WITH, Keyword::GRANT, Keyword::OPTION]);
 
-        let granted_by = self
-            .parse_keywords(&[Keyword::GRANTED, Keyword::BY])
-            .then(|| self.parse_identifier(false).unwrap());
+        let granted_by = if self.parse_keywords(&[Keyword::GRANTED, Keyword::BY]) {
+            Some(self.parse_identifier(false)?)
+        } else {
+            None
+        };
 
         Ok(Statement::Grant {
             privileges,
@@ -9624,13 +10693,25 @@ impl<'a> Parser<'a> {
                 schemas: self.parse_comma_separated(|p| p.parse_object_name(false))?,
             }
         } else {
-            let object_type =
-                self.parse_one_of_keywords(&[Keyword::SEQUENCE, Keyword::SCHEMA, Keyword::TABLE]);
+            let object_type = self.parse_one_of_keywords(&[
+                Keyword::DATABASE,
+                Keyword::SEQUENCE,
+                Keyword::SCHEMA,
+                Keyword::TABLE,
+            ]);
             let objects = self.parse_comma_separated(|p| p.parse_object_name(false));
             match object_type {
+                Some(Keyword::DATABASE) => GrantObjects::Databases(objects?),
                 Some(Keyword::SCHEMA) => GrantObjects::Schemas(objects?),
                 Some(Keyword::SEQUENCE) => GrantObjects::Sequences(objects?),
-                Some(Keyword::TABLE) | None => GrantObjects::Tables(objects?),
+                Some(Keyword::TABLE) => GrantObjects::Tables {
+                    table_keyword: true,
+                    tables: objects?,
+                },
+                None => GrantObjects::Tables {
+                    table_keyword: false,
+                    tables: objects?,
+                },
                 _ => unreachable!(),
             }
         };
@@ -9677,14 +10758,23 @@ impl<'a> Parser<'a> {
         self.expect_keyword(Keyword::FROM)?;
         let grantees = self.parse_comma_separated(|p| p.parse_identifier(false))?;
 
-        let granted_by = self
-            .parse_keywords(&[Keyword::GRANTED, Keyword::BY])
-            .then(|| self.parse_identifier(false).unwrap());
+        let granted_by = if self.parse_keywords(&[Keyword::GRANTED, Keyword::BY]) {
+            Some(self.parse_identifier(false)?)
+        } else {
+            None
+        };
 
         let loc = self.peek_token().location;
-        let cascade = self.parse_keyword(Keyword::CASCADE);
-        let restrict = self.parse_keyword(Keyword::RESTRICT);
-        if cascade && restrict {
+        let cascade = match self.parse_one_of_keywords(&[Keyword::CASCADE, Keyword::RESTRICT]) {
+            Some(Keyword::CASCADE) => Some(CascadeOption::Cascade),
+            Some(Keyword::RESTRICT) => Some(CascadeOption::Restrict),
+            _ => None,
+        };
+        if cascade.is_some()
+            && self
+                .parse_one_of_keywords(&[Keyword::CASCADE, Keyword::RESTRICT])
+                .is_some()
+        {
             return parser_err!("Cannot specify both CASCADE and RESTRICT in REVOKE", loc);
         }
 
@@ -9720,6 +10810,7 @@ impl<'a> Parser<'a> {
 
     /// Parse an INSERT statement
     pub fn parse_insert(&mut self) -> Result<Statement, ParserError> {
+        let mut replace_into = false;
         let or = if !dialect_of!(self is SQLiteDialect) {
             None
         } else if self.parse_keywords(&[Keyword::OR, Keyword::REPLACE]) {
@@ -9733,6 +10824,7 @@ impl<'a> Parser<'a> {
         } else if self.parse_keywords(&[Keyword::OR, Keyword::IGNORE]) {
             Some(SqliteOnConflict::Ignore)
         } else if self.parse_keyword(Keyword::REPLACE) {
+            replace_into = true;
             Some(SqliteOnConflict::Replace)
         } else {
             None
@@ -9753,8 +10845,6 @@ impl<'a> Parser<'a> {
         let ignore = dialect_of!(self is MySqlDialect | GenericDialect)
             && self.parse_keyword(Keyword::IGNORE);
 
-        let replace_into = false;
-
         let action = self.parse_one_of_keywords(&[Keyword::INTO, Keyword::OVERWRITE]);
         let into = action == Some(Keyword::INTO);
         let overwrite = action == Some(Keyword::OVERWRITE);
@@ -9790,9 +10880,14 @@ impl<'a> Parser<'a> {
 
             let is_mysql = dialect_of!(self is MySqlDialect);
 
-            let (columns, partitioned, after_columns, source) =
+            let (columns, partitioned, after_columns, source, assignments) =
                 if self.parse_keywords(&[Keyword::DEFAULT, Keyword::VALUES]) {
-                    (vec![], None, vec![], None)
+                    (vec![], None, vec![], None, vec![])
+                } else if dialect_of!(self is MySqlDialect | GenericDialect)
+                    && self.parse_keyword(Keyword::SET)
+                {
+                    let assignments = self.parse_comma_separated(Parser::parse_assignment)?;
+                    (vec![], None, vec![], None, assignments)
                 } else {
                     let columns = self.parse_parenthesized_column_list(Optional, is_mysql)?;
 
@@ -9806,7 +10901,7 @@ impl<'a> Parser<'a> {
 
                     let source = Some(self.parse_boxed_query()?);
 
-                    (columns, partitioned, after_columns, source)
+                    (columns, partitioned, after_columns, source, vec![])
                 };
 
             let insert_alias = if dialect_of!(self is MySqlDialect | GenericDialect)
@@ -9874,6 +10969,7 @@ impl<'a> Parser<'a> {
             } else {
                 None
             };
+            let returning_into = self.parse_returning_into(&returning)?;
 
             Ok(Statement::Insert(Insert {
                 or,
@@ -9889,9 +10985,11 @@ impl<'a> Parser<'a> {
                 table,
                 on,
                 returning,
+                returning_into,
                 replace_into,
                 priority,
                 insert_alias,
+                assignments,
             }))
         }
     }
@@ -9935,15 +11033,35 @@ impl<'a> Parser<'a> {
         } else {
             None
         };
+        let returning_into = self.parse_returning_into(&returning)?;
         Ok(Statement::Update {
             table,
             assignments,
             from,
             selection,
             returning,
+            returning_into,
         })
     }
 
+    /// Parse the optional `INTO [STRICT] var [, ...]` targets that may follow a
+    /// `RETURNING` list (PL/pgSQL)
+    fn parse_returning_into(
+        &mut self,
+        returning: &Option<Vec<SelectItem>>,
+    ) -> Result<Option<ReturningInto>, ParserError> {
+        if returning.is_some()
+            && dialect_of!(self is PostgreSqlDialect | GenericDialect)
+            && self.parse_keyword(Keyword::INTO)
+        {
+            let strict = self.parse_keyword(Keyword::STRICT);
+            let targets = self.parse_comma_separated(|p| p.parse_identifier(false))?;
+            Ok(Some(ReturningInto { strict, targets }))
+        } else {
+            Ok(None)
+        }
+    }
+
     /// Parse a `var = expr` assignment, used in an UPDATE statement
     pub fn parse_assignment(&mut self) -> Result<Assignment, ParserError> {
         let id = self.parse_identifiers()?;
@@ -10136,7 +11254,7 @@ impl<'a> Parser<'a> {
         } else {
             None
         };
-        let opt_rename = if dialect_of!(self is GenericDialect | SnowflakeDialect) {
+        let opt_rename = if dialect_of!(self is GenericDialect | DuckDbDialect | SnowflakeDialect) {
             self.parse_optional_select_item_rename()?
         } else {
             None
@@ -10374,7 +11492,7 @@ impl<'a> Parser<'a> {
         {
             (None, false)
         } else {
-            let quantity = Expr::Value(self.parse_value()?);
+            let quantity = self.parse_expr()?;
             let percent = self.parse_keyword(Keyword::PERCENT);
             self.expect_one_of_keywords(&[Keyword::ROW, Keyword::ROWS])?;
             (Some(quantity), percent)
@@ -10445,6 +11563,7 @@ impl<'a> Parser<'a> {
         Ok(Statement::StartTransaction {
             modes: self.parse_transaction_modes()?,
             begin: false,
+            transaction: None,
             modifier: None,
         })
     }
@@ -10461,10 +11580,15 @@ impl<'a> Parser<'a> {
         } else {
             None
         };
-        let _ = self.parse_one_of_keywords(&[Keyword::TRANSACTION, Keyword::WORK]);
+        let transaction = match self.parse_one_of_keywords(&[Keyword::TRANSACTION, Keyword::WORK]) {
+            Some(Keyword::TRANSACTION) => Some(BeginTransactionKind::Transaction),
+            Some(Keyword::WORK) => Some(BeginTransactionKind::Work),
+            _ => None,
+        };
         Ok(Statement::StartTransaction {
             modes: self.parse_transaction_modes()?,
             begin: true,
+            transaction,
             modifier,
         })
     }
@@ -10518,10 +11642,25 @@ impl<'a> Parser<'a> {
     }
 
     pub fn parse_rollback(&mut self) -> Result<Statement, ParserError> {
+        let transaction = match self.parse_one_of_keywords(&[Keyword::TRANSACTION, Keyword::WORK]) {
+            Some(Keyword::TRANSACTION) => Some(BeginTransactionKind::Transaction),
+            Some(Keyword::WORK) => Some(BeginTransactionKind::Work),
+            _ => None,
+        };
         let chain = self.parse_commit_rollback_chain()?;
-        let savepoint = self.parse_rollback_savepoint()?;
+        let (savepoint, savepoint_keyword) = if self.parse_keyword(Keyword::TO) {
+            let savepoint_keyword = self.parse_keyword(Keyword::SAVEPOINT);
+            (Some(self.parse_identifier(false)?), savepoint_keyword)
+        } else {
+            (None, false)
+        };
 
-        Ok(Statement::Rollback { chain, savepoint })
+        Ok(Statement::Rollback {
+            transaction,
+            chain,
+            savepoint,
+            savepoint_keyword,
+        })
     }
 
     pub fn parse_commit_rollback_chain(&mut self) -> Result<bool, ParserError> {
@@ -10548,7 +11687,11 @@ impl<'a> Parser<'a> {
 
     pub fn parse_deallocate(&mut self) -> Result<Statement, ParserError> {
         let prepare = self.parse_keyword(Keyword::PREPARE);
-        let name = self.parse_identifier(false)?;
+        let name = if self.parse_keyword(Keyword::ALL) {
+            None
+        } else {
+            Some(self.parse_identifier(false)?)
+        };
         Ok(Statement::Deallocate { name, prepare })
     }
 
@@ -10732,7 +11875,27 @@ impl<'a> Parser<'a> {
         })
     }
 
+    // pragma-value: signed-number | name | string-literal
     fn parse_pragma_value(&mut self) -> Result<Value, ParserError> {
+        if self.consume_token(&Token::Minus) {
+            let next_token = self.next_token();
+            return match next_token.token {
+                Token::Number(n, l) => match format!("-{n}").parse() {
+                    Ok(n) => Ok(Value::Number(n, l)),
+                    Err(e) => parser_err!(
+                        format!("Could not parse '-{n}' as number: {e}"),
+                        next_token.location
+                    ),
+                },
+                _ => self.expected("number", next_token),
+            };
+        }
+        if let Token::Word(w) = self.peek_token().token {
+            if w.quote_style.is_none() {
+                self.next_token();
+                return Ok(Value::UnQuotedString(w.value));
+            }
+        }
         match self.parse_value()? {
             v @ Value::SingleQuotedString(_) => Ok(v),
             v @ Value::DoubleQuotedString(_) => Ok(v),
@@ -10822,67 +11985,55 @@ impl<'a> Parser<'a> {
 
     fn parse_create_sequence_options(&mut self) -> Result<Vec<SequenceOptions>, ParserError> {
         let mut sequence_options = vec![];
-        //[ INCREMENT [ BY ] increment ]
-        if self.parse_keywords(&[Keyword::INCREMENT]) {
-            if self.parse_keywords(&[Keyword::BY]) {
-                sequence_options.push(SequenceOptions::IncrementBy(
-                    Expr::Value(self.parse_number_value()?),
-                    true,
-                ));
-            } else {
-                sequence_options.push(SequenceOptions::IncrementBy(
-                    Expr::Value(self.parse_number_value()?),
-                    false,
-                ));
-            }
-        }
-        //[ MINVALUE minvalue | NO MINVALUE ]
-        if self.parse_keyword(Keyword::MINVALUE) {
-            sequence_options.push(SequenceOptions::MinValue(Some(Expr::Value(
-                self.parse_number_value()?,
-            ))));
-        } else if self.parse_keywords(&[Keyword::NO, Keyword::MINVALUE]) {
-            sequence_options.push(SequenceOptions::MinValue(None));
-        }
-        //[ MAXVALUE maxvalue | NO MAXVALUE ]
-        if self.parse_keywords(&[Keyword::MAXVALUE]) {
-            sequence_options.push(SequenceOptions::MaxValue(Some(Expr::Value(
-                self.parse_number_value()?,
-            ))));
-        } else if self.parse_keywords(&[Keyword::NO, Keyword::MAXVALUE]) {
-            sequence_options.push(SequenceOptions::MaxValue(None));
-        }
-
-        //[ START [ WITH ] start ]
-        if self.parse_keywords(&[Keyword::START]) {
-            if self.parse_keywords(&[Keyword::WITH]) {
-                sequence_options.push(SequenceOptions::StartWith(
-                    Expr::Value(self.parse_number_value()?),
-                    true,
-                ));
+        // The options may appear in any order
+        loop {
+            let option = if self.parse_keyword(Keyword::INCREMENT) {
+                //[ INCREMENT [ BY ] increment ]
+                let by = self.parse_keyword(Keyword::BY);
+                SequenceOptions::IncrementBy(self.parse_sequence_option_value()?, by)
+            } else if self.parse_keyword(Keyword::MINVALUE) {
+                //[ MINVALUE minvalue | NO MINVALUE ]
+                SequenceOptions::MinValue(Some(self.parse_sequence_option_value()?))
+            } else if self.parse_keywords(&[Keyword::NO, Keyword::MINVALUE]) {
+                SequenceOptions::MinValue(None)
+            } else if self.parse_keyword(Keyword::MAXVALUE) {
+                //[ MAXVALUE maxvalue | NO MAXVALUE ]
+                SequenceOptions::MaxValue(Some(self.parse_sequence_option_value()?))
+            } else if self.parse_keywords(&[Keyword::NO, Keyword::MAXVALUE]) {
+                SequenceOptions::MaxValue(None)
+            } else if self.parse_keyword(Keyword::START) {
+                //[ START [ WITH ] start ]
+                let with = self.parse_keyword(Keyword::WITH);
+                SequenceOptions::StartWith(self.parse_sequence_option_value()?, with)
+            } else if self.parse_keyword(Keyword::CACHE) {
+                //[ CACHE cache ]
+                SequenceOptions::Cache(self.parse_sequence_option_value()?)
+            } else if self.parse_keywords(&[Keyword::NO, Keyword::CYCLE]) {
+                // [ [ NO ] CYCLE ]
+                SequenceOptions::Cycle(true)
+            } else if self.parse_keyword(Keyword::CYCLE) {
+                SequenceOptions::Cycle(false)
             } else {
-                sequence_options.push(SequenceOptions::StartWith(
-                    Expr::Value(self.parse_number_value()?),
-                    false,
-                ));
-            }
-        }
-        //[ CACHE cache ]
-        if self.parse_keywords(&[Keyword::CACHE]) {
-            sequence_options.push(SequenceOptions::Cache(Expr::Value(
-                self.parse_number_value()?,
-            )));
-        }
-        // [ [ NO ] CYCLE ]
-        if self.parse_keywords(&[Keyword::NO, Keyword::CYCLE]) {
-            sequence_options.push(SequenceOptions::Cycle(true));
-        } else if self.parse_keywords(&[Keyword::CYCLE]) {
-            sequence_options.push(SequenceOptions::Cycle(false));
+                break;
+            };
+            sequence_options.push(option);
         }
 
         Ok(sequence_options)
     }
 
+    /// Parse a possibly negative number used as a sequence option value
+    fn parse_sequence_option_value(&mut self) -> Result<Expr, ParserError> {
+        if self.consume_token(&Token::Minus) {
+            Ok(Expr::UnaryOp {
+                op: UnaryOperator::Minus,
+                expr: Box::new(Expr::Value(self.parse_number_value()?)),
+            })
+        } else {
+            Ok(Expr::Value(self.parse_number_value()?))
+        }
+    }
+
     /// The index of the first unprocessed token.
     pub fn index(&self) -> usize {
         self.index
@@ -10954,6 +12105,22 @@ impl<'a> Parser<'a> {
         let name = self.parse_object_name(false)?;
         self.expect_keyword(Keyword::AS)?;
 
+        if self.parse_keyword(Keyword::ENUM) {
+            self.expect_token(&Token::LParen)?;
+            let labels = self.parse_comma_separated0(|p| {
+                let next_token = p.next_token();
+                match next_token.token {
+                    Token::SingleQuotedString(label) => Ok(label),
+                    _ => p.expected("a string literal enum label", next_token),
+                }
+            })?;
+            self.expect_token(&Token::RParen)?;
+            return Ok(Statement::CreateType {
+                name,
+                representation: UserDefinedTypeRepresentation::Enum { labels },
+            });
+        }
+
         let mut attributes = vec![];
         if !self.consume_token(&Token::LParen) || self.consume_token(&Token::RParen) {
             return Ok(Statement::CreateType {
@@ -11580,7 +12747,7 @@ mod tests {
         assert_eq!(
             ast,
             Err(ParserError::ParserError(
-                "Expected [NOT] NULL or TRUE|FALSE or [NOT] DISTINCT FROM after IS, found: a at Line: 1, Column 16"
+                "Expected [NOT] NULL or TRUE|FALSE|UNKNOWN or [NOT] DISTINCT FROM after IS, found: a at Line: 1, Column 16"
                     .to_string()
             ))
         );
@@ -11713,12 +12880,11 @@ mod tests {
 
     #[test]
     fn test_replace_into_set() {
-        // NOTE: This is actually valid MySQL syntax, REPLACE and INSERT,
-        // but the parser does not yet support it.
         // https://dev.mysql.com/doc/refman/8.3/en/insert.html
         let sql = "REPLACE INTO t SET a='1'";
 
-        assert!(Parser::parse_sql(&MySqlDialect {}, sql).is_err());
+        let ast = Parser::parse_sql(&MySqlDialect {}, sql).unwrap();
+        assert_eq!(ast[0].to_string(), "REPLACE INTO t SET a = '1'");
     }
 
     #[test]
@@ -38,7 +38,8 @@ use sqlparser_derive::{Visit, VisitMut};
 use crate::ast::DollarQuotedString;
 use crate::dialect::Dialect;
 use crate::dialect::{
-    BigQueryDialect, DuckDbDialect, GenericDialect, PostgreSqlDialect, SnowflakeDialect,
+    BigQueryDialect, DuckDbDialect, GenericDialect, MySqlDialect, PostgreSqlDialect,
+    SnowflakeDialect,
 };
 use crate::keywords::{Keyword, ALL_KEYWORDS, ALL_KEYWORDS_INDEX};
 
@@ -94,8 +95,12 @@ pub enum Token {
     NationalStringLiteral(String),
     /// "escaped" string literal, which are an extension to the SQL standard: i.e: e'first \n second' or E 'first \n second'
     EscapedStringLiteral(String),
+    /// Unicode escape string literal, with the escapes left undecoded: i.e: U&'d\0061t\+000061'
+    UnicodeStringLiteral(String),
     /// Hexadecimal string literal: i.e.: X'deadbeef'
     HexStringLiteral(String),
+    /// MySQL bit-value literal: i.e.: 0b1010
+    BitValueLiteral(String),
     /// Comma
     Comma,
     /// Whitespace (space, tab, etc)
@@ -251,7 +256,9 @@ impl fmt::Display for Token {
             Token::DollarQuotedString(ref s) => write!(f, "{s}"),
             Token::NationalStringLiteral(ref s) => write!(f, "N'{s}'"),
             Token::EscapedStringLiteral(ref s) => write!(f, "E'{s}'"),
+            Token::UnicodeStringLiteral(ref s) => write!(f, "U&'{s}'"),
             Token::HexStringLiteral(ref s) => write!(f, "X'{s}'"),
+            Token::BitValueLiteral(ref s) => write!(f, "0b{s}"),
             Token::SingleQuotedByteStringLiteral(ref s) => write!(f, "B'{s}'"),
             Token::TripleSingleQuotedByteStringLiteral(ref s) => write!(f, "B'''{s}'''"),
             Token::DoubleQuotedByteStringLiteral(ref s) => write!(f, "B\"{s}\""),
@@ -794,6 +801,19 @@ impl<'a> Tokenizer<'a> {
                         }
                     }
                 }
+                // PostgreSQL accepts Unicode escape string constants: U&'d\0061t\+000061'
+                'u' | 'U'
+                    if dialect_of!(self is PostgreSqlDialect | GenericDialect) && {
+                        let mut lookahead = chars.peekable.clone();
+                        lookahead.next();
+                        lookahead.next() == Some('&') && lookahead.next() == Some('\'')
+                    } =>
+                {
+                    chars.next(); // consume the 'U'
+                    chars.next(); // consume the '&'
+                    let s = self.tokenize_single_quoted_string(chars, '\'', false)?;
+                    Ok(Some(Token::UnicodeStringLiteral(s)))
+                }
                 // The spec only allows an uppercase 'X' to introduce a hex
                 // string, but PostgreSQL, at least, allows a lowercase 'x' too.
                 x @ 'x' | x @ 'X' => {
@@ -876,13 +896,34 @@ impl<'a> Tokenizer<'a> {
                 }
                 // numbers and period
                 '0'..='9' | '.' => {
-                    let mut s = peeking_take_while(chars, |ch| ch.is_ascii_digit());
+                    let mut s = self.tokenize_digits(chars)?;
 
-                    // match binary literal that starts with 0x
+                    // match binary literal that starts with 0x, unless it is
+                    // just `0` followed by an identifier such as `x`
                     if s == "0" && chars.peek() == Some(&'x') {
-                        chars.next();
-                        let s2 = peeking_take_while(chars, |ch| ch.is_ascii_hexdigit());
-                        return Ok(Some(Token::HexStringLiteral(s2)));
+                        let mut lookahead = chars.peekable.clone();
+                        lookahead.next();
+                        if lookahead.peek().is_some_and(|ch| ch.is_ascii_hexdigit()) {
+                            chars.next();
+                            let s2 = peeking_take_while(chars, |ch| ch.is_ascii_hexdigit());
+                            return Ok(Some(Token::HexStringLiteral(s2)));
+                        }
+                    }
+
+                    // match MySQL bit-value literal that starts with 0b
+                    if s == "0" && chars.peek() == Some(&'b') && dialect_of!(self is MySqlDialect) {
+                        let bits: String = chars
+                            .peekable
+                            .clone()
+                            .skip(1)
+                            .take_while(|ch| self.dialect.is_identifier_part(*ch))
+                            .collect();
+                        if !bits.is_empty() && bits.chars().all(|ch| ch == '0' || ch == '1') {
+                            for _ in 0..=bits.len() {
+                                chars.next();
+                            }
+                            return Ok(Some(Token::BitValueLiteral(bits)));
+                        }
                     }
 
                     // match one period
@@ -890,7 +931,7 @@ impl<'a> Tokenizer<'a> {
                         s.push('.');
                         chars.next();
                     }
-                    s += &peeking_take_while(chars, |ch| ch.is_ascii_digit());
+                    s += &self.tokenize_digits(chars)?;
 
                     // No number -> Token::Period
                     if s == "." {
@@ -918,8 +959,7 @@ impl<'a> Tokenizer<'a> {
                                 for _ in 0..exponent_part.len() {
                                     chars.next();
                                 }
-                                exponent_part +=
-                                    &peeking_take_while(chars, |ch| ch.is_ascii_digit());
+                                exponent_part += &self.tokenize_digits(chars)?;
                                 s += exponent_part.as_str();
                             }
                             // Not an exponent, discard the work done
@@ -1115,8 +1155,8 @@ impl<'a> Tokenizer<'a> {
                 }
                 '{' => self.consume_and_return(chars, Token::LBrace),
                 '}' => self.consume_and_return(chars, Token::RBrace),
-                '#' if dialect_of!(self is SnowflakeDialect | BigQueryDialect) => {
-                    chars.next(); // consume the '#', starting a snowflake single-line comment
+                '#' if self.dialect.supports_hash_comment() => {
+                    chars.next(); // consume the '#', starting a single-line comment
                     let comment = self.tokenize_single_line_comment(chars);
                     Ok(Some(Token::Whitespace(Whitespace::SingleLineComment {
                         prefix: "#".to_owned(),
@@ -1346,6 +1386,35 @@ impl<'a> Tokenizer<'a> {
         }))
     }
 
+    /// Read a run of decimal digits. Dialects that support it may separate
+    /// digits with single underscores, which are kept in the returned text.
+    /// A trailing underscore is an error, unless it starts an identifier.
+    fn tokenize_digits(&self, chars: &mut State) -> Result<String, TokenizerError> {
+        let mut s = peeking_take_while(chars, |ch| ch.is_ascii_digit());
+        if !self.dialect.supports_numeric_literal_underscores() {
+            return Ok(s);
+        }
+        while !s.is_empty() && chars.peek() == Some(&'_') {
+            let mut lookahead = chars.peekable.clone();
+            lookahead.next();
+            match lookahead.peek() {
+                Some(ch) if ch.is_ascii_digit() => {}
+                // e.g. `1_a`: leave the underscore to start an identifier
+                Some(ch) if self.dialect.is_identifier_part(*ch) => break,
+                _ => {
+                    return self.tokenizer_error(
+                        chars.location(),
+                        "Expected a digit after '_' in numeric literal",
+                    )
+                }
+            }
+            chars.next();
+            s.push('_');
+            s += &peeking_take_while(chars, |ch| ch.is_ascii_digit());
+        }
+        Ok(s)
+    }
+
     fn tokenizer_error<R>(
         &self,
         loc: Location,
@@ -1801,7 +1870,7 @@ impl<'a: 'b, 'b> Unescape<'a, 'b> {
 mod tests {
     use super::*;
     use crate::dialect::{
-        BigQueryDialect, ClickHouseDialect, HiveDialect, MsSqlDialect, MySqlDialect,
+        AnsiDialect, BigQueryDialect, ClickHouseDialect, HiveDialect, MsSqlDialect, MySqlDialect,
     };
     use core::fmt::Debug;
 
@@ -1905,6 +1974,96 @@ mod tests {
         compare(expected, tokens);
     }
 
+    #[test]
+    fn tokenize_number_underscores() {
+        let sql = String::from("SELECT 1_000_000, 1_0.0_1e1_0");
+        let dialect = GenericDialect {};
+        let tokens = Tokenizer::new(&dialect, &sql).tokenize().unwrap();
+
+        let expected = vec![
+            Token::make_keyword("SELECT"),
+            Token::Whitespace(Whitespace::Space),
+            Token::Number(String::from("1_000_000"), false),
+            Token::Comma,
+            Token::Whitespace(Whitespace::Space),
+            Token::Number(String::from("1_0.0_1e1_0"), false),
+        ];
+
+        compare(expected, tokens);
+
+        let err = Tokenizer::new(&dialect, "SELECT 1_")
+            .tokenize()
+            .unwrap_err();
+        assert_eq!(
+            err,
+            TokenizerError {
+                message: "Expected a digit after '_' in numeric literal".to_string(),
+                location: Location { line: 1, column: 9 },
+            }
+        );
+
+        // An underscore followed by an identifier character starts a word
+        let tokens = Tokenizer::new(&dialect, "SELECT 1_a").tokenize().unwrap();
+        let expected = vec![
+            Token::make_keyword("SELECT"),
+            Token::Whitespace(Whitespace::Space),
+            Token::Number(String::from("1"), false),
+            Token::make_word("_a", None),
+        ];
+        compare(expected, tokens);
+
+        // Without separator support the underscore starts an identifier
+        let dialect = MsSqlDialect {};
+        let tokens = Tokenizer::new(&dialect, "SELECT 1_000").tokenize().unwrap();
+        let expected = vec![
+            Token::make_keyword("SELECT"),
+            Token::Whitespace(Whitespace::Space),
+            Token::Number(String::from("1"), false),
+            Token::make_word("_000", None),
+        ];
+        compare(expected, tokens);
+    }
+
+    #[test]
+    fn tokenize_hex_and_bit_literals() {
+        let sql = String::from("SELECT 0xFF, 0x, 0b1010, 0b111111111, 0b12");
+        let dialect = MySqlDialect {};
+        let tokens = Tokenizer::new(&dialect, &sql).tokenize().unwrap();
+
+        let expected = vec![
+            Token::make_keyword("SELECT"),
+            Token::Whitespace(Whitespace::Space),
+            Token::HexStringLiteral(String::from("FF")),
+            Token::Comma,
+            Token::Whitespace(Whitespace::Space),
+            Token::make_word("0x", None),
+            Token::Comma,
+            Token::Whitespace(Whitespace::Space),
+            Token::BitValueLiteral(String::from("1010")),
+            Token::Comma,
+            Token::Whitespace(Whitespace::Space),
+            Token::BitValueLiteral(String::from("111111111")),
+            Token::Comma,
+            Token::Whitespace(Whitespace::Space),
+            Token::make_word("0b12", None),
+        ];
+
+        compare(expected, tokens);
+
+        // Bit-value literals are MySQL specific
+        let dialect = GenericDialect {};
+        let tokens = Tokenizer::new(&dialect, "SELECT 0b1010")
+            .tokenize()
+            .unwrap();
+        let expected = vec![
+            Token::make_keyword("SELECT"),
+            Token::Whitespace(Whitespace::Space),
+            Token::Number(String::from("0"), false),
+            Token::make_word("b1010", None),
+        ];
+        compare(expected, tokens);
+    }
+
     #[test]
     fn tokenize_scalar_function() {
         let sql = String::from("SELECT sqrt(1)");
@@ -2335,6 +2494,25 @@ mod tests {
         compare(expected, tokens);
     }
 
+    #[test]
+    fn tokenize_hash_comment() {
+        let sql = String::from("SELECT 1 # comment\n");
+
+        let dialect = MySqlDialect {};
+        let tokens = Tokenizer::new(&dialect, &sql).tokenize().unwrap();
+        let expected = vec![
+            Token::make_keyword("SELECT"),
+            Token::Whitespace(Whitespace::Space),
+            Token::Number("1".to_string(), false),
+            Token::Whitespace(Whitespace::Space),
+            Token::Whitespace(Whitespace::SingleLineComment {
+                prefix: "#".to_string(),
+                comment: " comment\n".to_string(),
+            }),
+        ];
+        compare(expected, tokens);
+    }
+
     #[test]
     fn tokenize_multiline_comment() {
         let sql = String::from("0/*multi-line\n* /comment*/1");
@@ -2543,6 +2721,44 @@ mod tests {
         compare(expected, tokens);
     }
 
+    #[test]
+    fn tokenize_dialect_quoted_identifier() {
+        // The quote characters come from `Dialect::is_delimited_identifier_start`
+        let sql = "`a``b` [c]]d]";
+        let dialect = MySqlDialect {};
+        let tokens = Tokenizer::new(&dialect, sql).tokenize().unwrap();
+        let expected = vec![
+            Token::make_word("a`b", Some('`')),
+            Token::Whitespace(Whitespace::Space),
+            Token::LBracket,
+            Token::make_word("c", None),
+            Token::RBracket,
+            Token::RBracket,
+            Token::make_word("d", None),
+            Token::RBracket,
+        ];
+        compare(expected, tokens);
+
+        let sql = "[c]]d] \"e\"\"f\"";
+        let dialect = MsSqlDialect {};
+        let tokens = Tokenizer::new(&dialect, sql).tokenize().unwrap();
+        let expected = vec![
+            Token::make_word("c]d", Some('[')),
+            Token::Whitespace(Whitespace::Space),
+            Token::make_word("e\"f", Some('"')),
+        ];
+        compare(expected, tokens);
+
+        // ...so a backtick is not an identifier quote in MS SQL
+        let tokens = Tokenizer::new(&dialect, "`a`").tokenize().unwrap();
+        let expected = vec![
+            Token::Char('`'),
+            Token::make_word("a", None),
+            Token::Char('`'),
+        ];
+        compare(expected, tokens);
+    }
+
     #[test]
     fn tokenize_snowflake_div() {
         let sql = r#"field/1000"#;
@@ -2772,6 +2988,17 @@ mod tests {
         }
     }
 
+    #[test]
+    fn tokenize_backslash_escape_by_dialect() {
+        let sql = r#"'\n'"#;
+
+        let tokens = Tokenizer::new(&MySqlDialect {}, sql).tokenize().unwrap();
+        compare(vec![Token::SingleQuotedString("\n".to_string())], tokens);
+
+        let tokens = Tokenizer::new(&AnsiDialect {}, sql).tokenize().unwrap();
+        compare(vec![Token::SingleQuotedString(r#"\n"#.to_string())], tokens);
+    }
+
     #[test]
     fn tokenize_triple_quoted_string() {
         fn check<F>(
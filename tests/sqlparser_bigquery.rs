@@ -208,6 +208,16 @@ fn parse_raw_literal() {
     );
 }
 
+#[test]
+#[cfg(not(feature = "bigdecimal"))]
+fn parse_number_with_underscores() {
+    let select = bigquery().verified_only_select("SELECT 1_000_000");
+    assert_eq!(
+        &Expr::Value(number("1_000_000")),
+        expr_from_projection(&select.projection[0])
+    );
+}
+
 #[test]
 fn parse_delete_statement() {
     let sql = "DELETE \"table\" WHERE 1";
@@ -315,6 +325,8 @@ fn parse_create_view_if_not_exists() {
             with_no_schema_binding: late_binding,
             if_not_exists,
             temporary,
+            with_check_option,
+            with_data,
         } => {
             assert_eq!("mydataset.newview", name.to_string());
             assert_eq!(Vec::<ViewColumnDef>::new(), columns);
@@ -325,6 +337,8 @@ fn parse_create_view_if_not_exists() {
             assert_eq!(cluster_by, vec![]);
             assert!(comment.is_none());
             assert!(!late_binding);
+            assert!(with_check_option.is_none());
+            assert!(with_data.is_none());
             assert!(if_not_exists);
             assert!(!temporary);
         }
@@ -1834,6 +1848,52 @@ fn parse_cast_bytes_to_string_format() {
     bigquery_and_generic().verified_only_select(sql);
 }
 
+#[test]
+fn parse_safe_cast_format() {
+    let sql = r"SELECT SAFE_CAST(x AS DATE FORMAT 'YYYY-MM-DD')";
+    let select = bigquery_and_generic().verified_only_select(sql);
+    assert_eq!(
+        &Expr::Cast {
+            kind: CastKind::SafeCast,
+            expr: Box::new(Expr::Identifier(Ident::new("x"))),
+            data_type: DataType::Date,
+            format: Some(CastFormat::Value(Value::SingleQuotedString(
+                "YYYY-MM-DD".to_string()
+            ))),
+        },
+        expr_from_projection(only(&select.projection))
+    );
+
+    let sql = r"SELECT SAFE_CAST(ts AS STRING FORMAT 'TZH' AT TIME ZONE 'Asia/Kolkata')";
+    bigquery_and_generic().verified_only_select(sql);
+}
+
+#[test]
+fn parse_try_cast_format() {
+    // BigQuery itself spells this `SAFE_CAST`, but the same FORMAT clause is
+    // accepted after `TRY_CAST` in the dialects that have it
+    let dialects = TestedDialects {
+        dialects: vec![Box::new(GenericDialect {})],
+        options: None,
+    };
+    let sql = r"SELECT TRY_CAST(x AS STRING FORMAT 'YYYY-MM-DD')";
+    let select = dialects.verified_only_select(sql);
+    assert_eq!(
+        &Expr::Cast {
+            kind: CastKind::TryCast,
+            expr: Box::new(Expr::Identifier(Ident::new("x"))),
+            data_type: DataType::String(None),
+            format: Some(CastFormat::Value(Value::SingleQuotedString(
+                "YYYY-MM-DD".to_string()
+            ))),
+        },
+        expr_from_projection(only(&select.projection))
+    );
+
+    let sql = r"SELECT TRY_CAST(ts AS STRING FORMAT 'TZH' AT TIME ZONE 'Asia/Kolkata')";
+    dialects.verified_only_select(sql);
+}
+
 #[test]
 fn parse_array_agg_func() {
     for sql in [
@@ -2139,6 +2199,14 @@ fn test_select_as_struct() {
     bigquery().verified_only_select("SELECT * FROM (SELECT AS VALUE STRUCT(123 AS a, false AS b))");
     let select = bigquery().verified_only_select("SELECT AS STRUCT 1 AS a, 2 AS b");
     assert_eq!(Some(ValueTableMode::AsStruct), select.value_table_mode);
+
+    let select = bigquery().verified_only_select("SELECT DISTINCT AS STRUCT a, b FROM t");
+    assert_eq!(Some(Distinct::Distinct), select.distinct);
+    assert_eq!(Some(ValueTableMode::AsStruct), select.value_table_mode);
+    bigquery().one_statement_parses_to(
+        "SELECT AS STRUCT DISTINCT a, b FROM t",
+        "SELECT DISTINCT AS STRUCT a, b FROM t",
+    );
 }
 
 #[test]
@@ -2148,6 +2216,11 @@ fn test_select_as_value() {
     );
     let select = bigquery().verified_only_select("SELECT AS VALUE STRUCT(1 AS a, 2 AS b) AS xyz");
     assert_eq!(Some(ValueTableMode::AsValue), select.value_table_mode);
+
+    let select = bigquery().verified_only_select("SELECT DISTINCT AS VALUE a FROM t");
+    assert_eq!(Some(Distinct::Distinct), select.distinct);
+    assert_eq!(Some(ValueTableMode::AsValue), select.value_table_mode);
+    bigquery().one_statement_parses_to("SELECT ALL AS VALUE a FROM t", "SELECT AS VALUE a FROM t");
 }
 
 #[test]
@@ -2170,3 +2243,24 @@ fn test_any_value() {
     bigquery_and_generic().verified_expr("ANY_VALUE(fruit HAVING MAX sold)");
     bigquery_and_generic().verified_expr("ANY_VALUE(fruit HAVING MIN sold)");
 }
+
+#[test]
+fn test_composite_access() {
+    let expr = bigquery_and_generic().verified_expr("UNNEST(x).y.z");
+    assert_eq!(
+        Expr::CompositeAccess {
+            expr: Box::new(Expr::CompositeAccess {
+                expr: Box::new(call("UNNEST", [Expr::Identifier(Ident::new("x"))])),
+                key: Ident::new("y"),
+            }),
+            key: Ident::new("z"),
+        },
+        expr
+    );
+
+    bigquery_and_generic().verified_expr("f().field");
+    bigquery_and_generic().verified_expr("(a).b.c");
+    bigquery_and_generic().verified_expr("-f(x).a + 1");
+    bigquery().verified_only_select("SELECT (SELECT AS STRUCT 1 AS a, 2 AS b).b");
+    bigquery().verified_only_select("SELECT arr[OFFSET(0)].f FROM t");
+}
@@ -94,7 +94,6 @@ fn parse_map_access_expr() {
             sort_by: vec![],
             having: None,
             named_window: vec![],
-            window_before_qualify: false,
             qualify: None,
             value_table_mode: None,
             connect_by: None,
@@ -189,6 +188,7 @@ fn parse_delimited_identifiers() {
                 clauses: vec![],
             }),
             null_treatment: None,
+            nth_value_order: None,
             filter: None,
             over: None,
             within_group: vec![],
@@ -561,6 +561,13 @@ fn parse_select_star_except_no_parens() {
     );
 }
 
+#[test]
+fn parse_group_by_all() {
+    let select =
+        clickhouse_and_generic().verified_only_select("SELECT a, COUNT(*) FROM t GROUP BY ALL");
+    assert_eq!(GroupByExpr::All, select.group_by);
+}
+
 fn clickhouse() -> TestedDialects {
     TestedDialects {
         dialects: vec![Box::new(ClickHouseDialect {})],
@@ -401,7 +401,6 @@ fn parse_update_set_from() {
                             having: None,
                             named_window: vec![],
                             qualify: None,
-                            window_before_qualify: false,
                             value_table_mode: None,
                             connect_by: None,
                         }))),
@@ -432,6 +431,7 @@ fn parse_update_set_from() {
                 ])),
             }),
             returning: None,
+            returning_into: None,
         }
     );
 }
@@ -446,6 +446,7 @@ fn parse_update_with_table_alias() {
             from: _from,
             selection,
             returning,
+            returning_into,
         } => {
             assert_eq!(
                 TableWithJoins {
@@ -485,6 +486,7 @@ fn parse_update_with_table_alias() {
                 selection
             );
             assert_eq!(None, returning);
+            assert_eq!(None, returning_into);
         }
         _ => unreachable!(),
     }
@@ -582,7 +584,7 @@ fn parse_delete_without_from_error() {
 #[test]
 fn parse_delete_statement_for_multi_tables() {
     let sql = "DELETE schema1.table1, schema2.table2 FROM schema1.table1 JOIN schema2.table2 ON schema2.table2.col1 = schema1.table1.col1 WHERE schema2.table2.col2 = 1";
-    let dialects = all_dialects_except(|d| d.is::<BigQueryDialect>() || d.is::<GenericDialect>());
+    let dialects = all_dialects_except(|d| d.is::<BigQueryDialect>());
     match dialects.verified_stmt(sql) {
         Statement::Delete(Delete {
             tables,
@@ -1048,6 +1050,7 @@ fn parse_select_count_wildcard() {
                 clauses: vec![],
             }),
             null_treatment: None,
+            nth_value_order: None,
             filter: None,
             over: None,
             within_group: vec![]
@@ -1072,6 +1075,7 @@ fn parse_select_count_distinct() {
                 clauses: vec![],
             }),
             null_treatment: None,
+            nth_value_order: None,
             within_group: vec![],
             filter: None,
             over: None
@@ -1124,6 +1128,33 @@ fn parse_collate_after_parens() {
     );
 }
 
+#[test]
+fn parse_collate_precedence() {
+    // COLLATE applies to the operand it follows, not to the whole comparison
+    assert_eq!(
+        all_dialects().verified_expr("a = b COLLATE \"C\""),
+        Expr::BinaryOp {
+            left: Box::new(Expr::Identifier(Ident::new("a"))),
+            op: BinaryOperator::Eq,
+            right: Box::new(Expr::Collate {
+                expr: Box::new(Expr::Identifier(Ident::new("b"))),
+                collation: ObjectName(vec![Ident::with_quote('"', "C")]),
+            }),
+        }
+    );
+
+    assert_eq!(
+        all_dialects().verified_expr("a COLLATE \"C\" COLLATE \"POSIX\""),
+        Expr::Collate {
+            expr: Box::new(Expr::Collate {
+                expr: Box::new(Expr::Identifier(Ident::new("a"))),
+                collation: ObjectName(vec![Ident::with_quote('"', "C")]),
+            }),
+            collation: ObjectName(vec![Ident::with_quote('"', "POSIX")]),
+        }
+    );
+}
+
 #[test]
 fn parse_select_string_predicate() {
     let sql = "SELECT id, fname, lname FROM customer \
@@ -1537,6 +1568,64 @@ fn parse_not_precedence() {
             }),
         },
     );
+
+    // A prefix NOT negates the whole IN predicate, which is the same
+    // predicate as the infix `NOT IN` form
+    let in_list = |negated| Expr::InList {
+        expr: Box::new(Expr::Identifier("a".into())),
+        list: vec![Expr::Value(number("1")), Expr::Value(number("2"))],
+        negated,
+    };
+    assert_eq!(
+        verified_expr("NOT a IN (1, 2)"),
+        Expr::UnaryOp {
+            op: UnaryOperator::Not,
+            expr: Box::new(in_list(false)),
+        },
+    );
+    assert_eq!(verified_expr("a NOT IN (1, 2)"), in_list(true));
+
+    // The AND of BETWEEN binds before a following AND, and NOT applies to
+    // the BETWEEN only: (NOT (a BETWEEN 1 AND 2)) AND c
+    let between = |negated| Expr::Between {
+        expr: Box::new(Expr::Identifier("a".into())),
+        low: Box::new(Expr::Value(number("1"))),
+        high: Box::new(Expr::Value(number("2"))),
+        negated,
+    };
+    assert_eq!(
+        verified_expr("NOT a BETWEEN 1 AND 2 AND c"),
+        Expr::BinaryOp {
+            left: Box::new(Expr::UnaryOp {
+                op: UnaryOperator::Not,
+                expr: Box::new(between(false)),
+            }),
+            op: BinaryOperator::And,
+            right: Box::new(Expr::Identifier("c".into())),
+        },
+    );
+    assert_eq!(
+        verified_expr("a NOT BETWEEN 1 AND 2 AND c"),
+        Expr::BinaryOp {
+            left: Box::new(between(true)),
+            op: BinaryOperator::And,
+            right: Box::new(Expr::Identifier("c".into())),
+        },
+    );
+
+    // Likewise for LIKE
+    assert_eq!(
+        verified_expr("NOT a LIKE 'b'"),
+        Expr::UnaryOp {
+            op: UnaryOperator::Not,
+            expr: Box::new(Expr::Like {
+                expr: Box::new(Expr::Identifier("a".into())),
+                negated: false,
+                pattern: Box::new(Expr::Value(Value::SingleQuotedString("b".into()))),
+                escape_char: None,
+            }),
+        },
+    );
 }
 
 #[test]
@@ -1816,7 +1905,7 @@ fn parse_in_error() {
 fn parse_string_agg() {
     let sql = "SELECT a || b";
 
-    let select = verified_only_select(sql);
+    let select = all_dialects_where(|d| !d.supports_pipes_as_or()).verified_only_select(sql);
     assert_eq!(
         SelectItem::UnnamedExpr(Expr::BinaryOp {
             left: Box::new(Expr::Identifier(Ident::new("a"))),
@@ -1858,6 +1947,92 @@ fn parse_bitwise_ops() {
             select.projection[0]
         );
     }
+
+    // `&` binds tighter than `|`, on either side
+    let ident = |name: &str| Box::new(Expr::Identifier(Ident::new(name)));
+    let dialects = all_dialects_except(|d| d.is::<PostgreSqlDialect>());
+    assert_eq!(
+        dialects.verified_expr("a | b & c"),
+        Expr::BinaryOp {
+            left: ident("a"),
+            op: BinaryOperator::BitwiseOr,
+            right: Box::new(Expr::BinaryOp {
+                left: ident("b"),
+                op: BinaryOperator::BitwiseAnd,
+                right: ident("c"),
+            }),
+        }
+    );
+    assert_eq!(
+        dialects.verified_expr("a & b | c"),
+        Expr::BinaryOp {
+            left: Box::new(Expr::BinaryOp {
+                left: ident("a"),
+                op: BinaryOperator::BitwiseAnd,
+                right: ident("b"),
+            }),
+            op: BinaryOperator::BitwiseOr,
+            right: ident("c"),
+        }
+    );
+    // PostgreSQL's bitwise operators share one precedence level and associate to the left
+    assert_eq!(
+        all_dialects_where(|d| d.is::<PostgreSqlDialect>()).verified_expr("a | b # c & d << e"),
+        Expr::BinaryOp {
+            left: Box::new(Expr::BinaryOp {
+                left: Box::new(Expr::BinaryOp {
+                    left: Box::new(Expr::BinaryOp {
+                        left: ident("a"),
+                        op: BinaryOperator::BitwiseOr,
+                        right: ident("b"),
+                    }),
+                    op: BinaryOperator::PGBitwiseXor,
+                    right: ident("c"),
+                }),
+                op: BinaryOperator::BitwiseAnd,
+                right: ident("d"),
+            }),
+            op: BinaryOperator::PGBitwiseShiftLeft,
+            right: ident("e"),
+        }
+    );
+}
+
+#[test]
+fn parse_bitwise_shift_ops() {
+    let dialects = all_dialects_where(|d| {
+        d.is::<PostgreSqlDialect>()
+            || d.is::<DuckDbDialect>()
+            || d.is::<MySqlDialect>()
+            || d.is::<BigQueryDialect>()
+            || d.is::<GenericDialect>()
+    });
+
+    // Shifts bind looser than arithmetic but tighter than comparisons
+    assert_eq!(
+        dialects.verified_expr("x << 2 + 1 < y >> 1"),
+        Expr::BinaryOp {
+            left: Box::new(Expr::BinaryOp {
+                left: Box::new(Expr::Identifier(Ident::new("x"))),
+                op: BinaryOperator::PGBitwiseShiftLeft,
+                right: Box::new(Expr::BinaryOp {
+                    left: Box::new(Expr::Value(number("2"))),
+                    op: BinaryOperator::Plus,
+                    right: Box::new(Expr::Value(number("1"))),
+                }),
+            }),
+            op: BinaryOperator::Lt,
+            right: Box::new(Expr::BinaryOp {
+                left: Box::new(Expr::Identifier(Ident::new("y"))),
+                op: BinaryOperator::PGBitwiseShiftRight,
+                right: Box::new(Expr::Value(number("1"))),
+            }),
+        }
+    );
+
+    // Comparisons next to nested type parameters are unaffected
+    dialects.verified_expr("a < b AND c > d");
+    dialects.verified_expr("CAST(a AS ARRAY<ARRAY<INT>>) > b");
 }
 
 #[test]
@@ -1994,6 +2169,42 @@ fn parse_between_with_expr() {
     )
 }
 
+#[test]
+fn parse_overlaps() {
+    let ident = |s: &str| Expr::Identifier(Ident::new(s));
+    let sql = "SELECT * FROM t WHERE (s1, e1) OVERLAPS (s2, e2) AND x = 1";
+    let select = verified_only_select(sql);
+    assert_eq!(
+        Expr::BinaryOp {
+            left: Box::new(Expr::Overlaps {
+                left: Box::new(Expr::Tuple(vec![ident("s1"), ident("e1")])),
+                right: Box::new(Expr::Tuple(vec![ident("s2"), ident("e2")])),
+            }),
+            op: BinaryOperator::And,
+            right: Box::new(Expr::BinaryOp {
+                left: Box::new(ident("x")),
+                op: BinaryOperator::Eq,
+                right: Box::new(Expr::Value(number("1"))),
+            }),
+        },
+        select.selection.unwrap()
+    );
+
+    verified_only_select(
+        "SELECT (DATE '2001-02-16', INTERVAL '100 days') OVERLAPS (DATE '2001-10-30', DATE '2002-10-30')",
+    );
+
+    // Both operands must be (start, end) pairs
+    assert_eq!(
+        ParserError::ParserError("OVERLAPS requires (start, end) periods".to_string()),
+        parse_sql_statements("SELECT (a) OVERLAPS (c, d)").unwrap_err()
+    );
+    assert!(parse_sql_statements("SELECT (a, b) OVERLAPS (c, d, e)").is_err());
+
+    // OVERLAPS is still usable as an identifier
+    verified_only_select("SELECT 1 AS overlaps");
+}
+
 #[test]
 fn parse_tuples() {
     let sql = "SELECT (1, 2), (1), ('foo', 3, baz)";
@@ -2063,6 +2274,34 @@ fn parse_select_order_by() {
     chk("SELECT 1 AS lname, 2 AS fname, 3 AS id, 4 ORDER BY lname ASC, fname DESC, id");
 }
 
+#[test]
+fn parse_select_order_by_ordinal() {
+    let query = verified_query("SELECT a, b, c FROM t ORDER BY 1 DESC, 2, 3 ASC");
+    assert_eq!(
+        vec![
+            OrderByExpr {
+                expr: Expr::Value(number("1")),
+                asc: Some(false),
+                nulls_first: None,
+            },
+            OrderByExpr {
+                expr: Expr::Value(number("2")),
+                asc: None,
+                nulls_first: None,
+            },
+            OrderByExpr {
+                expr: Expr::Value(number("3")),
+                asc: Some(true),
+                nulls_first: None,
+            },
+        ],
+        query.order_by
+    );
+
+    // ordinals are kept as written rather than resolved or folded
+    verified_query("SELECT a, b FROM t ORDER BY 2 DESC, 1 + 0");
+}
+
 #[test]
 fn parse_select_order_by_limit() {
     let sql = "SELECT id, fname, lname FROM customer WHERE id < 5 \
@@ -2131,15 +2370,31 @@ fn parse_select_group_by() {
 #[test]
 fn parse_select_group_by_all() {
     let sql = "SELECT id, fname, lname, SUM(order) FROM customer GROUP BY ALL";
-    let select = verified_only_select(sql);
+    let select = all_dialects_where(|d| d.supports_group_by_all()).verified_only_select(sql);
     assert_eq!(GroupByExpr::All, select.group_by);
 
-    one_statement_parses_to(
-        "SELECT id, fname, lname, SUM(order) FROM customer GROUP BY ALL",
-        "SELECT id, fname, lname, SUM(order) FROM customer GROUP BY ALL",
+    assert_eq!(
+        ParserError::ParserError("Expected an expression after GROUP BY, found: ALL".to_string()),
+        all_dialects_where(|d| !d.supports_group_by_all())
+            .parse_sql_statements(sql)
+            .unwrap_err()
     );
 }
 
+#[test]
+fn parse_select_group_by_position() {
+    let sql = "SELECT name, COUNT(*) FROM customer GROUP BY 1, name ORDER BY 2 DESC, 1";
+    let select = verified_only_select(sql);
+    assert_eq!(
+        GroupByExpr::Expressions(vec![
+            Expr::Value(number("1")),
+            Expr::Identifier(Ident::new("name")),
+        ]),
+        select.group_by
+    );
+    assert_eq!("GROUP BY 1, name", select.group_by.to_string());
+}
+
 #[test]
 fn parse_select_having() {
     let sql = "SELECT foo FROM bar GROUP BY foo HAVING COUNT(*) > 1";
@@ -2154,6 +2409,7 @@ fn parse_select_having() {
                     clauses: vec![],
                 }),
                 null_treatment: None,
+                nth_value_order: None,
                 filter: None,
                 over: None,
                 within_group: vec![]
@@ -2183,6 +2439,7 @@ fn parse_select_qualify() {
                     clauses: vec![],
                 }),
                 null_treatment: None,
+                nth_value_order: None,
                 filter: None,
                 over: Some(WindowType::WindowSpec(WindowSpec {
                     window_name: None,
@@ -2364,10 +2621,50 @@ fn parse_cast() {
     );
 }
 
+#[test]
+fn parse_cast_multiset_subquery() {
+    let dialects = all_dialects_where(|d| d.is::<GenericDialect>() || d.is::<AnsiDialect>());
+    let select =
+        dialects.verified_only_select("SELECT CAST(MULTISET(SELECT a FROM t) AS INT MULTISET)");
+    assert_eq!(
+        &Expr::Cast {
+            kind: CastKind::Cast,
+            expr: Box::new(Expr::Function(Function {
+                name: ObjectName(vec![Ident::new("MULTISET")]),
+                args: FunctionArguments::Subquery(Box::new(
+                    dialects.verified_query("SELECT a FROM t")
+                )),
+                filter: None,
+                null_treatment: None,
+                nth_value_order: None,
+                over: None,
+                within_group: vec![],
+            })),
+            data_type: DataType::Multiset(Box::new(DataType::Int(None))),
+            format: None,
+        },
+        expr_from_projection(only(&select.projection))
+    );
+
+    // Only a subquery makes `MULTISET(...)` a constructor
+    dialects.verified_expr("MULTISET(SELECT 1)");
+    dialects.verified_expr("multiset(1)");
+
+    // Dialects without the MULTISET type do not have the constructor either
+    let dialects = all_dialects_where(|d| d.is::<PostgreSqlDialect>());
+    assert!(dialects
+        .parse_sql_statements("SELECT CAST(a AS INT MULTISET)")
+        .is_err());
+    assert!(dialects
+        .parse_sql_statements("SELECT MULTISET(SELECT 1)")
+        .is_err());
+}
+
 #[test]
 fn parse_try_cast() {
+    let dialects = all_dialects_where(|d| d.supports_try_cast());
     let sql = "SELECT TRY_CAST(id AS BIGINT) FROM customer";
-    let select = verified_only_select(sql);
+    let select = dialects.verified_only_select(sql);
     assert_eq!(
         &Expr::Cast {
             kind: CastKind::TryCast,
@@ -2377,13 +2674,44 @@ fn parse_try_cast() {
         },
         expr_from_projection(only(&select.projection))
     );
-    verified_stmt("SELECT TRY_CAST(id AS BIGINT) FROM customer");
+    dialects.verified_stmt("SELECT TRY_CAST(id AS BIGINT) FROM customer");
 
-    verified_stmt("SELECT TRY_CAST(id AS NUMERIC) FROM customer");
+    dialects.verified_stmt("SELECT TRY_CAST(id AS NUMERIC) FROM customer");
 
-    verified_stmt("SELECT TRY_CAST(id AS DEC) FROM customer");
+    dialects.verified_stmt("SELECT TRY_CAST(id AS DEC) FROM customer");
 
-    verified_stmt("SELECT TRY_CAST(id AS DECIMAL) FROM customer");
+    dialects.verified_stmt("SELECT TRY_CAST(id AS DECIMAL) FROM customer");
+
+    dialects.verified_stmt("SELECT TRY_CAST(ts AS VARCHAR FORMAT 'YYYY-MM-DD') FROM customer");
+
+    // elsewhere `TRY_CAST` is an ordinary function name
+    let res = all_dialects_where(|d| !d.supports_try_cast())
+        .parse_sql_statements("SELECT TRY_CAST(id AS BIGINT) FROM customer");
+    assert_eq!(
+        ParserError::ParserError("Expected ), found: AS".to_string()),
+        res.unwrap_err()
+    );
+}
+
+#[test]
+fn parse_safe_cast() {
+    let sql = "SELECT SAFE_CAST(id AS BIGINT) FROM customer";
+    let select = all_dialects_where(|d| d.supports_safe_cast()).verified_only_select(sql);
+    assert_eq!(
+        &Expr::Cast {
+            kind: CastKind::SafeCast,
+            expr: Box::new(Expr::Identifier(Ident::new("id"))),
+            data_type: DataType::BigInt(None),
+            format: None,
+        },
+        expr_from_projection(only(&select.projection))
+    );
+
+    let res = all_dialects_where(|d| !d.supports_safe_cast()).parse_sql_statements(sql);
+    assert_eq!(
+        ParserError::ParserError("Expected ), found: AS".to_string()),
+        res.unwrap_err()
+    );
 }
 
 #[test]
@@ -2428,6 +2756,7 @@ fn parse_extract() {
     verified_stmt("SELECT EXTRACT(MILLENNIUM FROM d)");
     verified_stmt("SELECT EXTRACT(MILLISECOND FROM d)");
     verified_stmt("SELECT EXTRACT(MILLISECONDS FROM d)");
+    verified_stmt("SELECT EXTRACT(NANOSECONDS FROM d)");
     verified_stmt("SELECT EXTRACT(QUARTER FROM d)");
     verified_stmt("SELECT EXTRACT(TIMEZONE FROM d)");
     verified_stmt("SELECT EXTRACT(TIMEZONE_ABBR FROM d)");
@@ -2444,6 +2773,24 @@ fn parse_extract() {
     );
 }
 
+#[test]
+fn parse_extract_at_time_zone() {
+    let sql = "SELECT EXTRACT(HOUR FROM ts AT TIME ZONE 'UTC')";
+    let select = verified_only_select(sql);
+    assert_eq!(
+        &Expr::Extract {
+            field: DateTimeField::Hour,
+            expr: Box::new(Expr::AtTimeZone {
+                timestamp: Box::new(Expr::Identifier(Ident::new("ts"))),
+                time_zone: Box::new(Expr::Value(Value::SingleQuotedString("UTC".to_string()))),
+            }),
+        },
+        expr_from_projection(only(&select.projection)),
+    );
+
+    verified_stmt("SELECT EXTRACT(HOUR FROM CAST(ts AS TIMESTAMP) AT TIME ZONE 'UTC')");
+}
+
 #[test]
 fn parse_ceil_number() {
     verified_stmt("SELECT CEIL(1.5)");
@@ -2541,6 +2888,7 @@ fn parse_listagg() {
             }),
             filter: None,
             null_treatment: None,
+            nth_value_order: None,
             over: None,
             within_group: vec![
                 OrderByExpr {
@@ -2572,6 +2920,50 @@ fn parse_listagg() {
     verified_stmt("SELECT LISTAGG(dateid ON OVERFLOW TRUNCATE X'deadbeef' WITH COUNT)");
 }
 
+#[test]
+fn parse_listagg_on_overflow() {
+    let overflow_clauses =
+        |sql: &str| match expr_from_projection(only(&verified_only_select(sql).projection)) {
+            Expr::Function(Function {
+                args: FunctionArguments::List(FunctionArgumentList { clauses, .. }),
+                ..
+            }) => clauses.clone(),
+            _ => unreachable!(),
+        };
+
+    assert_eq!(
+        vec![FunctionArgumentClause::OnOverflow(ListAggOnOverflow::Error)],
+        overflow_clauses("SELECT LISTAGG(x, ',' ON OVERFLOW ERROR)")
+    );
+    assert_eq!(
+        vec![FunctionArgumentClause::OnOverflow(
+            ListAggOnOverflow::Truncate {
+                filler: Some(Box::new(Expr::Value(Value::SingleQuotedString(
+                    "...".to_string()
+                )))),
+                with_count: true,
+            }
+        )],
+        overflow_clauses("SELECT LISTAGG(x, ',' ON OVERFLOW TRUNCATE '...' WITH COUNT)")
+    );
+    assert_eq!(
+        vec![FunctionArgumentClause::OnOverflow(
+            ListAggOnOverflow::Truncate {
+                filler: None,
+                with_count: false,
+            }
+        )],
+        overflow_clauses("SELECT LISTAGG(x, ',' ON OVERFLOW TRUNCATE WITHOUT COUNT)")
+    );
+
+    assert_eq!(
+        ParserError::ParserError(
+            "Expected either WITH or WITHOUT in LISTAGG, found: )".to_string()
+        ),
+        parse_sql_statements("SELECT LISTAGG(x, ',' ON OVERFLOW TRUNCATE '...')").unwrap_err()
+    );
+}
+
 #[test]
 fn parse_array_agg_func() {
     let supported_dialects = TestedDialects {
@@ -2659,6 +3051,73 @@ fn parse_window_rank_function() {
     }
 }
 
+#[test]
+fn parse_window_function_null_treatment() {
+    for sql in [
+        "SELECT NTH_VALUE(x, 2) IGNORE NULLS OVER (ORDER BY y) FROM t1",
+        "SELECT LEAD(x, 1) RESPECT NULLS OVER (ORDER BY y) FROM t1",
+        "SELECT LAG(x) IGNORE NULLS OVER w FROM t1",
+    ] {
+        all_dialects().verified_stmt(sql);
+    }
+
+    let select = verified_only_select("SELECT LEAD(x) IGNORE NULLS OVER (ORDER BY y) FROM t1");
+    match expr_from_projection(only(&select.projection)) {
+        Expr::Function(Function {
+            name,
+            null_treatment,
+            over,
+            ..
+        }) => {
+            assert_eq!(&ObjectName(vec![Ident::new("LEAD")]), name);
+            assert_eq!(&Some(NullTreatment::IgnoreNulls), null_treatment);
+            assert!(over.is_some());
+        }
+        _ => unreachable!(),
+    }
+}
+
+#[test]
+fn parse_nth_value_from_first_last() {
+    let sql = "SELECT NTH_VALUE(x, 2) FROM LAST IGNORE NULLS OVER (ORDER BY y) FROM t1";
+    let select = verified_only_select(sql);
+    match expr_from_projection(only(&select.projection)) {
+        Expr::Function(Function {
+            nth_value_order,
+            null_treatment,
+            ..
+        }) => {
+            assert_eq!(&Some(NthValueOrder::FromLast), nth_value_order);
+            assert_eq!(&Some(NullTreatment::IgnoreNulls), null_treatment);
+        }
+        _ => unreachable!(),
+    }
+
+    match verified_expr("NTH_VALUE(x, 2) FROM FIRST OVER w") {
+        Expr::Function(Function {
+            name,
+            nth_value_order,
+            null_treatment,
+            ..
+        }) => {
+            assert_eq!(ObjectName(vec![Ident::new("NTH_VALUE")]), name);
+            assert_eq!(Some(NthValueOrder::FromFirst), nth_value_order);
+            assert_eq!(None, null_treatment);
+        }
+        _ => unreachable!(),
+    }
+    verified_expr("NTH_VALUE(x, 2) FROM LAST RESPECT NULLS OVER (PARTITION BY y)");
+    verified_expr("s.nth_value(x, 2) FROM LAST OVER w");
+
+    // a quoted name is not the NTH_VALUE function
+    let sql = r#"SELECT "NTH_VALUE"(x, 2) FROM LAST OVER w"#;
+    assert!(Parser::parse_sql(&GenericDialect {}, sql).is_err());
+
+    // without a following window, `FROM` starts the FROM clause
+    let select = verified_only_select("SELECT NTH_VALUE(x, 2) FROM last");
+    assert_eq!(table("last"), only(&select.from).relation);
+}
+
 #[test]
 fn parse_window_function_null_treatment_arg() {
     let dialects = all_dialects_where(|d| d.supports_window_function_null_treatment_arg());
@@ -2743,7 +3202,7 @@ fn parse_create_table() {
          ref2 INT REFERENCES othertable2 ON DELETE CASCADE ON UPDATE NO ACTION, \
          CONSTRAINT fkey FOREIGN KEY (lat) REFERENCES othertable3(lat) ON DELETE RESTRICT, \
          CONSTRAINT fkey2 FOREIGN KEY (lat) REFERENCES othertable4(lat) ON DELETE NO ACTION ON UPDATE RESTRICT, \
-         FOREIGN KEY (lat) REFERENCES othertable4(lat) ON DELETE CASCADE ON UPDATE SET DEFAULT, \
+         FOREIGN KEY (lat) REFERENCES othertable4(lat) ON UPDATE SET DEFAULT ON DELETE CASCADE, \
          FOREIGN KEY (lng) REFERENCES othertable4(longitude) ON UPDATE SET NULL)",
     );
     match ast {
@@ -2818,7 +3277,10 @@ fn parse_create_table() {
                             },
                             ColumnOptionDef {
                                 name: None,
-                                option: ColumnOption::Check(verified_expr("constrained > 0")),
+                                option: ColumnOption::Check {
+                                    expr: verified_expr("constrained > 0"),
+                                    no_inherit: false,
+                                },
                             },
                         ],
                     },
@@ -2831,8 +3293,10 @@ fn parse_create_table() {
                             option: ColumnOption::ForeignKey {
                                 foreign_table: ObjectName(vec!["othertable".into()]),
                                 referred_columns: vec!["a".into(), "b".into()],
+                                match_kind: None,
                                 on_delete: None,
                                 on_update: None,
+                                on_update_first: false,
                                 characteristics: None,
                             },
                         }],
@@ -2846,8 +3310,10 @@ fn parse_create_table() {
                             option: ColumnOption::ForeignKey {
                                 foreign_table: ObjectName(vec!["othertable2".into()]),
                                 referred_columns: vec![],
+                                match_kind: None,
                                 on_delete: Some(ReferentialAction::Cascade),
                                 on_update: Some(ReferentialAction::NoAction),
+                                on_update_first: false,
                                 characteristics: None,
                             },
                         },],
@@ -2862,8 +3328,10 @@ fn parse_create_table() {
                         columns: vec!["lat".into()],
                         foreign_table: ObjectName(vec!["othertable3".into()]),
                         referred_columns: vec!["lat".into()],
+                        match_kind: None,
                         on_delete: Some(ReferentialAction::Restrict),
                         on_update: None,
+                        on_update_first: false,
                         characteristics: None,
                     },
                     TableConstraint::ForeignKey {
@@ -2871,8 +3339,10 @@ fn parse_create_table() {
                         columns: vec!["lat".into()],
                         foreign_table: ObjectName(vec!["othertable4".into()]),
                         referred_columns: vec!["lat".into()],
+                        match_kind: None,
                         on_delete: Some(ReferentialAction::NoAction),
                         on_update: Some(ReferentialAction::Restrict),
+                        on_update_first: false,
                         characteristics: None,
                     },
                     TableConstraint::ForeignKey {
@@ -2880,8 +3350,10 @@ fn parse_create_table() {
                         columns: vec!["lat".into()],
                         foreign_table: ObjectName(vec!["othertable4".into()]),
                         referred_columns: vec!["lat".into()],
+                        match_kind: None,
                         on_delete: Some(ReferentialAction::Cascade),
                         on_update: Some(ReferentialAction::SetDefault),
+                        on_update_first: true,
                         characteristics: None,
                     },
                     TableConstraint::ForeignKey {
@@ -2889,8 +3361,10 @@ fn parse_create_table() {
                         columns: vec!["lng".into()],
                         foreign_table: ObjectName(vec!["othertable4".into()]),
                         referred_columns: vec!["longitude".into()],
+                        match_kind: None,
                         on_delete: None,
                         on_update: Some(ReferentialAction::SetNull),
+                        on_update_first: false,
                         characteristics: None,
                     },
                 ]
@@ -2932,7 +3406,7 @@ fn parse_create_table_with_constraint_characteristics() {
          lng DOUBLE, \
          CONSTRAINT fkey FOREIGN KEY (lat) REFERENCES othertable3(lat) ON DELETE RESTRICT DEFERRABLE INITIALLY DEFERRED, \
          CONSTRAINT fkey2 FOREIGN KEY (lat) REFERENCES othertable4(lat) ON DELETE NO ACTION ON UPDATE RESTRICT DEFERRABLE INITIALLY IMMEDIATE, \
-         FOREIGN KEY (lat) REFERENCES othertable4(lat) ON DELETE CASCADE ON UPDATE SET DEFAULT NOT DEFERRABLE INITIALLY DEFERRED NOT ENFORCED, \
+         FOREIGN KEY (lat) REFERENCES othertable4(lat) ON UPDATE SET DEFAULT ON DELETE CASCADE NOT DEFERRABLE INITIALLY DEFERRED NOT ENFORCED, \
          FOREIGN KEY (lng) REFERENCES othertable4(longitude) ON UPDATE SET NULL NOT DEFERRABLE INITIALLY IMMEDIATE ENFORCED)",
     );
     match ast {
@@ -2988,8 +3462,10 @@ fn parse_create_table_with_constraint_characteristics() {
                         columns: vec!["lat".into()],
                         foreign_table: ObjectName(vec!["othertable3".into()]),
                         referred_columns: vec!["lat".into()],
+                        match_kind: None,
                         on_delete: Some(ReferentialAction::Restrict),
                         on_update: None,
+                        on_update_first: false,
                         characteristics: Some(ConstraintCharacteristics {
                             deferrable: Some(true),
                             initially: Some(DeferrableInitial::Deferred),
@@ -3001,8 +3477,10 @@ fn parse_create_table_with_constraint_characteristics() {
                         columns: vec!["lat".into()],
                         foreign_table: ObjectName(vec!["othertable4".into()]),
                         referred_columns: vec!["lat".into()],
+                        match_kind: None,
                         on_delete: Some(ReferentialAction::NoAction),
                         on_update: Some(ReferentialAction::Restrict),
+                        on_update_first: false,
                         characteristics: Some(ConstraintCharacteristics {
                             deferrable: Some(true),
                             initially: Some(DeferrableInitial::Immediate),
@@ -3014,8 +3492,10 @@ fn parse_create_table_with_constraint_characteristics() {
                         columns: vec!["lat".into()],
                         foreign_table: ObjectName(vec!["othertable4".into()]),
                         referred_columns: vec!["lat".into()],
+                        match_kind: None,
                         on_delete: Some(ReferentialAction::Cascade),
                         on_update: Some(ReferentialAction::SetDefault),
+                        on_update_first: true,
                         characteristics: Some(ConstraintCharacteristics {
                             deferrable: Some(false),
                             initially: Some(DeferrableInitial::Deferred),
@@ -3027,8 +3507,10 @@ fn parse_create_table_with_constraint_characteristics() {
                         columns: vec!["lng".into()],
                         foreign_table: ObjectName(vec!["othertable4".into()]),
                         referred_columns: vec!["longitude".into()],
+                        match_kind: None,
                         on_delete: None,
                         on_update: Some(ReferentialAction::SetNull),
+                        on_update_first: false,
                         characteristics: Some(ConstraintCharacteristics {
                             deferrable: Some(false),
                             initially: Some(DeferrableInitial::Immediate),
@@ -3070,6 +3552,36 @@ fn parse_create_table_with_constraint_characteristics() {
         .contains("Expected \',\' or \')\' after column definition, found: INITIALLY"));
 }
 
+#[test]
+fn parse_create_table_with_composite_foreign_key() {
+    let sql = "CREATE TABLE order_lines (\
+               order_id INT, \
+               line_no INT, \
+               CONSTRAINT fk_lines FOREIGN KEY (order_id, line_no) REFERENCES orders(id, line) ON DELETE CASCADE)";
+    match verified_stmt(sql) {
+        Statement::CreateTable(CreateTable { constraints, .. }) => {
+            assert_eq!(
+                constraints,
+                vec![TableConstraint::ForeignKey {
+                    name: Some("fk_lines".into()),
+                    columns: vec!["order_id".into(), "line_no".into()],
+                    foreign_table: ObjectName(vec!["orders".into()]),
+                    referred_columns: vec!["id".into(), "line".into()],
+                    match_kind: None,
+                    on_delete: Some(ReferentialAction::Cascade),
+                    on_update: None,
+                    on_update_first: false,
+                    characteristics: None,
+                }]
+            );
+        }
+        _ => unreachable!(),
+    }
+
+    // the referenced column list may be omitted to target the primary key
+    verified_stmt("CREATE TABLE t (a INT, b INT, FOREIGN KEY (a, b) REFERENCES parent)");
+}
+
 #[test]
 fn parse_create_table_column_constraint_characteristics() {
     fn test_combo(
@@ -3368,6 +3880,32 @@ fn parse_drop_schema() {
     }
 }
 
+#[test]
+fn parse_create_temporary_table_scope() {
+    let temp_scope = |sql: &str| match verified_stmt(sql) {
+        Statement::CreateTable(CreateTable {
+            temporary, global, ..
+        }) => (temporary, global),
+        _ => unreachable!(),
+    };
+
+    assert_eq!(
+        (true, Some(true)),
+        temp_scope("CREATE GLOBAL TEMPORARY TABLE t (a INT)")
+    );
+    assert_eq!(
+        (true, Some(false)),
+        temp_scope("CREATE LOCAL TEMPORARY TABLE t (a INT)")
+    );
+    assert_eq!((true, None), temp_scope("CREATE TEMPORARY TABLE t (a INT)"));
+    assert_eq!((false, None), temp_scope("CREATE TABLE t (a INT)"));
+
+    one_statement_parses_to(
+        "CREATE LOCAL TEMP TABLE t (a INT)",
+        "CREATE LOCAL TEMPORARY TABLE t (a INT)",
+    );
+}
+
 #[test]
 fn parse_create_table_as() {
     let sql = "CREATE TABLE t AS SELECT * FROM a";
@@ -3396,6 +3934,23 @@ fn parse_create_table_as() {
         }
         _ => unreachable!(),
     }
+
+    // The source may be a parenthesized query with set operations
+    for query in [
+        "(SELECT a FROM x UNION SELECT a FROM y)",
+        "SELECT a FROM x UNION ALL SELECT a FROM y",
+        "(SELECT a FROM x) EXCEPT (SELECT a FROM y) ORDER BY a",
+    ] {
+        match verified_stmt(&format!("CREATE TABLE t AS {query}")) {
+            Statement::CreateTable(CreateTable {
+                query: Some(ctas_query),
+                ..
+            }) => {
+                assert_eq!(*ctas_query, verified_query(query));
+            }
+            _ => unreachable!(),
+        }
+    }
 }
 
 #[test]
@@ -4214,6 +4769,20 @@ fn parse_explain_analyze_with_simple_select() {
         false,
         Some(AnalyzeFormat::TEXT),
     );
+
+    run_explain_analyze(
+        "EXPLAIN ANALYZE FORMAT YAML SELECT sqrt(id) FROM foo",
+        false,
+        true,
+        Some(AnalyzeFormat::YAML),
+    );
+
+    run_explain_analyze(
+        "EXPLAIN FORMAT XML SELECT sqrt(id) FROM foo",
+        false,
+        false,
+        Some(AnalyzeFormat::XML),
+    );
 }
 
 #[test]
@@ -4245,6 +4814,7 @@ fn parse_named_argument_function() {
                 clauses: vec![],
             }),
             null_treatment: None,
+            nth_value_order: None,
             filter: None,
             over: None,
             within_group: vec![]
@@ -4283,6 +4853,7 @@ fn parse_named_argument_function_with_eq_operator() {
                 clauses: vec![],
             }),
             null_treatment: None,
+            nth_value_order: None,
             filter: None,
             over: None,
             within_group: vec![],
@@ -4340,6 +4911,7 @@ fn parse_window_functions() {
                 clauses: vec![],
             }),
             null_treatment: None,
+            nth_value_order: None,
             filter: None,
             over: Some(WindowType::WindowSpec(WindowSpec {
                 window_name: None,
@@ -4370,6 +4942,92 @@ fn parse_window_functions() {
     }
 }
 
+#[test]
+fn parse_window_frame_bound_combinations() {
+    let preceding = |n: &str| WindowFrameBound::Preceding(Some(Box::new(Expr::Value(number(n)))));
+    let following = |n: &str| WindowFrameBound::Following(Some(Box::new(Expr::Value(number(n)))));
+    let cases = [
+        (
+            "RANGE BETWEEN CURRENT ROW AND CURRENT ROW",
+            WindowFrameUnits::Range,
+            WindowFrameBound::CurrentRow,
+            Some(WindowFrameBound::CurrentRow),
+        ),
+        (
+            "ROWS BETWEEN CURRENT ROW AND UNBOUNDED FOLLOWING",
+            WindowFrameUnits::Rows,
+            WindowFrameBound::CurrentRow,
+            Some(WindowFrameBound::Following(None)),
+        ),
+        (
+            "GROUPS BETWEEN CURRENT ROW AND 2 FOLLOWING",
+            WindowFrameUnits::Groups,
+            WindowFrameBound::CurrentRow,
+            Some(following("2")),
+        ),
+        (
+            "RANGE BETWEEN 1 PRECEDING AND CURRENT ROW",
+            WindowFrameUnits::Range,
+            preceding("1"),
+            Some(WindowFrameBound::CurrentRow),
+        ),
+        (
+            "ROWS BETWEEN UNBOUNDED PRECEDING AND 3 FOLLOWING",
+            WindowFrameUnits::Rows,
+            WindowFrameBound::Preceding(None),
+            Some(following("3")),
+        ),
+        (
+            "ROWS BETWEEN 5 PRECEDING AND 2 PRECEDING",
+            WindowFrameUnits::Rows,
+            preceding("5"),
+            Some(preceding("2")),
+        ),
+        (
+            "ROWS BETWEEN 2 FOLLOWING AND UNBOUNDED FOLLOWING",
+            WindowFrameUnits::Rows,
+            following("2"),
+            Some(WindowFrameBound::Following(None)),
+        ),
+        (
+            "RANGE CURRENT ROW",
+            WindowFrameUnits::Range,
+            WindowFrameBound::CurrentRow,
+            None,
+        ),
+        (
+            "ROWS 3 PRECEDING",
+            WindowFrameUnits::Rows,
+            preceding("3"),
+            None,
+        ),
+    ];
+
+    for (frame, units, start_bound, end_bound) in cases {
+        let sql = format!("SELECT SUM(x) OVER (ORDER BY y {frame}) FROM t");
+        let select = verified_only_select(&sql);
+        match expr_from_projection(only(&select.projection)) {
+            Expr::Function(Function {
+                over:
+                    Some(WindowType::WindowSpec(WindowSpec {
+                        window_frame: Some(window_frame),
+                        ..
+                    })),
+                ..
+            }) => assert_eq!(
+                &WindowFrame {
+                    units,
+                    start_bound,
+                    end_bound,
+                },
+                window_frame,
+                "{sql}"
+            ),
+            _ => unreachable!(),
+        }
+    }
+}
+
 #[test]
 fn parse_named_window_functions() {
     let supported_dialects = TestedDialects {
@@ -4417,6 +5075,33 @@ fn parse_named_window_functions() {
     supported_dialects.verified_stmt(sql);
 }
 
+#[test]
+fn parse_named_window_forward_reference() {
+    // windows may be used, and may refer to each other, before they are defined
+    let sql = "SELECT SUM(x) OVER w1 FROM t WINDOW w1 AS (w2 ORDER BY z), w2 AS (PARTITION BY y)";
+    let select = verified_only_select(sql);
+    match expr_from_projection(only(&select.projection)) {
+        Expr::Function(Function { over, .. }) => {
+            assert_eq!(&Some(WindowType::NamedWindow(Ident::new("w1"))), over);
+        }
+        _ => unreachable!(),
+    }
+    assert_eq!(
+        vec![Ident::new("w1"), Ident::new("w2")],
+        select
+            .named_window
+            .iter()
+            .map(|NamedWindowDefinition(name, _)| name.clone())
+            .collect::<Vec<_>>()
+    );
+    match &select.named_window[0].1 {
+        NamedWindowExpr::WindowSpec(WindowSpec { window_name, .. }) => {
+            assert_eq!(&Some(Ident::new("w2")), window_name);
+        }
+        _ => unreachable!(),
+    }
+}
+
 #[test]
 fn parse_window_clause() {
     let sql = "SELECT * \
@@ -4473,6 +5158,7 @@ fn test_parse_named_window() {
                         clauses: vec![],
                     }),
                     null_treatment: None,
+                    nth_value_order: None,
                     filter: None,
                     over: Some(WindowType::NamedWindow(Ident {
                         value: "window1".to_string(),
@@ -4502,6 +5188,7 @@ fn test_parse_named_window() {
                         clauses: vec![],
                     }),
                     null_treatment: None,
+                    nth_value_order: None,
                     filter: None,
                     over: Some(WindowType::NamedWindow(Ident {
                         value: "window2".to_string(),
@@ -4574,7 +5261,6 @@ fn test_parse_named_window() {
             ),
         ],
         qualify: None,
-        window_before_qualify: true,
         value_table_mode: None,
         connect_by: None,
     };
@@ -4583,15 +5269,16 @@ fn test_parse_named_window() {
 
 #[test]
 fn parse_window_and_qualify_clause() {
-    let sql = "SELECT \
+    let canonical = "SELECT \
     MIN(c12) OVER window1 AS min1 \
     FROM aggregate_test_100 \
     QUALIFY ROW_NUMBER() OVER my_window \
     WINDOW window1 AS (ORDER BY C12), \
     window2 AS (PARTITION BY C11) \
     ORDER BY C3";
-    verified_only_select(sql);
+    verified_only_select(canonical);
 
+    // WINDOW before QUALIFY is accepted and displayed in the canonical order
     let sql = "SELECT \
     MIN(c12) OVER window1 AS min1 \
     FROM aggregate_test_100 \
@@ -4599,7 +5286,7 @@ fn parse_window_and_qualify_clause() {
     window2 AS (PARTITION BY C11) \
     QUALIFY ROW_NUMBER() OVER my_window \
     ORDER BY C3";
-    verified_only_select(sql);
+    all_dialects().one_statement_parses_to(sql, canonical);
 }
 
 #[test]
@@ -4762,6 +5449,21 @@ fn parse_interval() {
         expr_from_projection(only(&select.projection)),
     );
 
+    let sql = "SELECT INTERVAL '1 2:03:04.5' DAY (2) TO SECOND (3)";
+    let select = verified_only_select(sql);
+    assert_eq!(
+        &Expr::Interval(Interval {
+            value: Box::new(Expr::Value(Value::SingleQuotedString(String::from(
+                "1 2:03:04.5"
+            )))),
+            leading_field: Some(DateTimeField::Day),
+            leading_precision: Some(2),
+            last_field: Some(DateTimeField::Second),
+            fractional_seconds_precision: Some(3),
+        }),
+        expr_from_projection(only(&select.projection)),
+    );
+
     let sql = "SELECT INTERVAL '1' SECOND (5, 4)";
     let select = verified_only_select(sql);
     assert_eq!(
@@ -4879,6 +5581,29 @@ fn parse_interval() {
     );
 }
 
+#[test]
+fn parse_interval_spelling_is_preserved() {
+    // Equivalent spellings stay distinct; the string body is never rewritten
+    for sql in [
+        "INTERVAL '1' DAY",
+        "INTERVAL '1 day'",
+        "INTERVAL '1 DAY'",
+        "INTERVAL '1  day 02:00'",
+        "INTERVAL '1' DAY (2)",
+        "INTERVAL '1:30' HOUR TO MINUTE",
+    ] {
+        verified_expr(sql);
+    }
+    assert_ne!(
+        verified_expr("INTERVAL '1' DAY"),
+        verified_expr("INTERVAL '1 day'")
+    );
+    assert_ne!(
+        verified_expr("INTERVAL '1 day'"),
+        verified_expr("INTERVAL '1 DAY'")
+    );
+}
+
 #[test]
 fn parse_interval_and_or_xor() {
     let sql = "SELECT col FROM test \
@@ -4968,7 +5693,6 @@ fn parse_interval_and_or_xor() {
             having: None,
             named_window: vec![],
             qualify: None,
-            window_before_qualify: false,
             value_table_mode: None,
             connect_by: None,
         }))),
@@ -6060,6 +6784,56 @@ fn parse_multiple_statements() {
     assert_eq!(0, res.unwrap().len());
 }
 
+#[test]
+fn parse_statements_with_error_recovery() {
+    let sql = "SELECT foo FROM bar; SELECT * FRM baz WHERE x = 1; INSERT INTO t VALUES (1)";
+    let dialect = GenericDialect {};
+    let (statements, errors) = Parser::new(&dialect)
+        .try_with_sql(sql)
+        .unwrap()
+        .try_parse_statements();
+    assert_eq!(
+        vec![
+            verified_stmt("SELECT foo FROM bar"),
+            verified_stmt("INSERT INTO t VALUES (1)"),
+        ],
+        statements
+    );
+    assert_eq!(1, errors.len());
+    assert!(errors[0]
+        .to_string()
+        .starts_with("sql parser error: Expected end of statement, found: FRM"));
+
+    // A statement running into the next one is reported and skipped as a whole
+    let (statements, errors) = Parser::new(&dialect)
+        .try_with_sql("SELECT 1 SELECT 2; SELECT 3; DROP; SELECT 4")
+        .unwrap()
+        .try_parse_statements();
+    assert_eq!(
+        vec![verified_stmt("SELECT 3"), verified_stmt("SELECT 4")],
+        statements
+    );
+    assert_eq!(2, errors.len());
+
+    // A stray `END` is an error too rather than the end of the input
+    let (statements, errors) = Parser::new(&dialect)
+        .try_with_sql("SELECT 1 END; SELECT 2 FRM x; SELECT 3")
+        .unwrap()
+        .try_parse_statements();
+    assert_eq!(vec![verified_stmt("SELECT 3")], statements);
+    assert_eq!(
+        vec![
+            ParserError::ParserError(
+                "Expected end of statement, found: END at Line: 1, Column 10".to_string()
+            ),
+            ParserError::ParserError(
+                "Expected end of statement, found: x at Line: 1, Column 28".to_string()
+            ),
+        ],
+        errors
+    );
+}
+
 #[test]
 fn parse_scalar_subqueries() {
     let sql = "(SELECT 1) + (SELECT 2)";
@@ -6270,6 +7044,8 @@ fn parse_create_view() {
             with_no_schema_binding: late_binding,
             if_not_exists,
             temporary,
+            with_check_option,
+            with_data,
         } => {
             assert_eq!("myschema.myview", name.to_string());
             assert_eq!(Vec::<ViewColumnDef>::new(), columns);
@@ -6280,6 +7056,8 @@ fn parse_create_view() {
             assert_eq!(cluster_by, vec![]);
             assert!(comment.is_none());
             assert!(!late_binding);
+            assert!(with_check_option.is_none());
+            assert!(with_data.is_none());
             assert!(!if_not_exists);
             assert!(!temporary);
         }
@@ -6287,6 +7065,41 @@ fn parse_create_view() {
     }
 }
 
+#[test]
+fn parse_create_view_with_check_option() {
+    let sql = "CREATE OR REPLACE VIEW v (a, b) AS SELECT 1, 2 WITH CASCADED CHECK OPTION";
+    match verified_stmt(sql) {
+        Statement::CreateView {
+            columns,
+            or_replace,
+            with_check_option,
+            ..
+        } => {
+            assert!(or_replace);
+            assert_eq!(
+                vec![Ident::new("a"), Ident::new("b")],
+                columns.into_iter().map(|c| c.name).collect::<Vec<_>>()
+            );
+            assert_eq!(Some(CheckOption::Cascaded), with_check_option);
+        }
+        _ => unreachable!(),
+    }
+
+    match verified_stmt("CREATE VIEW v AS SELECT 1 WITH LOCAL CHECK OPTION") {
+        Statement::CreateView {
+            with_check_option, ..
+        } => assert_eq!(Some(CheckOption::Local), with_check_option),
+        _ => unreachable!(),
+    }
+    match verified_stmt("CREATE VIEW v AS SELECT 1 WITH CHECK OPTION") {
+        Statement::CreateView {
+            with_check_option, ..
+        } => assert_eq!(Some(CheckOption::Unqualified), with_check_option),
+        _ => unreachable!(),
+    }
+    verified_stmt("CREATE MATERIALIZED VIEW v (a) AS SELECT 1 WITH CHECK OPTION");
+}
+
 #[test]
 fn parse_create_view_with_options() {
     let sql = "CREATE VIEW v WITH (foo = 'bar', a = 123) AS SELECT 1";
@@ -6326,6 +7139,8 @@ fn parse_create_view_with_columns() {
             with_no_schema_binding: late_binding,
             if_not_exists,
             temporary,
+            with_check_option,
+            with_data,
         } => {
             assert_eq!("v", name.to_string());
             assert_eq!(
@@ -6346,6 +7161,8 @@ fn parse_create_view_with_columns() {
             assert_eq!(cluster_by, vec![]);
             assert!(comment.is_none());
             assert!(!late_binding);
+            assert!(with_check_option.is_none());
+            assert!(with_data.is_none());
             assert!(!if_not_exists);
             assert!(!temporary);
         }
@@ -6369,6 +7186,8 @@ fn parse_create_view_temporary() {
             with_no_schema_binding: late_binding,
             if_not_exists,
             temporary,
+            with_check_option,
+            with_data,
         } => {
             assert_eq!("myschema.myview", name.to_string());
             assert_eq!(Vec::<ViewColumnDef>::new(), columns);
@@ -6379,6 +7198,8 @@ fn parse_create_view_temporary() {
             assert_eq!(cluster_by, vec![]);
             assert!(comment.is_none());
             assert!(!late_binding);
+            assert!(with_check_option.is_none());
+            assert!(with_data.is_none());
             assert!(!if_not_exists);
             assert!(temporary);
         }
@@ -6402,6 +7223,8 @@ fn parse_create_or_replace_view() {
             with_no_schema_binding: late_binding,
             if_not_exists,
             temporary,
+            with_check_option,
+            with_data,
         } => {
             assert_eq!("v", name.to_string());
             assert_eq!(columns, vec![]);
@@ -6412,6 +7235,8 @@ fn parse_create_or_replace_view() {
             assert_eq!(cluster_by, vec![]);
             assert!(comment.is_none());
             assert!(!late_binding);
+            assert!(with_check_option.is_none());
+            assert!(with_data.is_none());
             assert!(!if_not_exists);
             assert!(!temporary);
         }
@@ -6439,6 +7264,8 @@ fn parse_create_or_replace_materialized_view() {
             with_no_schema_binding: late_binding,
             if_not_exists,
             temporary,
+            with_check_option,
+            with_data,
         } => {
             assert_eq!("v", name.to_string());
             assert_eq!(columns, vec![]);
@@ -6449,6 +7276,8 @@ fn parse_create_or_replace_materialized_view() {
             assert_eq!(cluster_by, vec![]);
             assert!(comment.is_none());
             assert!(!late_binding);
+            assert!(with_check_option.is_none());
+            assert!(with_data.is_none());
             assert!(!if_not_exists);
             assert!(!temporary);
         }
@@ -6472,6 +7301,8 @@ fn parse_create_materialized_view() {
             with_no_schema_binding: late_binding,
             if_not_exists,
             temporary,
+            with_check_option,
+            with_data,
         } => {
             assert_eq!("myschema.myview", name.to_string());
             assert_eq!(Vec::<ViewColumnDef>::new(), columns);
@@ -6482,6 +7313,8 @@ fn parse_create_materialized_view() {
             assert_eq!(cluster_by, vec![]);
             assert!(comment.is_none());
             assert!(!late_binding);
+            assert!(with_check_option.is_none());
+            assert!(with_data.is_none());
             assert!(!if_not_exists);
             assert!(!temporary);
         }
@@ -6505,6 +7338,8 @@ fn parse_create_materialized_view_with_cluster_by() {
             with_no_schema_binding: late_binding,
             if_not_exists,
             temporary,
+            with_check_option,
+            with_data,
         } => {
             assert_eq!("myschema.myview", name.to_string());
             assert_eq!(Vec::<ViewColumnDef>::new(), columns);
@@ -6515,6 +7350,8 @@ fn parse_create_materialized_view_with_cluster_by() {
             assert_eq!(cluster_by, vec![Ident::new("foo")]);
             assert!(comment.is_none());
             assert!(!late_binding);
+            assert!(with_check_option.is_none());
+            assert!(with_data.is_none());
             assert!(!if_not_exists);
             assert!(!temporary);
         }
@@ -6882,7 +7719,6 @@ fn lateral_function() {
         having: None,
         named_window: vec![],
         qualify: None,
-        window_before_qualify: false,
         value_table_mode: None,
         connect_by: None,
     };
@@ -6892,7 +7728,12 @@ fn lateral_function() {
 #[test]
 fn parse_start_transaction() {
     match verified_stmt("START TRANSACTION READ ONLY, READ WRITE, ISOLATION LEVEL SERIALIZABLE") {
-        Statement::StartTransaction { modes, .. } => assert_eq!(
+        Statement::StartTransaction {
+            modes,
+            begin: false,
+            transaction: None,
+            ..
+        } => assert_eq!(
             modes,
             vec![
                 TransactionMode::AccessMode(TransactionAccessMode::ReadOnly),
@@ -6921,9 +7762,24 @@ fn parse_start_transaction() {
     }
 
     verified_stmt("START TRANSACTION");
-    one_statement_parses_to("BEGIN", "BEGIN TRANSACTION");
-    one_statement_parses_to("BEGIN WORK", "BEGIN TRANSACTION");
-    one_statement_parses_to("BEGIN TRANSACTION", "BEGIN TRANSACTION");
+    match verified_stmt("BEGIN") {
+        Statement::StartTransaction {
+            begin, transaction, ..
+        } => {
+            assert!(begin);
+            assert_eq!(None, transaction);
+        }
+        _ => unreachable!(),
+    }
+    match verified_stmt("BEGIN WORK") {
+        Statement::StartTransaction { transaction, .. } => {
+            assert_eq!(Some(BeginTransactionKind::Work), transaction);
+        }
+        _ => unreachable!(),
+    }
+    verified_stmt("BEGIN TRANSACTION");
+    verified_stmt("BEGIN ISOLATION LEVEL READ COMMITTED, READ ONLY");
+    verified_stmt("BEGIN WORK READ WRITE, ISOLATION LEVEL REPEATABLE READ");
 
     verified_stmt("START TRANSACTION ISOLATION LEVEL READ UNCOMMITTED");
     verified_stmt("START TRANSACTION ISOLATION LEVEL READ COMMITTED");
@@ -6990,13 +7846,15 @@ fn parse_set_transaction() {
 fn parse_set_variable() {
     match verified_stmt("SET SOMETHING = '1'") {
         Statement::SetVariable {
-            local,
+            scope,
             hivevar,
             variables,
             value,
+            to_keyword,
         } => {
-            assert!(!local);
+            assert_eq!(ContextModifier::None, scope);
             assert!(!hivevar);
+            assert!(!to_keyword);
             assert_eq!(
                 variables,
                 OneOrManyWithParens::One(ObjectName(vec!["SOMETHING".into()]))
@@ -7013,13 +7871,15 @@ fn parse_set_variable() {
     let sql = r#"SET (a, b, c) = (1, 2, 3)"#;
     match multi_variable_dialects.verified_stmt(sql) {
         Statement::SetVariable {
-            local,
+            scope,
             hivevar,
             variables,
             value,
+            to_keyword,
         } => {
-            assert!(!local);
+            assert_eq!(ContextModifier::None, scope);
             assert!(!hivevar);
+            assert!(!to_keyword);
             assert_eq!(
                 variables,
                 OneOrManyWithParens::Many(vec![
@@ -7053,7 +7913,10 @@ fn parse_set_variable() {
         );
     }
 
-    one_statement_parses_to("SET SOMETHING TO '1'", "SET SOMETHING = '1'");
+    match verified_stmt("SET SOMETHING TO '1'") {
+        Statement::SetVariable { to_keyword, .. } => assert!(to_keyword),
+        _ => unreachable!(),
+    }
 }
 
 #[test]
@@ -7083,12 +7946,13 @@ fn parse_double_colon_cast_at_timezone() {
 fn parse_set_time_zone() {
     match verified_stmt("SET TIMEZONE = 'UTC'") {
         Statement::SetVariable {
-            local,
+            scope,
             hivevar,
             variables: variable,
             value,
+            ..
         } => {
-            assert!(!local);
+            assert_eq!(ContextModifier::None, scope);
             assert!(!hivevar);
             assert_eq!(
                 variable,
@@ -7102,14 +7966,14 @@ fn parse_set_time_zone() {
         _ => unreachable!(),
     }
 
-    one_statement_parses_to("SET TIME ZONE TO 'UTC'", "SET TIMEZONE = 'UTC'");
+    one_statement_parses_to("SET TIME ZONE TO 'UTC'", "SET TIMEZONE TO 'UTC'");
 }
 
 #[test]
 fn parse_set_time_zone_alias() {
     match verified_stmt("SET TIME ZONE 'UTC'") {
-        Statement::SetTimeZone { local, value } => {
-            assert!(!local);
+        Statement::SetTimeZone { scope, value } => {
+            assert_eq!(ContextModifier::None, scope);
             assert_eq!(value, Expr::Value(Value::SingleQuotedString("UTC".into())));
         }
         _ => unreachable!(),
@@ -7152,16 +8016,20 @@ fn parse_end() {
 fn parse_rollback() {
     match verified_stmt("ROLLBACK") {
         Statement::Rollback {
+            transaction: None,
             chain: false,
             savepoint: None,
+            savepoint_keyword: false,
         } => (),
         _ => unreachable!(),
     }
 
     match verified_stmt("ROLLBACK AND CHAIN") {
         Statement::Rollback {
+            transaction: None,
             chain: true,
             savepoint: None,
+            ..
         } => (),
         _ => unreachable!(),
     }
@@ -7170,6 +8038,8 @@ fn parse_rollback() {
         Statement::Rollback {
             chain: false,
             savepoint,
+            savepoint_keyword: true,
+            ..
         } => {
             assert_eq!(savepoint, Some(Ident::new("test1")));
         }
@@ -7180,24 +8050,36 @@ fn parse_rollback() {
         Statement::Rollback {
             chain: true,
             savepoint,
+            ..
+        } => {
+            assert_eq!(savepoint, Some(Ident::new("test1")));
+        }
+        _ => unreachable!(),
+    }
+
+    match verified_stmt("ROLLBACK WORK TO test1") {
+        Statement::Rollback {
+            transaction,
+            savepoint,
+            savepoint_keyword: false,
+            ..
         } => {
+            assert_eq!(transaction, Some(BeginTransactionKind::Work));
             assert_eq!(savepoint, Some(Ident::new("test1")));
         }
         _ => unreachable!(),
     }
 
     one_statement_parses_to("ROLLBACK AND NO CHAIN", "ROLLBACK");
-    one_statement_parses_to("ROLLBACK WORK AND NO CHAIN", "ROLLBACK");
-    one_statement_parses_to("ROLLBACK TRANSACTION AND NO CHAIN", "ROLLBACK");
-    one_statement_parses_to("ROLLBACK WORK AND CHAIN", "ROLLBACK AND CHAIN");
-    one_statement_parses_to("ROLLBACK TRANSACTION AND CHAIN", "ROLLBACK AND CHAIN");
-    one_statement_parses_to("ROLLBACK WORK", "ROLLBACK");
-    one_statement_parses_to("ROLLBACK TRANSACTION", "ROLLBACK");
-    one_statement_parses_to("ROLLBACK TO test1", "ROLLBACK TO SAVEPOINT test1");
-    one_statement_parses_to(
-        "ROLLBACK AND CHAIN TO test1",
-        "ROLLBACK AND CHAIN TO SAVEPOINT test1",
-    );
+    one_statement_parses_to("ROLLBACK WORK AND NO CHAIN", "ROLLBACK WORK");
+    one_statement_parses_to("ROLLBACK TRANSACTION AND NO CHAIN", "ROLLBACK TRANSACTION");
+    verified_stmt("ROLLBACK WORK AND CHAIN");
+    verified_stmt("ROLLBACK TRANSACTION AND CHAIN");
+    verified_stmt("ROLLBACK WORK");
+    verified_stmt("ROLLBACK TRANSACTION");
+    verified_stmt("ROLLBACK TO test1");
+    verified_stmt("ROLLBACK AND CHAIN TO test1");
+    verified_stmt("ROLLBACK WORK TO SAVEPOINT test1");
 }
 
 #[test]
@@ -7370,7 +8252,12 @@ fn parse_grant() {
             granted_by,
             ..
         } => match (privileges, objects) {
-            (Privileges::Actions(actions), GrantObjects::Tables(objects)) => {
+            (
+                Privileges::Actions(actions),
+                GrantObjects::Tables {
+                    tables: objects, ..
+                },
+            ) => {
                 assert_eq!(
                     vec![
                         Action::Select { columns: None },
@@ -7500,6 +8387,27 @@ fn parse_grant() {
         },
         _ => unreachable!(),
     }
+
+    let sql7 = "GRANT CONNECT, TEMPORARY ON DATABASE db1, db2 TO reader";
+    match verified_stmt(sql7) {
+        Statement::Grant {
+            privileges,
+            objects,
+            ..
+        } => match (privileges, objects) {
+            (Privileges::Actions(actions), GrantObjects::Databases(databases)) => {
+                assert_eq!(vec![Action::Connect, Action::Temporary], actions);
+                assert_eq_vec(&["db1", "db2"], &databases);
+            }
+            _ => unreachable!(),
+        },
+        _ => unreachable!(),
+    }
+
+    assert_eq!(
+        ParserError::ParserError("Expected identifier, found: EOF".to_string()),
+        parse_sql_statements("GRANT SELECT ON t TO r GRANTED BY").unwrap_err(),
+    );
 }
 
 #[test]
@@ -7508,7 +8416,11 @@ fn test_revoke() {
     match verified_stmt(sql) {
         Statement::Revoke {
             privileges,
-            objects: GrantObjects::Tables(tables),
+            objects:
+                GrantObjects::Tables {
+                    table_keyword: false,
+                    tables,
+                },
             grantees,
             cascade,
             granted_by,
@@ -7521,11 +8433,45 @@ fn test_revoke() {
             );
             assert_eq_vec(&["users", "auth"], &tables);
             assert_eq_vec(&["analyst"], &grantees);
-            assert!(cascade);
+            assert_eq!(Some(CascadeOption::Cascade), cascade);
             assert_eq!(None, granted_by);
         }
         _ => unreachable!(),
     }
+
+    let sql = "REVOKE SELECT, INSERT ON SEQUENCE s FROM r GRANTED BY admin RESTRICT";
+    match verified_stmt(sql) {
+        Statement::Revoke {
+            objects: GrantObjects::Sequences(sequences),
+            cascade,
+            granted_by,
+            ..
+        } => {
+            assert_eq_vec(&["s"], &sequences);
+            assert_eq!(Some(CascadeOption::Restrict), cascade);
+            assert_eq!(Some(Ident::new("admin")), granted_by);
+        }
+        _ => unreachable!(),
+    }
+
+    verified_stmt("REVOKE USAGE ON SCHEMA s1 FROM r CASCADE");
+    verified_stmt("REVOKE ALL ON DATABASE db FROM r RESTRICT");
+    match verified_stmt("REVOKE SELECT ON TABLE t FROM r") {
+        Statement::Revoke {
+            objects: GrantObjects::Tables { table_keyword, .. },
+            cascade,
+            ..
+        } => {
+            assert!(table_keyword);
+            assert_eq!(None, cascade);
+        }
+        _ => unreachable!(),
+    }
+
+    assert_eq!(
+        ParserError::ParserError("Cannot specify both CASCADE and RESTRICT in REVOKE".to_string()),
+        parse_sql_statements("REVOKE SELECT ON t FROM r CASCADE RESTRICT").unwrap_err(),
+    );
 }
 
 #[test]
@@ -7600,7 +8546,6 @@ fn parse_merge() {
                             sort_by: vec![],
                             having: None,
                             named_window: vec![],
-                            window_before_qualify: false,
                             qualify: None,
                             value_table_mode: None,
                             connect_by: None,
@@ -8033,6 +8978,36 @@ fn parse_offset_and_limit() {
         }),
     );
 
+    // placeholders are allowed
+    let ast = all_dialects_where(|d| !d.is_identifier_start('$'))
+        .verified_query("SELECT foo FROM bar LIMIT $1 OFFSET $2");
+    assert_eq!(
+        ast.limit,
+        Some(Expr::Value(Value::Placeholder("$1".into())))
+    );
+    assert_eq!(
+        ast.offset,
+        Some(Offset {
+            value: Expr::Value(Value::Placeholder("$2".into())),
+            rows: OffsetRows::None,
+        }),
+    );
+    // `?` is a JSON operator in PostgreSQL
+    let dialects = all_dialects_except(|d| d.is::<PostgreSqlDialect>());
+    let ast = dialects.verified_query("SELECT foo FROM bar LIMIT ?");
+    assert_eq!(ast.limit, Some(Expr::Value(Value::Placeholder("?".into()))));
+    assert_eq!(ast.offset, None);
+    let ast =
+        dialects.verified_query("SELECT foo FROM bar OFFSET ? ROWS FETCH FIRST ? + 1 ROWS ONLY");
+    assert_eq!(
+        ast.fetch.and_then(|fetch| fetch.quantity),
+        Some(Expr::BinaryOp {
+            left: Box::new(Expr::Value(Value::Placeholder("?".into()))),
+            op: BinaryOperator::Plus,
+            right: Box::new(Expr::Value(number("1"))),
+        }),
+    );
+
     // Can't repeat OFFSET / LIMIT
     let res = parse_sql_statements("SELECT foo FROM bar OFFSET 2 OFFSET 2");
     assert_eq!(
@@ -8066,6 +9041,7 @@ fn parse_time_functions() {
                 clauses: vec![],
             }),
             null_treatment: None,
+            nth_value_order: None,
             filter: None,
             over: None,
             within_group: vec![],
@@ -8171,11 +9147,37 @@ fn parse_is_boolean() {
     verified_stmt("SELECT f FROM foo WHERE field IS UNKNOWN");
     verified_stmt("SELECT f FROM foo WHERE field IS NOT UNKNOWN");
 
+    let sql = "(a = b) IS UNKNOWN";
+    assert_eq!(
+        IsUnknown(Box::new(Nested(Box::new(BinaryOp {
+            left: Box::new(Identifier(Ident::new("a"))),
+            op: BinaryOperator::Eq,
+            right: Box::new(Identifier(Ident::new("b"))),
+        })))),
+        verified_expr(sql)
+    );
+    verified_stmt("SELECT f FROM foo WHERE (a = b) IS NOT UNKNOWN AND c IS NOT NULL");
+
+    // `IS` binds looser than comparisons but tighter than `AND`
+    let sql = "a = b IS TRUE AND c IS NOT UNKNOWN";
+    assert_eq!(
+        BinaryOp {
+            left: Box::new(IsTrue(Box::new(BinaryOp {
+                left: Box::new(Identifier(Ident::new("a"))),
+                op: BinaryOperator::Eq,
+                right: Box::new(Identifier(Ident::new("b"))),
+            }))),
+            op: BinaryOperator::And,
+            right: Box::new(IsNotUnknown(Box::new(Identifier(Ident::new("c"))))),
+        },
+        verified_expr(sql)
+    );
+
     let sql = "SELECT f from foo where field is 0";
     let res = parse_sql_statements(sql);
     assert_eq!(
         ParserError::ParserError(
-            "Expected [NOT] NULL or TRUE|FALSE or [NOT] DISTINCT FROM after IS, found: 0"
+            "Expected [NOT] NULL or TRUE|FALSE|UNKNOWN or [NOT] DISTINCT FROM after IS, found: 0"
                 .to_string()
         ),
         res.unwrap_err()
@@ -8237,6 +9239,12 @@ fn parse_show_functions() {
             filter: Some(ShowStatementFilter::Like("pattern".into())),
         }
     );
+    assert_eq!(
+        verified_stmt("SHOW FUNCTIONS ILIKE 'pat''tern'"),
+        Statement::ShowFunctions {
+            filter: Some(ShowStatementFilter::ILike("pat'tern".into())),
+        }
+    );
 }
 
 #[test]
@@ -8724,6 +9732,7 @@ fn parse_unpivot_table() {
                 .into_iter()
                 .map(Ident::new)
                 .collect(),
+            null_inclusion: None,
             alias: Some(TableAlias {
                 name: Ident::new("u"),
                 columns: ["product", "quarter", "quantity"]
@@ -8752,6 +9761,32 @@ fn parse_unpivot_table() {
         verified_stmt(sql_without_aliases).to_string(),
         sql_without_aliases
     );
+
+    let sql_with_nulls = concat!(
+        "SELECT * FROM sales ",
+        "UNPIVOT INCLUDE NULLS (quantity FOR quarter IN (Q1, Q2, Q3))"
+    );
+    assert_matches!(
+        &verified_only_select(sql_with_nulls).from[0].relation,
+        Unpivot {
+            columns,
+            null_inclusion: Some(NullInclusion::IncludeNulls),
+            ..
+        } if columns.len() == 3
+    );
+
+    let sql_without_nulls = concat!(
+        "SELECT * FROM sales ",
+        "UNPIVOT EXCLUDE NULLS (quantity FOR quarter IN (Q1, Q2)) AS u"
+    );
+    assert_matches!(
+        &verified_only_select(sql_without_nulls).from[0].relation,
+        Unpivot {
+            null_inclusion: Some(NullInclusion::ExcludeNulls),
+            alias: Some(_),
+            ..
+        }
+    );
 }
 
 #[test]
@@ -8790,6 +9825,7 @@ fn parse_pivot_unpivot_table() {
                     .into_iter()
                     .map(Ident::new)
                     .collect(),
+                null_inclusion: None,
                 alias: Some(TableAlias {
                     name: Ident::new("u"),
                     columns: vec![]
@@ -8979,6 +10015,32 @@ fn parse_create_type() {
     );
 }
 
+#[test]
+fn parse_create_type_as_enum() {
+    assert_eq!(
+        Statement::CreateType {
+            name: ObjectName(vec![Ident::new("status")]),
+            representation: UserDefinedTypeRepresentation::Enum {
+                labels: vec!["active".to_string(), "it's done".to_string()],
+            },
+        },
+        verified_stmt("CREATE TYPE status AS ENUM ('active', 'it''s done')")
+    );
+
+    assert_eq!(
+        Statement::CreateType {
+            name: ObjectName(vec![Ident::new("empty")]),
+            representation: UserDefinedTypeRepresentation::Enum { labels: vec![] },
+        },
+        verified_stmt("CREATE TYPE empty AS ENUM ()")
+    );
+
+    assert_eq!(
+        ParserError::ParserError("Expected a string literal enum label, found: a".to_string()),
+        parse_sql_statements("CREATE TYPE status AS ENUM (a)").unwrap_err()
+    );
+}
+
 #[test]
 fn parse_call() {
     all_dialects().verified_stmt("CALL my_procedure()");
@@ -8998,6 +10060,7 @@ fn parse_call() {
             name: ObjectName(vec![Ident::new("my_procedure")]),
             filter: None,
             null_treatment: None,
+            nth_value_order: None,
             over: None,
             within_group: vec![],
         })
@@ -9098,7 +10161,6 @@ fn parse_unload() {
                     sort_by: vec![],
                     having: None,
                     named_window: vec![],
-                    window_before_qualify: false,
                     qualify: None,
                     value_table_mode: None,
                     connect_by: None,
@@ -9140,13 +10202,26 @@ fn test_savepoint() {
 #[test]
 fn test_release_savepoint() {
     match verified_stmt("RELEASE SAVEPOINT test1") {
-        Statement::ReleaseSavepoint { name } => {
+        Statement::ReleaseSavepoint {
+            name,
+            savepoint_keyword,
+        } => {
             assert_eq!(Ident::new("test1"), name);
+            assert!(savepoint_keyword);
         }
         _ => unreachable!(),
     }
 
-    one_statement_parses_to("RELEASE test1", "RELEASE SAVEPOINT test1");
+    match verified_stmt("RELEASE test1") {
+        Statement::ReleaseSavepoint {
+            name,
+            savepoint_keyword,
+        } => {
+            assert_eq!(Ident::new("test1"), name);
+            assert!(!savepoint_keyword);
+        }
+        _ => unreachable!(),
+    }
 }
 
 #[test]
@@ -9242,7 +10317,6 @@ fn parse_connect_by() {
         having: None,
         named_window: vec![],
         qualify: None,
-        window_before_qualify: false,
         value_table_mode: None,
         connect_by: Some(ConnectBy {
             condition: Expr::BinaryOp {
@@ -9330,7 +10404,6 @@ fn parse_connect_by() {
             having: None,
             named_window: vec![],
             qualify: None,
-            window_before_qualify: false,
             value_table_mode: None,
             connect_by: Some(ConnectBy {
                 condition: Expr::BinaryOp {
@@ -9401,7 +10474,8 @@ fn test_selective_aggregation() {
                 ))))),
                 over: None,
                 within_group: vec![],
-                null_treatment: None
+                null_treatment: None,
+                nth_value_order: None
             })),
             SelectItem::ExprWithAlias {
                 expr: Expr::Function(Function {
@@ -9420,6 +10494,7 @@ fn test_selective_aggregation() {
                         escape_char: None,
                     })),
                     null_treatment: None,
+                    nth_value_order: None,
                     over: None,
                     within_group: vec![]
                 }),
@@ -9442,15 +10517,58 @@ fn test_group_by_grouping_sets() {
             .verified_only_select(sql)
             .group_by,
         GroupByExpr::Expressions(vec![Expr::GroupingSets(vec![
-            vec![
+            OneOrManyWithParens::Many(vec![
                 Expr::Identifier(Ident::new("city")),
                 Expr::Identifier(Ident::new("car_model"))
-            ],
-            vec![Expr::Identifier(Ident::new("city")),],
-            vec![Expr::Identifier(Ident::new("car_model"))],
-            vec![]
+            ]),
+            OneOrManyWithParens::Many(vec![Expr::Identifier(Ident::new("city")),]),
+            OneOrManyWithParens::Many(vec![Expr::Identifier(Ident::new("car_model"))]),
+            OneOrManyWithParens::Many(vec![])
         ])])
     );
+
+    let dialects = all_dialects_where(|d| d.supports_group_by_expr());
+    let select = dialects
+        .verified_only_select("SELECT a, b FROM t GROUP BY a, GROUPING SETS (ROLLUP (a, b), ())");
+    assert_eq!(
+        select.group_by,
+        GroupByExpr::Expressions(vec![
+            Expr::Identifier(Ident::new("a")),
+            Expr::GroupingSets(vec![
+                OneOrManyWithParens::One(Expr::Rollup(vec![
+                    vec![Expr::Identifier(Ident::new("a"))],
+                    vec![Expr::Identifier(Ident::new("b"))],
+                ])),
+                OneOrManyWithParens::Many(vec![]),
+            ]),
+        ])
+    );
+    dialects.verified_only_select("SELECT a FROM t GROUP BY GROUPING SETS (CUBE (a, b), (c))");
+    dialects.verified_only_select("SELECT a FROM t GROUP BY GROUPING SETS (GROUPING SETS ((a)))");
+
+    // a bare expression is a single-element grouping set, kept without parentheses
+    match dialects.verified_stmt("SELECT a FROM t GROUP BY GROUPING SETS (a, (b, c), (d))") {
+        Statement::Query(query) => assert_eq!(
+            query.body.as_select().unwrap().group_by,
+            GroupByExpr::Expressions(vec![Expr::GroupingSets(vec![
+                OneOrManyWithParens::One(Expr::Identifier(Ident::new("a"))),
+                OneOrManyWithParens::Many(vec![
+                    Expr::Identifier(Ident::new("b")),
+                    Expr::Identifier(Ident::new("c")),
+                ]),
+                OneOrManyWithParens::Many(vec![Expr::Identifier(Ident::new("d"))]),
+            ])])
+        ),
+        _ => unreachable!(),
+    }
+
+    // the empty grouping set may also appear directly in the GROUP BY list
+    let select = dialects.verified_only_select("SELECT count(*) FROM t GROUP BY ()");
+    assert_eq!(
+        select.group_by,
+        GroupByExpr::Expressions(vec![Expr::Tuple(vec![])])
+    );
+    dialects.verified_only_select("SELECT a FROM t GROUP BY a, ROLLUP (b, c), ()");
 }
 
 #[test]
@@ -9599,6 +10717,39 @@ fn test_match_recognize() {
     for sql in examples {
         all_dialects_where(|d| d.supports_match_recognize()).verified_query(sql);
     }
+
+    for (options, rows_per_match, after_match_skip) in [
+        (
+            "ALL ROWS PER MATCH SHOW EMPTY MATCHES AFTER MATCH SKIP PAST LAST ROW",
+            Some(RowsPerMatch::AllRows(Some(EmptyMatchesMode::Show))),
+            Some(AfterMatchSkip::PastLastRow),
+        ),
+        (
+            "ALL ROWS PER MATCH OMIT EMPTY MATCHES AFTER MATCH SKIP TO NEXT ROW",
+            Some(RowsPerMatch::AllRows(Some(EmptyMatchesMode::Omit))),
+            Some(AfterMatchSkip::ToNextRow),
+        ),
+        (
+            "ALL ROWS PER MATCH WITH UNMATCHED ROWS AFTER MATCH SKIP TO FIRST a",
+            Some(RowsPerMatch::AllRows(Some(EmptyMatchesMode::WithUnmatched))),
+            Some(AfterMatchSkip::ToFirst(Ident::new("a"))),
+        ),
+    ] {
+        let select =
+            all_dialects_where(|d| d.supports_match_recognize()).verified_only_select(&format!(
+                "SELECT * FROM my_table MATCH_RECOGNIZE({options} PATTERN (a) DEFINE a AS true)"
+            ));
+        let TableFactor::MatchRecognize {
+            rows_per_match: actual_rows_per_match,
+            after_match_skip: actual_after_match_skip,
+            ..
+        } = &select.from[0].relation
+        else {
+            panic!("expected match_recognize table factor");
+        };
+        assert_eq!(actual_rows_per_match, &rows_per_match);
+        assert_eq!(actual_after_match_skip, &after_match_skip);
+    }
 }
 
 #[test]
@@ -9907,6 +11058,36 @@ fn parse_within_group() {
         "PERCENTILE_CONT(0.5) WITHIN GROUP (ORDER BY sales_amount) ",
         "OVER (PARTITION BY department)",
     ));
+
+    let select = verified_only_select(
+        "SELECT PERCENTILE_CONT(0.5) WITHIN GROUP (ORDER BY x) OVER (PARTITION BY g) FROM t",
+    );
+    match expr_from_projection(only(&select.projection)) {
+        Expr::Function(Function {
+            within_group,
+            over: Some(WindowType::WindowSpec(window_spec)),
+            ..
+        }) => {
+            assert_eq!(
+                vec![OrderByExpr {
+                    expr: Expr::Identifier(Ident::new("x")),
+                    asc: None,
+                    nulls_first: None,
+                }],
+                *within_group
+            );
+            assert_eq!(
+                vec![Expr::Identifier(Ident::new("g"))],
+                window_spec.partition_by
+            );
+        }
+        _ => unreachable!(),
+    }
+
+    all_dialects_where(|d| d.supports_filter_during_aggregation()).verified_expr(concat!(
+        "PERCENTILE_CONT(0.5) WITHIN GROUP (ORDER BY x) ",
+        "FILTER (WHERE x > 0) OVER w",
+    ));
 }
 
 #[test]
@@ -9991,3 +11172,121 @@ fn parse_select_wildcard_with_except() {
         "sql parser error: Expected identifier, found: )"
     );
 }
+
+#[test]
+fn parse_json_object() {
+    let select = verified_only_select("SELECT JSON_OBJECT('a' VALUE 1, 'b' VALUE 2)");
+    assert_eq!(
+        &Expr::JsonObject {
+            entries: vec![
+                JsonKeyValue {
+                    explicit_key: false,
+                    key: Expr::Value(Value::SingleQuotedString("a".to_string())),
+                    value: Expr::Value(number("1")),
+                    separator: JsonKeyValueSeparator::Value,
+                },
+                JsonKeyValue {
+                    explicit_key: false,
+                    key: Expr::Value(Value::SingleQuotedString("b".to_string())),
+                    value: Expr::Value(number("2")),
+                    separator: JsonKeyValueSeparator::Value,
+                },
+            ],
+            null_clause: None,
+        },
+        expr_from_projection(only(&select.projection))
+    );
+
+    // Snowflake parses `:` as semi-structured data access
+    let dialects = all_dialects_except(|d| d.is::<SnowflakeDialect>());
+    let select = dialects
+        .verified_only_select("SELECT JSON_OBJECT(KEY 'a' VALUE x, 'b' : y ABSENT ON NULL)");
+    match expr_from_projection(only(&select.projection)) {
+        Expr::JsonObject {
+            entries,
+            null_clause,
+        } => {
+            assert!(entries[0].explicit_key);
+            assert_eq!(JsonKeyValueSeparator::Colon, entries[1].separator);
+            assert_eq!(&Some(JsonNullClause::AbsentOnNull), null_clause);
+        }
+        _ => unreachable!(),
+    }
+
+    verified_only_select("SELECT JSON_OBJECT(key VALUE 1)");
+    verified_only_select("SELECT JSON_OBJECT()");
+    verified_only_select("SELECT JSON_OBJECT(NULL ON NULL)");
+
+    // MySQL style alternating keys and values is a plain function call
+    let select = verified_only_select("SELECT JSON_OBJECT('a', 1, 'b', 2)");
+    assert!(matches!(
+        expr_from_projection(only(&select.projection)),
+        Expr::Function(_)
+    ));
+}
+
+#[test]
+fn parse_json_array() {
+    let select = verified_only_select("SELECT JSON_ARRAY(1, 2)");
+    assert_eq!(
+        &Expr::JsonArray {
+            values: vec![Expr::Value(number("1")), Expr::Value(number("2"))],
+            null_clause: None,
+        },
+        expr_from_projection(only(&select.projection))
+    );
+
+    let select = verified_only_select("SELECT JSON_ARRAY(a, b NULL ON NULL)");
+    assert_eq!(
+        &Expr::JsonArray {
+            values: vec![
+                Expr::Identifier(Ident::new("a")),
+                Expr::Identifier(Ident::new("b"))
+            ],
+            null_clause: Some(JsonNullClause::NullOnNull),
+        },
+        expr_from_projection(only(&select.projection))
+    );
+
+    verified_only_select("SELECT JSON_ARRAY()");
+    verified_only_select("SELECT JSON_ARRAY(ABSENT ON NULL)");
+}
+
+#[test]
+fn parse_multi_dimensional_array() {
+    let row = |a: Expr, b: Expr, named: bool| {
+        Expr::Array(Array {
+            elem: vec![a, b],
+            named,
+        })
+    };
+    let string = |s: &str| Expr::Value(Value::SingleQuotedString(s.to_string()));
+    // `[` may start a quoted identifier in these dialects
+    let dialects = all_dialects_except(|d| {
+        d.is::<MsSqlDialect>() || d.is::<SQLiteDialect>() || d.is::<RedshiftSqlDialect>()
+    });
+
+    let expr = dialects.verified_expr("ARRAY[[1, 2], [3, 4]]");
+    assert_eq!(
+        Expr::Array(Array {
+            elem: vec![
+                row(Expr::Value(number("1")), Expr::Value(number("2")), false),
+                row(Expr::Value(number("3")), Expr::Value(number("4")), false),
+            ],
+            named: true,
+        }),
+        expr
+    );
+
+    let expr = dialects.verified_expr("ARRAY[ARRAY['a', 'b'], ARRAY['c', 'd']]");
+    assert_eq!(
+        Expr::Array(Array {
+            elem: vec![
+                row(string("a"), string("b"), true),
+                row(string("c"), string("d"), true),
+            ],
+            named: true,
+        }),
+        expr
+    );
+}
@@ -125,6 +125,33 @@ fn custom_statement_parser() -> Result<(), ParserError> {
     Ok(())
 }
 
+#[test]
+fn custom_pipes_as_logical_or() -> Result<(), ParserError> {
+    #[derive(Debug)]
+    struct MyDialect {}
+
+    impl Dialect for MyDialect {
+        fn is_identifier_start(&self, ch: char) -> bool {
+            is_identifier_start(ch)
+        }
+
+        fn is_identifier_part(&self, ch: char) -> bool {
+            is_identifier_part(ch)
+        }
+
+        fn supports_pipes_as_or(&self) -> bool {
+            true
+        }
+    }
+
+    let dialect = MyDialect {};
+    let sql = "SELECT a || b AND c";
+    let ast = Parser::parse_sql(&dialect, sql)?;
+    let query = &ast[0];
+    assert_eq!(sql, &format!("{query}"));
+    Ok(())
+}
+
 fn is_identifier_start(ch: char) -> bool {
     ch.is_ascii_lowercase() || ch.is_ascii_uppercase() || ch == '_'
 }
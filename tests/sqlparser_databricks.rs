@@ -188,3 +188,18 @@ fn test_values_clause() {
     // TODO: support this example from https://docs.databricks.com/en/sql/language-manual/sql-ref-syntax-qry-select-values.html#examples
     // databricks().verified_query("VALUES 1, 2, 3");
 }
+
+#[test]
+fn parse_use() {
+    assert_eq!(
+        databricks().verified_stmt("USE DATABASE my_db"),
+        Statement::Use(Use::Database(ObjectName(vec![Ident::new("my_db")])))
+    );
+    assert_eq!(
+        databricks().verified_stmt("USE SCHEMA my_schema"),
+        Statement::Use(Use::Schema(ObjectName(vec![Ident::new("my_schema")])))
+    );
+    assert!(databricks()
+        .parse_sql_statements("USE WAREHOUSE compute_wh")
+        .is_err());
+}
@@ -64,6 +64,23 @@ fn test_select_wildcard_with_exclude() {
     assert_eq!(expected, select.projection[0]);
 }
 
+#[test]
+fn test_select_wildcard_with_exclude_and_rename() {
+    let select = duckdb().verified_only_select("SELECT * EXCLUDE (a) RENAME (b AS c) FROM t");
+    let expected = SelectItem::Wildcard(WildcardAdditionalOptions {
+        opt_exclude: Some(ExcludeSelectItem::Multiple(vec![Ident::new("a")])),
+        opt_rename: Some(RenameSelectItem::Multiple(vec![IdentWithAlias {
+            ident: Ident::new("b"),
+            alias: Ident::new("c"),
+        }])),
+        ..Default::default()
+    });
+    assert_eq!(expected, select.projection[0]);
+
+    duckdb().verified_stmt("SELECT * EXCLUDE (a) RENAME (b AS c) REPLACE (d + 1 AS d) FROM t");
+    duckdb().verified_stmt("SELECT t.* RENAME b AS c FROM t");
+}
+
 #[test]
 fn parse_div_infix() {
     duckdb_and_generic().verified_stmt(r#"SELECT 5 // 2"#);
@@ -177,7 +194,6 @@ fn test_select_union_by_name() {
                 sort_by: vec![],
                 having: None,
                 named_window: vec![],
-                window_before_qualify: false,
                 qualify: None,
                 value_table_mode: None,
                 connect_by: None,
@@ -215,7 +231,6 @@ fn test_select_union_by_name() {
                 sort_by: vec![],
                 having: None,
                 named_window: vec![],
-                window_before_qualify: false,
                 qualify: None,
                 value_table_mode: None,
                 connect_by: None,
@@ -509,6 +524,7 @@ fn test_duckdb_named_argument_function_with_assignment_operator() {
                 clauses: vec![],
             }),
             null_treatment: None,
+            nth_value_order: None,
             filter: None,
             over: None,
             within_group: vec![],
@@ -544,3 +560,40 @@ fn test_array_index() {
         expr
     );
 }
+
+#[test]
+fn test_composite_access() {
+    let select = duckdb_and_generic().verified_only_select("SELECT arr[1].name FROM t");
+    assert_eq!(
+        &Expr::CompositeAccess {
+            expr: Box::new(Expr::Subscript {
+                expr: Box::new(Expr::Identifier(Ident::new("arr"))),
+                subscript: Box::new(Subscript::Index {
+                    index: Expr::Value(number("1")),
+                }),
+            }),
+            key: Ident::new("name"),
+        },
+        expr_from_projection(only(&select.projection))
+    );
+
+    duckdb_and_generic().verified_expr("unnest(x).y");
+    duckdb_and_generic().verified_expr("struct_pack(a := 1).a");
+}
+
+#[test]
+fn parse_pragma() {
+    match duckdb().verified_stmt("PRAGMA table_info('t')") {
+        Statement::Pragma {
+            name,
+            value: Some(value),
+            is_eq: false,
+        } => {
+            assert_eq!("table_info", name.to_string());
+            assert_eq!(Value::SingleQuotedString("t".to_string()), value);
+        }
+        _ => unreachable!(),
+    }
+    duckdb().verified_stmt("PRAGMA enable_progress_bar");
+    duckdb().verified_stmt("PRAGMA threads = 4");
+}
@@ -16,9 +16,9 @@
 //! is also tested (on the inputs it can handle).
 
 use sqlparser::ast::{
-    CreateFunctionBody, CreateFunctionUsing, Expr, Function, FunctionArgumentList,
-    FunctionArguments, Ident, ObjectName, OneOrManyWithParens, SelectItem, Statement, TableFactor,
-    UnaryOperator, Value,
+    BinaryOperator, ContextModifier, CreateFunctionBody, CreateFunctionUsing, CreateTable, Expr,
+    FileFormat, Function, FunctionArgumentList, FunctionArguments, Ident, Insert, ObjectName,
+    OneOrManyWithParens, SelectItem, Statement, TableFactor, UnaryOperator, Value,
 };
 use sqlparser::dialect::{GenericDialect, HiveDialect, MsSqlDialect};
 use sqlparser::parser::{ParserError, ParserOptions};
@@ -35,6 +35,27 @@ fn parse_table_create() {
     hive().verified_stmt(serdeproperties);
 }
 
+#[test]
+fn parse_create_external_table() {
+    hive().verified_stmt("CREATE EXTERNAL TABLE t (a INT, b STRING) PARTITIONED BY (d STRING) STORED AS PARQUET LOCATION 's3://bucket/t'");
+    hive().verified_stmt("CREATE EXTERNAL TABLE t (a INT) ROW FORMAT DELIMITED FIELDS TERMINATED BY ',' STORED AS TEXTFILE LOCATION '/data/t'");
+    hive().verified_stmt("CREATE EXTERNAL TABLE t (a INT) ROW FORMAT SERDE 'org.apache.hadoop.hive.ql.io.orc.OrcSerde' STORED AS INPUTFORMAT 'org.apache.hadoop.hive.ql.io.orc.OrcInputFormat' OUTPUTFORMAT 'org.apache.hadoop.hive.ql.io.orc.OrcOutputFormat' LOCATION '/data/t' TBLPROPERTIES ('orc.compress' = 'ZLIB')");
+
+    match hive().verified_stmt("CREATE EXTERNAL TABLE t (a INT) STORED AS ORC") {
+        Statement::CreateTable(CreateTable {
+            external,
+            file_format,
+            location,
+            ..
+        }) => {
+            assert!(external);
+            assert_eq!(file_format, Some(FileFormat::ORC));
+            assert_eq!(location, None);
+        }
+        _ => unreachable!(),
+    }
+}
+
 fn generic(options: Option<ParserOptions>) -> TestedDialects {
     TestedDialects {
         dialects: vec![Box::new(GenericDialect {})],
@@ -63,12 +84,47 @@ fn explain_describe_extended() {
 fn parse_insert_overwrite() {
     let insert_partitions = r#"INSERT OVERWRITE TABLE db.new_table PARTITION (a = '1', b) SELECT a, b, c FROM db.table"#;
     hive().verified_stmt(insert_partitions);
+
+    match hive().verified_stmt(
+        "INSERT OVERWRITE TABLE t PARTITION (dt = '2023-01-01', hr) SELECT a, hr FROM s",
+    ) {
+        Statement::Insert(Insert {
+            overwrite,
+            table,
+            partitioned,
+            ..
+        }) => {
+            assert!(overwrite);
+            assert!(table);
+            assert_eq!(
+                partitioned,
+                Some(vec![
+                    Expr::BinaryOp {
+                        left: Box::new(Expr::Identifier(Ident::new("dt"))),
+                        op: BinaryOperator::Eq,
+                        right: Box::new(Expr::Value(Value::SingleQuotedString(
+                            "2023-01-01".to_string()
+                        ))),
+                    },
+                    Expr::Identifier(Ident::new("hr")),
+                ])
+            );
+        }
+        _ => unreachable!(),
+    }
+
+    hive().verified_stmt("INSERT OVERWRITE TABLE t PARTITION (dt) SELECT a, dt FROM s");
+    hive().verified_stmt("INSERT INTO TABLE t PARTITION (dt = 1) (a, b) VALUES (1, 2)");
+    hive().verified_stmt("INSERT OVERWRITE t SELECT a FROM s");
 }
 
 #[test]
 fn test_truncate() {
     let truncate = r#"TRUNCATE TABLE db.table"#;
     hive().verified_stmt(truncate);
+
+    let truncate = r#"TRUNCATE TABLE db.table PARTITION (ds = '2024-01-01', hr)"#;
+    hive().verified_stmt(truncate);
 }
 
 #[test]
@@ -266,8 +322,9 @@ fn set_statement_with_minus() {
     assert_eq!(
         hive().verified_stmt("SET hive.tez.java.opts = -Xmx4g"),
         Statement::SetVariable {
-            local: false,
+            scope: ContextModifier::None,
             hivevar: false,
+            to_keyword: false,
             variables: OneOrManyWithParens::One(ObjectName(vec![
                 Ident::new("hive"),
                 Ident::new("tez"),
@@ -387,6 +444,7 @@ fn parse_delimited_identifiers() {
                 clauses: vec![],
             }),
             null_treatment: None,
+            nth_value_order: None,
             filter: None,
             over: None,
             within_group: vec![],
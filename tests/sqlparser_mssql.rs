@@ -73,6 +73,59 @@ fn parse_table_time_travel() {
     assert!(ms().parse_sql_statements(&sql).is_err());
 }
 
+#[test]
+fn parse_table_time_travel_ranges() {
+    let start = Expr::Value(Value::SingleQuotedString("2023-01-01".to_string()));
+    let end = Expr::Value(Value::SingleQuotedString("2024-01-01".to_string()));
+    for (sql, expected) in [
+        (
+            "SELECT 1 FROM t1 FOR SYSTEM_TIME BETWEEN '2023-01-01' AND '2024-01-01'",
+            TableVersion::ForSystemTimeBetween(start.clone(), end.clone()),
+        ),
+        (
+            "SELECT 1 FROM t1 FOR SYSTEM_TIME FROM '2023-01-01' TO '2024-01-01'",
+            TableVersion::ForSystemTimeFromTo(start.clone(), end.clone()),
+        ),
+        (
+            "SELECT 1 FROM t1 FOR SYSTEM_TIME CONTAINED IN ('2023-01-01', '2024-01-01')",
+            TableVersion::ForSystemTimeContainedIn(start.clone(), end.clone()),
+        ),
+        (
+            "SELECT 1 FROM t1 FOR SYSTEM_TIME ALL",
+            TableVersion::ForSystemTimeAll,
+        ),
+    ] {
+        let select = ms_and_generic().verified_only_select(sql);
+        match &select.from[0].relation {
+            TableFactor::Table { version, .. } => assert_eq!(&Some(expected), version),
+            _ => unreachable!(),
+        }
+    }
+
+    ms().verified_only_select("SELECT 1 FROM t1 FOR SYSTEM_TIME ALL AS t WHERE t.a = 1");
+
+    // the version follows the arguments of a table-valued function
+    match ms_and_generic().verified_stmt("SELECT 1 FROM f(1) FOR SYSTEM_TIME ALL AS t") {
+        Statement::Query(query) => match &query.body.as_select().unwrap().from[0].relation {
+            TableFactor::Table { args, version, .. } => {
+                assert_eq!(1, args.as_ref().unwrap().len());
+                assert_eq!(&Some(TableVersion::ForSystemTimeAll), version);
+            }
+            _ => unreachable!(),
+        },
+        _ => unreachable!(),
+    }
+
+    let res = ms().parse_sql_statements("SELECT 1 FROM t1 FOR SYSTEM_TIME LATEST");
+    assert_eq!(
+        ParserError::ParserError(
+            "Expected AS OF, BETWEEN, FROM, CONTAINED IN or ALL after FOR SYSTEM_TIME, found: LATEST"
+                .to_string()
+        ),
+        res.unwrap_err()
+    );
+}
+
 #[test]
 fn parse_mssql_single_quoted_aliases() {
     let _ = ms_and_generic().one_statement_parses_to("SELECT foo 'alias'", "SELECT foo AS 'alias'");
@@ -84,6 +137,26 @@ fn parse_mssql_delimited_identifiers() {
         "SELECT [a.b!] [FROM] FROM foo [WHERE]",
         "SELECT [a.b!] AS [FROM] FROM foo AS [WHERE]",
     );
+
+    let select = ms().verified_only_select("SELECT [Order Details].[a]]b] FROM [Order Details]");
+    assert_eq!(
+        &Expr::CompoundIdentifier(vec![
+            Ident::with_quote('[', "Order Details"),
+            Ident::with_quote('[', "a]b"),
+        ]),
+        expr_from_projection(only(&select.projection))
+    );
+    assert_eq!(
+        TableFactor::Table {
+            name: ObjectName(vec![Ident::with_quote('[', "Order Details")]),
+            alias: None,
+            args: None,
+            with_hints: vec![],
+            version: None,
+            partitions: vec![],
+        },
+        only(select.from).relation
+    );
 }
 
 #[test]
@@ -117,7 +190,6 @@ fn parse_create_procedure() {
                     sort_by: vec![],
                     having: None,
                     named_window: vec![],
-                    window_before_qualify: false,
                     qualify: None,
                     value_table_mode: None,
                     connect_by: None,
@@ -360,6 +432,7 @@ fn parse_delimited_identifiers() {
                 clauses: vec![],
             }),
             null_treatment: None,
+            nth_value_order: None,
             filter: None,
             over: None,
             within_group: vec![],
@@ -534,7 +607,6 @@ fn parse_substring_in_select() {
                         having: None,
                         named_window: vec![],
                         qualify: None,
-                        window_before_qualify: false,
                         value_table_mode: None,
                         connect_by: None,
                     }))),
@@ -611,6 +683,54 @@ fn parse_mssql_declare() {
     );
 }
 
+#[test]
+fn parse_full_text_predicates() {
+    let select = ms().verified_only_select("SELECT * FROM t WHERE CONTAINS(t.col, 'term')");
+    assert_eq!(
+        Expr::FullTextPredicate {
+            kind: FullTextPredicateKind::Contains,
+            columns: vec![ObjectName(vec![Ident::new("t"), Ident::new("col")])],
+            search_condition: Box::new(Expr::Value(SingleQuotedString("term".to_string()))),
+            language: None,
+        },
+        select.selection.unwrap()
+    );
+
+    let select =
+        ms().verified_only_select("SELECT * FROM t WHERE FREETEXT((a, b), @phrase, LANGUAGE 1033)");
+    assert_eq!(
+        Expr::FullTextPredicate {
+            kind: FullTextPredicateKind::Freetext,
+            columns: vec![
+                ObjectName(vec![Ident::new("a")]),
+                ObjectName(vec![Ident::new("b")]),
+            ],
+            search_condition: Box::new(Expr::Identifier(Ident::new("@phrase"))),
+            language: Some(Box::new(Expr::Value(number("1033")))),
+        },
+        select.selection.unwrap()
+    );
+
+    let select = ms().verified_only_select("SELECT * FROM t WHERE CONTAINS(*, '\"x*\"') AND y = 1");
+    match select.selection.unwrap() {
+        Expr::BinaryOp { left, .. } => match *left {
+            Expr::FullTextPredicate { columns, .. } => assert!(columns.is_empty()),
+            _ => unreachable!(),
+        },
+        _ => unreachable!(),
+    }
+
+    // Other dialects keep treating these as ordinary functions
+    let dialects = TestedDialects {
+        dialects: vec![Box::new(GenericDialect {})],
+        options: None,
+    };
+    assert!(matches!(
+        dialects.verified_expr("CONTAINS(col, 'term')"),
+        Expr::Function(_)
+    ));
+}
+
 fn ms() -> TestedDialects {
     TestedDialects {
         dialects: vec![Box::new(MsSqlDialect {})],
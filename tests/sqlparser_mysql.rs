@@ -378,6 +378,29 @@ fn parse_show_tables() {
     mysql_and_generic().one_statement_parses_to("SHOW TABLES IN mydb", "SHOW TABLES FROM mydb");
 }
 
+#[test]
+fn parse_show_databases() {
+    assert_eq!(
+        mysql_and_generic().verified_stmt("SHOW DATABASES"),
+        Statement::ShowDatabases { filter: None }
+    );
+    assert_eq!(
+        mysql_and_generic().verified_stmt("SHOW DATABASES LIKE 'a%'"),
+        Statement::ShowDatabases {
+            filter: Some(ShowStatementFilter::Like("a%".into())),
+        }
+    );
+    assert_eq!(
+        mysql_and_generic().verified_stmt("SHOW DATABASES WHERE 1 = 2"),
+        Statement::ShowDatabases {
+            filter: Some(ShowStatementFilter::Where(
+                mysql_and_generic().verified_expr("1 = 2")
+            )),
+        }
+    );
+    mysql_and_generic().verified_stmt("SHOW TABLES FROM db LIKE 'a%'");
+}
+
 #[test]
 fn parse_show_extended_full() {
     assert!(mysql_and_generic()
@@ -446,9 +469,17 @@ fn parse_show_collation() {
 fn parse_use() {
     assert_eq!(
         mysql_and_generic().verified_stmt("USE mydb"),
-        Statement::Use {
-            db_name: Ident::new("mydb")
-        }
+        Statement::Use(Use::Object(ObjectName(vec![Ident::new("mydb")])))
+    );
+    assert_eq!(
+        mysql_and_generic().verified_stmt("USE `mydb`"),
+        Statement::Use(Use::Object(ObjectName(vec![Ident::with_quote(
+            '`', "mydb"
+        )])))
+    );
+    assert_eq!(
+        mysql_and_generic().verified_stmt("USE DATABASE mydb"),
+        Statement::Use(Use::Database(ObjectName(vec![Ident::new("mydb")])))
     );
 }
 
@@ -458,12 +489,25 @@ fn parse_set_variables() {
     assert_eq!(
         mysql_and_generic().verified_stmt("SET LOCAL autocommit = 1"),
         Statement::SetVariable {
-            local: true,
+            scope: ContextModifier::Local,
             hivevar: false,
+            to_keyword: false,
             variables: OneOrManyWithParens::One(ObjectName(vec!["autocommit".into()])),
             value: vec![Expr::Value(number("1"))],
         }
     );
+    assert_eq!(
+        mysql_and_generic().verified_stmt("SET GLOBAL sql_mode = 'ANSI'"),
+        Statement::SetVariable {
+            scope: ContextModifier::Global,
+            hivevar: false,
+            variables: OneOrManyWithParens::One(ObjectName(vec!["sql_mode".into()])),
+            value: vec![Expr::Value(Value::SingleQuotedString("ANSI".into()))],
+            to_keyword: false,
+        }
+    );
+    mysql_and_generic().verified_stmt("SET SESSION sql_mode = 'ANSI'");
+    mysql_and_generic().verified_stmt("SET @var = 1");
 }
 
 #[test]
@@ -674,6 +718,27 @@ fn parse_create_table_primary_and_unique_key_with_index_type() {
     mysql_and_generic().verified_stmt(sql);
 }
 
+#[test]
+fn parse_create_table_primary_key_using_btree() {
+    let sql = "CREATE TABLE foo (id INT, PRIMARY KEY (id) USING BTREE)";
+    match mysql_and_generic().verified_stmt(sql) {
+        Statement::CreateTable(CreateTable { constraints, .. }) => {
+            assert_eq!(
+                vec![TableConstraint::PrimaryKey {
+                    name: None,
+                    index_name: None,
+                    index_type: None,
+                    columns: vec![Ident::new("id")],
+                    index_options: vec![IndexOption::Using(IndexType::BTree)],
+                    characteristics: None,
+                }],
+                constraints
+            );
+        }
+        _ => unreachable!(),
+    }
+}
+
 #[test]
 fn parse_create_table_primary_and_unique_key_characteristic_test() {
     let sqls = ["UNIQUE INDEX", "PRIMARY KEY"]
@@ -699,6 +764,35 @@ fn parse_create_table_comment() {
     }
 }
 
+#[test]
+fn parse_create_table_like() {
+    let src = CreateTableLike {
+        name: ObjectName(vec!["src".into()]),
+        options: vec![],
+    };
+    for (sql, expected) in [
+        (
+            "CREATE TABLE copy LIKE src",
+            CreateTableLikeKind::Plain(src.clone()),
+        ),
+        (
+            "CREATE TABLE copy (LIKE src)",
+            CreateTableLikeKind::Parenthesized(vec![CreateTableLikeElement {
+                column_index: 0,
+                like: src.clone(),
+            }]),
+        ),
+    ] {
+        match mysql_and_generic().verified_stmt(sql) {
+            Statement::CreateTable(CreateTable { name, like, .. }) => {
+                assert_eq!("copy", name.to_string());
+                assert_eq!(Some(expected), like);
+            }
+            _ => unreachable!(),
+        }
+    }
+}
+
 #[test]
 fn parse_create_table_auto_increment_offset() {
     let canonical =
@@ -857,6 +951,29 @@ fn parse_create_table_gencol() {
     mysql_and_generic().verified_stmt("CREATE TABLE t1 (a INT, b INT AS (a * 2))");
     mysql_and_generic().verified_stmt("CREATE TABLE t1 (a INT, b INT AS (a * 2) VIRTUAL)");
     mysql_and_generic().verified_stmt("CREATE TABLE t1 (a INT, b INT AS (a * 2) STORED)");
+
+    match mysql_and_generic().verified_stmt("CREATE TABLE t1 (total INT AS (price * qty) STORED)") {
+        Statement::CreateTable(CreateTable { columns, .. }) => {
+            assert_eq!(
+                vec![ColumnOptionDef {
+                    name: None,
+                    option: ColumnOption::Generated {
+                        generated_as: GeneratedAs::ExpStored,
+                        sequence_options: None,
+                        generation_expr: Some(Expr::BinaryOp {
+                            left: Box::new(Expr::Identifier(Ident::new("price"))),
+                            op: BinaryOperator::Multiply,
+                            right: Box::new(Expr::Identifier(Ident::new("qty"))),
+                        }),
+                        generation_expr_mode: Some(GeneratedExpressionMode::Stored),
+                        generated_keyword: false,
+                    },
+                }],
+                columns[0].options
+            );
+        }
+        _ => unreachable!(),
+    }
 }
 
 #[test]
@@ -914,7 +1031,6 @@ fn parse_escaped_quote_identifiers_with_escape() {
                 having: None,
                 named_window: vec![],
                 qualify: None,
-                window_before_qualify: false,
                 value_table_mode: None,
                 connect_by: None,
             }))),
@@ -961,7 +1077,6 @@ fn parse_escaped_quote_identifiers_with_no_escape() {
                 having: None,
                 named_window: vec![],
                 qualify: None,
-                window_before_qualify: false,
                 value_table_mode: None,
                 connect_by: None,
             }))),
@@ -1005,7 +1120,6 @@ fn parse_escaped_backticks_with_escape() {
                 having: None,
                 named_window: vec![],
                 qualify: None,
-                window_before_qualify: false,
                 value_table_mode: None,
                 connect_by: None,
             }))),
@@ -1049,7 +1163,6 @@ fn parse_escaped_backticks_with_no_escape() {
                 having: None,
                 named_window: vec![],
                 qualify: None,
-                window_before_qualify: false,
                 value_table_mode: None,
                 connect_by: None,
             }))),
@@ -1128,6 +1241,47 @@ fn check_roundtrip_of_escaped_string() {
     .verified_stmt(r#"SELECT "I'm ''fine''""#);
 }
 
+#[test]
+fn parse_create_table_column_visibility() {
+    let sql = "CREATE TABLE foo (id INT NOT NULL INVISIBLE, bar INT VISIBLE)";
+    match mysql_and_generic().verified_stmt(sql) {
+        Statement::CreateTable(CreateTable { columns, .. }) => {
+            assert_eq!(
+                vec![
+                    ColumnDef {
+                        name: Ident::new("id"),
+                        data_type: DataType::Int(None),
+                        collation: None,
+                        options: vec![
+                            ColumnOptionDef {
+                                name: None,
+                                option: ColumnOption::NotNull,
+                            },
+                            ColumnOptionDef {
+                                name: None,
+                                option: ColumnOption::Visible(false),
+                            },
+                        ],
+                    },
+                    ColumnDef {
+                        name: Ident::new("bar"),
+                        data_type: DataType::Int(None),
+                        collation: None,
+                        options: vec![ColumnOptionDef {
+                            name: None,
+                            option: ColumnOption::Visible(true),
+                        }],
+                    },
+                ],
+                columns
+            );
+        }
+        _ => unreachable!(),
+    }
+
+    mysql().verified_stmt("CREATE TABLE foo (id INT INVISIBLE DEFAULT 1 COMMENT 'hidden')");
+}
+
 #[test]
 fn parse_create_table_with_minimum_display_width() {
     let sql = "CREATE TABLE foo (bar_tinyint TINYINT(3), bar_smallint SMALLINT(5), bar_mediumint MEDIUMINT(6), bar_int INT(11), bar_bigint BIGINT(20))";
@@ -1501,6 +1655,25 @@ fn parse_insert_as() {
     }
 }
 
+#[test]
+fn parse_replace_into_forms() {
+    mysql().verified_stmt("REPLACE INTO t SELECT * FROM s");
+    mysql().verified_stmt("REPLACE t (a) VALUES (1)");
+    match mysql().verified_stmt("REPLACE INTO t SET a = 1, b = 2") {
+        Statement::Insert(Insert {
+            replace_into,
+            assignments,
+            source,
+            ..
+        }) => {
+            assert!(replace_into);
+            assert_eq!(assignments.len(), 2);
+            assert!(source.is_none());
+        }
+        _ => unreachable!(),
+    }
+}
+
 #[test]
 fn parse_replace_insert() {
     let sql = r"REPLACE DELAYED INTO tasks (title, priority) VALUES ('Test Some Inserts', 1)";
@@ -1700,7 +1873,6 @@ fn parse_select_with_numeric_prefix_column_name() {
                     having: None,
                     named_window: vec![],
                     qualify: None,
-                    window_before_qualify: false,
                     value_table_mode: None,
                     connect_by: None,
                 })))
@@ -1753,7 +1925,6 @@ fn parse_select_with_concatenation_of_exp_number_and_numeric_prefix_column() {
                     having: None,
                     named_window: vec![],
                     qualify: None,
-                    window_before_qualify: false,
                     value_table_mode: None,
                     connect_by: None,
                 })))
@@ -1763,6 +1934,41 @@ fn parse_select_with_concatenation_of_exp_number_and_numeric_prefix_column() {
     }
 }
 
+#[test]
+fn parse_insert_set() {
+    let sql = "INSERT INTO tb SET a = 1, b = 'x'";
+    match mysql_and_generic().verified_stmt(sql) {
+        Statement::Insert(Insert {
+            table_name,
+            columns,
+            source,
+            assignments,
+            ..
+        }) => {
+            assert_eq!(ObjectName(vec![Ident::new("tb")]), table_name);
+            assert!(columns.is_empty());
+            assert_eq!(None, source);
+            assert_eq!(
+                vec![
+                    Assignment {
+                        id: vec![Ident::new("a")],
+                        value: Expr::Value(number("1")),
+                    },
+                    Assignment {
+                        id: vec![Ident::new("b")],
+                        value: Expr::Value(Value::SingleQuotedString("x".to_string())),
+                    },
+                ],
+                assignments
+            );
+        }
+        _ => unreachable!(),
+    }
+
+    mysql_and_generic()
+        .verified_stmt("INSERT IGNORE INTO tb SET a = 1 ON DUPLICATE KEY UPDATE a = a + 1");
+}
+
 #[test]
 fn parse_insert_with_numeric_prefix_column_name() {
     let sql = "INSERT INTO s1.t1 (123col_$@length123) VALUES (67.654)";
@@ -1792,6 +1998,7 @@ fn parse_update_with_joins() {
             from: _from,
             selection,
             returning,
+            returning_into,
         } => {
             assert_eq!(
                 TableWithJoins {
@@ -1852,11 +2059,45 @@ fn parse_update_with_joins() {
                 selection
             );
             assert_eq!(None, returning);
+            assert_eq!(None, returning_into);
         }
         _ => unreachable!(),
     }
 }
 
+#[test]
+fn parse_delete_multiple_tables() {
+    let sql = "DELETE a, b FROM a JOIN b ON a.id = b.id WHERE a.x = 1";
+    match mysql_and_generic().verified_stmt(sql) {
+        Statement::Delete(Delete {
+            tables,
+            from: FromTable::WithFromKeyword(from),
+            selection,
+            ..
+        }) => {
+            assert_eq!(
+                vec![
+                    ObjectName(vec![Ident::new("a")]),
+                    ObjectName(vec![Ident::new("b")])
+                ],
+                tables
+            );
+            assert_eq!(1, from.len());
+            assert_eq!(table("a"), from[0].relation);
+            assert_eq!(table("b"), from[0].joins[0].relation);
+            assert_eq!(
+                Some(mysql_and_generic().verified_expr("a.x = 1")),
+                selection
+            );
+        }
+        _ => unreachable!(),
+    }
+
+    mysql_and_generic().verified_stmt("DELETE a FROM a JOIN b ON a.id = b.id");
+    // without targets, the tables to delete from follow FROM
+    mysql_and_generic().verified_stmt("DELETE FROM a, b USING a JOIN b ON a.id = b.id");
+}
+
 #[test]
 fn parse_delete_with_order_by() {
     let sql = "DELETE FROM customers ORDER BY id DESC";
@@ -2248,7 +2489,6 @@ fn parse_substring_in_select() {
                         sort_by: vec![],
                         having: None,
                         named_window: vec![],
-                        window_before_qualify: false,
                         qualify: None,
                         value_table_mode: None,
                         connect_by: None,
@@ -2352,7 +2592,7 @@ fn parse_set_names() {
     assert_eq!(
         stmt,
         Statement::SetNames {
-            charset_name: "utf8mb4".to_string(),
+            charset_name: Ident::new("utf8mb4"),
             collation_name: None,
         }
     );
@@ -2361,8 +2601,8 @@ fn parse_set_names() {
     assert_eq!(
         stmt,
         Statement::SetNames {
-            charset_name: "utf8mb4".to_string(),
-            collation_name: Some("bogus".to_string()),
+            charset_name: Ident::new("utf8mb4"),
+            collation_name: Some(Ident::new("bogus")),
         }
     );
 
@@ -2372,11 +2612,21 @@ fn parse_set_names() {
     assert_eq!(
         stmt,
         vec![Statement::SetNames {
-            charset_name: "utf8mb4".to_string(),
-            collation_name: Some("bogus".to_string()),
+            charset_name: Ident::new("utf8mb4"),
+            collation_name: Some(Ident::new("bogus")),
         }]
     );
 
+    mysql_and_generic().verified_stmt("SET NAMES 'utf8mb4' COLLATE bogus");
+    mysql_and_generic().verified_stmt("SET NAMES binary");
+    assert_eq!(
+        mysql_and_generic().verified_stmt("SET NAMES `utf8mb4` COLLATE 'utf8mb4_bin'"),
+        Statement::SetNames {
+            charset_name: Ident::with_quote('`', "utf8mb4"),
+            collation_name: Some(Ident::with_quote('\'', "utf8mb4_bin")),
+        }
+    );
+
     let stmt = mysql_and_generic().verified_stmt("SET NAMES DEFAULT");
     assert_eq!(stmt, Statement::SetNamesDefault {});
 }
@@ -2530,6 +2780,55 @@ fn parse_values() {
     mysql().verified_stmt("SELECT a, c FROM (VALUES ROW(1, true, 'a'), ROW(2, false, 'b'), ROW(3, false, 'c')) AS t (a, b, c)");
 }
 
+#[test]
+fn parse_pipes_as_or() {
+    // `||` is a logical OR binding looser than AND
+    assert_eq!(
+        Expr::BinaryOp {
+            left: Box::new(Expr::Identifier(Ident::new("a"))),
+            op: BinaryOperator::PipesOr,
+            right: Box::new(Expr::BinaryOp {
+                left: Box::new(Expr::Identifier(Ident::new("b"))),
+                op: BinaryOperator::And,
+                right: Box::new(Expr::Identifier(Ident::new("c"))),
+            }),
+        },
+        mysql().verified_expr("a || b AND c")
+    );
+    mysql().verified_stmt("SELECT * FROM t WHERE a = 1 || b = 2");
+}
+
+#[test]
+fn parse_bit_value_literal() {
+    let select = mysql().verified_only_select("SELECT 0b1010, 0b111111111");
+    assert_eq!(
+        vec![
+            SelectItem::UnnamedExpr(Expr::Value(Value::BitValueLiteral("1010".to_string()))),
+            SelectItem::UnnamedExpr(Expr::Value(Value::BitValueLiteral("111111111".to_string()))),
+        ],
+        select.projection
+    );
+
+    // Anything else starting with `0b` is an identifier with a numeric prefix
+    mysql().verified_only_select("SELECT 0b12 FROM t");
+}
+
+#[test]
+#[cfg(not(feature = "bigdecimal"))]
+fn parse_number_with_underscores() {
+    let select = mysql().verified_only_select("SELECT 1_000_000, 1_000.5");
+    assert_eq!(
+        vec![
+            SelectItem::UnnamedExpr(Expr::Value(number("1_000_000"))),
+            SelectItem::UnnamedExpr(Expr::Value(number("1_000.5"))),
+        ],
+        select.projection
+    );
+
+    // An underscore that can't be a separator still gives a numeric prefix
+    mysql().verified_only_select("SELECT 1_a FROM t");
+}
+
 #[test]
 fn parse_hex_string_introducer() {
     assert_eq!(
@@ -2552,7 +2851,6 @@ fn parse_hex_string_introducer() {
                 sort_by: vec![],
                 having: None,
                 named_window: vec![],
-                window_before_qualify: false,
                 qualify: None,
                 value_table_mode: None,
                 into: None,
@@ -2722,4 +3020,102 @@ fn test_group_concat() {
     mysql_and_generic().verified_expr("GROUP_CONCAT(test_score SEPARATOR ' ')");
     mysql_and_generic()
         .verified_expr("GROUP_CONCAT(DISTINCT test_score ORDER BY test_score DESC SEPARATOR ' ')");
+
+    // the separator is kept as a clause rather than a second argument
+    match mysql().verified_expr("GROUP_CONCAT(test_score SEPARATOR ', ')") {
+        Expr::Function(Function {
+            args: FunctionArguments::List(FunctionArgumentList { args, clauses, .. }),
+            ..
+        }) => {
+            assert_eq!(
+                args,
+                vec![FunctionArg::Unnamed(FunctionArgExpr::Expr(
+                    Expr::Identifier(Ident::new("test_score"))
+                ))]
+            );
+            assert_eq!(
+                clauses,
+                vec![FunctionArgumentClause::Separator(
+                    Value::SingleQuotedString(", ".to_string())
+                )]
+            );
+        }
+        _ => unreachable!(),
+    }
+
+    // the comma-separated `STRING_AGG` form is left as a plain call
+    mysql().verified_expr("STRING_AGG(test_score, ', ')");
+}
+
+#[test]
+fn parse_truncate_without_table_keyword() {
+    mysql().verified_stmt("TRUNCATE t");
+}
+
+#[test]
+fn parse_truncate_partition() {
+    match mysql_and_generic().verified_stmt("TRUNCATE TABLE t PARTITION (p1, p2)") {
+        Statement::Truncate {
+            table_names,
+            partitions,
+            table,
+            ..
+        } => {
+            assert_eq!(
+                vec![TruncateTableTarget {
+                    only: false,
+                    name: ObjectName(vec![Ident::new("t")]),
+                    descendants: false,
+                }],
+                table_names
+            );
+            assert_eq!(
+                Some(vec![
+                    Expr::Identifier(Ident::new("p1")),
+                    Expr::Identifier(Ident::new("p2")),
+                ]),
+                partitions
+            );
+            assert!(table);
+        }
+        _ => unreachable!(),
+    }
+}
+
+#[test]
+fn parse_hash_comment() {
+    mysql().one_statement_parses_to("SELECT 1 # comment", "SELECT 1");
+    mysql().one_statement_parses_to("SELECT 1, # first\n2", "SELECT 1, 2");
+}
+
+#[test]
+fn parse_alter_table_multiple_operations() {
+    let sql = "ALTER TABLE tab ADD COLUMN a INT, DROP COLUMN b, RENAME COLUMN c TO d";
+    let operations = match mysql_and_generic().verified_stmt(sql) {
+        Statement::AlterTable { operations, .. } => operations,
+        _ => unreachable!(),
+    };
+    assert_eq!(operations.len(), 3);
+    assert_matches!(
+        &operations[0],
+        AlterTableOperation::AddColumn { column_def, .. } if column_def.name.value == "a"
+    );
+    assert_eq!(
+        operations[1],
+        AlterTableOperation::DropColumn {
+            column_name: "b".into(),
+            if_exists: false,
+            cascade: false,
+        }
+    );
+    assert_eq!(
+        operations[2],
+        AlterTableOperation::RenameColumn {
+            old_column_name: "c".into(),
+            new_column_name: "d".into(),
+        }
+    );
+
+    // a single operation is displayed without a trailing separator
+    mysql_and_generic().verified_stmt("ALTER TABLE tab DROP COLUMN b");
 }
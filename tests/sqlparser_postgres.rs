@@ -218,6 +218,43 @@ fn parse_create_table_generated_always_as_identity() {
     );
 }
 
+#[test]
+fn parse_create_table_identity_sequence_options() {
+    let sql =
+        "CREATE TABLE t (id INT GENERATED BY DEFAULT AS IDENTITY (START WITH 1 INCREMENT BY -1))";
+    let canonical =
+        "CREATE TABLE t (id INT GENERATED BY DEFAULT AS IDENTITY ( START WITH 1 INCREMENT BY -1 ))";
+    match pg().one_statement_parses_to(sql, canonical) {
+        Statement::CreateTable(CreateTable { columns, .. }) => {
+            assert_eq!(
+                columns[0].options[0].option,
+                ColumnOption::Generated {
+                    generated_as: GeneratedAs::ByDefault,
+                    sequence_options: Some(vec![
+                        SequenceOptions::StartWith(Expr::Value(number("1")), true),
+                        SequenceOptions::IncrementBy(
+                            Expr::UnaryOp {
+                                op: UnaryOperator::Minus,
+                                expr: Box::new(Expr::Value(number("1"))),
+                            },
+                            true
+                        ),
+                    ]),
+                    generation_expr: None,
+                    generation_expr_mode: None,
+                    generated_keyword: true,
+                }
+            );
+        }
+        _ => unreachable!(),
+    }
+
+    pg().verified_stmt("CREATE TABLE t (id INT GENERATED ALWAYS AS IDENTITY)");
+    pg().verified_stmt(
+        "CREATE TABLE t (id INT GENERATED ALWAYS AS IDENTITY ( CYCLE MAXVALUE 10 MINVALUE -10 ))",
+    );
+}
+
 #[test]
 fn parse_create_sequence() {
     // SimpleLogger::new().init().unwrap();
@@ -281,6 +318,36 @@ fn parse_create_sequence() {
         pg().parse_sql_statements("CREATE SEQUENCE foo INCREMENT 1 NO MINVALUE NO"),
         Err(ParserError::ParserError(_))
     ));
+
+    match pg().verified_stmt(
+        "CREATE SEQUENCE s START WITH 100 INCREMENT BY 2 MINVALUE 1 MAXVALUE 1000 CYCLE OWNED BY t.c",
+    ) {
+        Statement::CreateSequence {
+            temporary: false,
+            if_not_exists: false,
+            name,
+            data_type: None,
+            sequence_options,
+            owned_by,
+        } => {
+            assert_eq!("s", name.to_string());
+            assert_eq!(
+                vec![
+                    SequenceOptions::StartWith(Expr::Value(number("100")), true),
+                    SequenceOptions::IncrementBy(Expr::Value(number("2")), true),
+                    SequenceOptions::MinValue(Some(Expr::Value(number("1")))),
+                    SequenceOptions::MaxValue(Some(Expr::Value(number("1000")))),
+                    SequenceOptions::Cycle(false),
+                ],
+                sequence_options
+            );
+            assert_eq!(Some(ObjectName(vec!["t".into(), "c".into()])), owned_by);
+        }
+        _ => unreachable!(),
+    }
+
+    pg().verified_stmt("CREATE SEQUENCE s AS BIGINT NO MINVALUE NO MAXVALUE NO CYCLE");
+    pg().verified_stmt("CREATE SEQUENCE s MINVALUE -5 START WITH -5 INCREMENT BY -1");
 }
 
 #[test]
@@ -554,6 +621,248 @@ fn parse_create_table_constraints_only() {
     };
 }
 
+#[test]
+fn parse_create_table_as_with_column_names_and_data() {
+    match pg_and_generic().verified_stmt("CREATE TABLE t (a, b) AS SELECT 1, 2 WITH NO DATA") {
+        Statement::CreateTable(CreateTable {
+            columns,
+            column_names,
+            query,
+            with_data,
+            ..
+        }) => {
+            assert!(columns.is_empty());
+            assert_eq!(vec![Ident::new("a"), Ident::new("b")], column_names);
+            assert_eq!("SELECT 1, 2", query.unwrap().to_string());
+            assert_eq!(Some(false), with_data);
+        }
+        _ => unreachable!(),
+    }
+
+    match pg_and_generic().verified_stmt("CREATE TABLE t AS SELECT 1 WITH DATA") {
+        Statement::CreateTable(CreateTable {
+            column_names,
+            with_data,
+            ..
+        }) => {
+            assert!(column_names.is_empty());
+            assert_eq!(Some(true), with_data);
+        }
+        _ => unreachable!(),
+    }
+
+    pg_and_generic().verified_stmt("CREATE TABLE t (a, b) AS SELECT 1, 2");
+    pg_and_generic()
+        .verified_stmt("CREATE TABLE t (a, b) WITH (fillfactor = 70) AS SELECT 1, 2 WITH NO DATA");
+    // a column list with types is still a schema
+    match pg_and_generic().verified_stmt("CREATE TABLE t (a INT) AS SELECT 1") {
+        Statement::CreateTable(CreateTable {
+            columns,
+            column_names,
+            with_data: None,
+            ..
+        }) => {
+            assert_eq!(1, columns.len());
+            assert!(column_names.is_empty());
+        }
+        _ => unreachable!(),
+    }
+}
+
+#[test]
+fn parse_create_table_like() {
+    match pg_and_generic()
+        .verified_stmt("CREATE TABLE copy (LIKE src INCLUDING DEFAULTS EXCLUDING INDEXES)")
+    {
+        Statement::CreateTable(CreateTable { columns, like, .. }) => {
+            assert!(columns.is_empty());
+            assert_eq!(
+                Some(CreateTableLikeKind::Parenthesized(vec![
+                    CreateTableLikeElement {
+                        column_index: 0,
+                        like: CreateTableLike {
+                            name: ObjectName(vec!["src".into()]),
+                            options: vec![
+                                CreateTableLikeOption {
+                                    including: true,
+                                    property: "DEFAULTS".into(),
+                                },
+                                CreateTableLikeOption {
+                                    including: false,
+                                    property: "INDEXES".into(),
+                                },
+                            ],
+                        },
+                    }
+                ])),
+                like
+            );
+        }
+        _ => unreachable!(),
+    }
+
+    match pg_and_generic().verified_stmt("CREATE TABLE copy (id INT, LIKE src, extra INT)") {
+        Statement::CreateTable(CreateTable { columns, like, .. }) => {
+            assert_eq!(2, columns.len());
+            assert_eq!(
+                Some(CreateTableLikeKind::Parenthesized(vec![
+                    CreateTableLikeElement {
+                        column_index: 1,
+                        like: CreateTableLike {
+                            name: ObjectName(vec!["src".into()]),
+                            options: vec![],
+                        },
+                    }
+                ])),
+                like
+            );
+        }
+        _ => unreachable!(),
+    }
+
+    pg_and_generic().verified_stmt("CREATE TABLE copy (LIKE s.src INCLUDING ALL)");
+    pg_and_generic().verified_stmt("CREATE TABLE copy (LIKE src)");
+    pg_and_generic().verified_stmt("CREATE TABLE copy (LIKE src INCLUDING ALL, extra INT)");
+    pg_and_generic().verified_stmt("CREATE TABLE copy (id INT, LIKE src)");
+    pg_and_generic().verified_stmt("CREATE TABLE copy (LIKE a, LIKE b EXCLUDING ALL, c INT)");
+    pg_and_generic()
+        .verified_stmt("CREATE TABLE copy (LIKE src, id INT, CONSTRAINT pk PRIMARY KEY (id))");
+    // a quoted `"LIKE"` is still a column name
+    pg_and_generic().verified_stmt(r#"CREATE TABLE copy ("LIKE" INT)"#);
+}
+
+#[test]
+fn parse_create_table_inherits_and_partition_by() {
+    match pg_and_generic()
+        .verified_stmt("CREATE TABLE c (a INT) INHERITS (p1, s.p2) WITH (fillfactor = 70)")
+    {
+        Statement::CreateTable(CreateTable {
+            inherits,
+            partitioning,
+            with_options,
+            ..
+        }) => {
+            assert_eq!(
+                Some(vec![
+                    ObjectName(vec!["p1".into()]),
+                    ObjectName(vec!["s".into(), "p2".into()]),
+                ]),
+                inherits
+            );
+            assert_eq!(None, partitioning);
+            assert_eq!("fillfactor = 70", only(with_options).to_string());
+        }
+        _ => unreachable!(),
+    }
+
+    match pg_and_generic().verified_stmt(
+        "CREATE TABLE m (a INT, d DATE) INHERITS (p) PARTITION BY RANGE (d, (a + 1)) WITH (fillfactor = 70)",
+    ) {
+        Statement::CreateTable(CreateTable {
+            inherits,
+            partitioning,
+            with_options,
+            ..
+        }) => {
+            assert_eq!(Some(vec![ObjectName(vec!["p".into()])]), inherits);
+            assert_eq!(
+                Some(TablePartitioning {
+                    method: PartitionMethod::Range,
+                    keys: vec![
+                        Expr::Identifier("d".into()),
+                        Expr::Nested(Box::new(pg().verified_expr("a + 1"))),
+                    ],
+                }),
+                partitioning
+            );
+            assert_eq!(1, with_options.len());
+        }
+        _ => unreachable!(),
+    }
+
+    pg_and_generic().verified_stmt("CREATE TABLE m (a INT) PARTITION BY LIST (a)");
+    pg_and_generic().verified_stmt("CREATE TABLE m (a INT) PARTITION BY HASH (a)");
+    pg_and_generic().verified_stmt(
+        "CREATE TABLE c PARTITION OF p FOR VALUES FROM (1) TO (10) PARTITION BY LIST (b)",
+    );
+}
+
+#[test]
+fn parse_create_table_partition_of() {
+    match pg_and_generic()
+        .verified_stmt("CREATE TABLE child PARTITION OF parent FOR VALUES FROM (1) TO (10)")
+    {
+        Statement::CreateTable(CreateTable {
+            name,
+            columns,
+            partition_of,
+            ..
+        }) => {
+            assert_eq!("child", name.to_string());
+            assert!(columns.is_empty());
+            assert_eq!(
+                Some(PartitionOf {
+                    parent: ObjectName(vec!["parent".into()]),
+                    bound: PartitionBound::FromTo {
+                        from: vec![PartitionRangeValue::Expr(Box::new(Expr::Value(number(
+                            "1"
+                        ))))],
+                        to: vec![PartitionRangeValue::Expr(Box::new(Expr::Value(number(
+                            "10"
+                        ))))],
+                    },
+                }),
+                partition_of
+            );
+        }
+        _ => unreachable!(),
+    }
+
+    match pg_and_generic()
+        .verified_stmt("CREATE TABLE child PARTITION OF parent FOR VALUES IN ('a', 'b')")
+    {
+        Statement::CreateTable(CreateTable { partition_of, .. }) => assert_eq!(
+            Some(PartitionBound::In(vec![
+                Expr::Value(Value::SingleQuotedString("a".to_string())),
+                Expr::Value(Value::SingleQuotedString("b".to_string())),
+            ])),
+            partition_of.map(|p| p.bound)
+        ),
+        _ => unreachable!(),
+    }
+
+    match pg_and_generic().verified_stmt(
+        "CREATE TABLE child PARTITION OF parent FOR VALUES WITH (MODULUS 4, REMAINDER 3)",
+    ) {
+        Statement::CreateTable(CreateTable { partition_of, .. }) => assert_eq!(
+            Some(PartitionBound::WithModulus {
+                modulus: 4,
+                remainder: 3,
+            }),
+            partition_of.map(|p| p.bound)
+        ),
+        _ => unreachable!(),
+    }
+
+    pg_and_generic().verified_stmt(
+        "CREATE TABLE child PARTITION OF parent FOR VALUES FROM (MINVALUE, 1) TO ('2024-01-01', MAXVALUE)",
+    );
+    pg_and_generic().verified_stmt("CREATE TABLE child PARTITION OF parent DEFAULT");
+    pg_and_generic().verified_stmt(
+        "CREATE TABLE IF NOT EXISTS child PARTITION OF parent (CONSTRAINT positive CHECK (a > 0)) FOR VALUES IN (1)",
+    );
+
+    assert_eq!(
+        ParserError::ParserError(
+            "Expected IN, FROM or WITH after FOR VALUES, found: BETWEEN".to_string()
+        ),
+        pg().parse_sql_statements(
+            "CREATE TABLE child PARTITION OF parent FOR VALUES BETWEEN 1 AND 2"
+        )
+        .unwrap_err()
+    );
+}
+
 #[test]
 fn parse_alter_table_constraints_rename() {
     match alter_table_op(
@@ -713,6 +1022,40 @@ fn parse_alter_table_add_columns() {
     }
 }
 
+#[test]
+fn parse_alter_table_mixed_operations() {
+    let sql = "ALTER TABLE tab ADD COLUMN a INT, DROP COLUMN b, ALTER COLUMN c SET NOT NULL";
+    let operations = match pg_and_generic().verified_stmt(sql) {
+        Statement::AlterTable { operations, .. } => operations,
+        _ => unreachable!(),
+    };
+    assert_eq!(
+        operations,
+        vec![
+            AlterTableOperation::AddColumn {
+                column_keyword: true,
+                if_not_exists: false,
+                column_def: ColumnDef {
+                    name: "a".into(),
+                    data_type: DataType::Int(None),
+                    collation: None,
+                    options: vec![],
+                },
+                column_position: None,
+            },
+            AlterTableOperation::DropColumn {
+                column_name: "b".into(),
+                if_exists: false,
+                cascade: false,
+            },
+            AlterTableOperation::AlterColumn {
+                column_name: "c".into(),
+                op: AlterColumnOperation::SetNotNull,
+            },
+        ]
+    );
+}
+
 #[test]
 fn parse_create_table_if_not_exists() {
     let sql = "CREATE TABLE IF NOT EXISTS uk_cities ()";
@@ -822,6 +1165,7 @@ fn test_copy_from() {
             target: CopyTarget::File {
                 filename: "data.csv".to_string(),
             },
+            with: false,
             options: vec![],
             legacy_options: vec![],
             values: vec![],
@@ -840,6 +1184,7 @@ fn test_copy_from() {
             target: CopyTarget::File {
                 filename: "data.csv".to_string(),
             },
+            with: false,
             options: vec![],
             legacy_options: vec![CopyLegacyOption::Delimiter(',')],
             values: vec![],
@@ -858,6 +1203,7 @@ fn test_copy_from() {
             target: CopyTarget::File {
                 filename: "data.csv".to_string(),
             },
+            with: false,
             options: vec![],
             legacy_options: vec![
                 CopyLegacyOption::Delimiter(','),
@@ -882,6 +1228,7 @@ fn test_copy_to() {
             target: CopyTarget::File {
                 filename: "data.csv".to_string(),
             },
+            with: false,
             options: vec![],
             legacy_options: vec![],
             values: vec![],
@@ -900,6 +1247,7 @@ fn test_copy_to() {
             target: CopyTarget::File {
                 filename: "data.csv".to_string(),
             },
+            with: false,
             options: vec![],
             legacy_options: vec![CopyLegacyOption::Delimiter(',')],
             values: vec![],
@@ -918,6 +1266,7 @@ fn test_copy_to() {
             target: CopyTarget::File {
                 filename: "data.csv".to_string(),
             },
+            with: false,
             options: vec![],
             legacy_options: vec![
                 CopyLegacyOption::Delimiter(','),
@@ -959,6 +1308,7 @@ fn parse_copy_from() {
             target: CopyTarget::File {
                 filename: "file.csv".into()
             },
+            with: true,
             options: vec![
                 CopyOption::Format("CSV".into()),
                 CopyOption::Freeze(true),
@@ -966,9 +1316,9 @@ fn parse_copy_from() {
                 CopyOption::Freeze(false),
                 CopyOption::Delimiter(','),
                 CopyOption::Null("".into()),
-                CopyOption::Header(true),
-                CopyOption::Header(true),
-                CopyOption::Header(false),
+                CopyOption::Header(None),
+                CopyOption::Header(Some(true)),
+                CopyOption::Header(Some(false)),
                 CopyOption::Quote('"'),
                 CopyOption::Escape('\\'),
                 CopyOption::ForceQuote(vec!["a".into(), "b".into()]),
@@ -982,6 +1332,69 @@ fn parse_copy_from() {
     );
 }
 
+#[test]
+fn parse_copy_header_match_and_default() {
+    match pg_and_generic()
+        .verified_stmt("COPY t (a, b) FROM 'file.csv' (FORMAT csv, HEADER MATCH, DEFAULT '\\D')")
+    {
+        Statement::Copy { options, .. } => assert_eq!(
+            vec![
+                CopyOption::Format("csv".into()),
+                CopyOption::HeaderMatch,
+                CopyOption::Default("\\D".into()),
+            ],
+            options
+        ),
+        _ => unreachable!(),
+    }
+}
+
+#[test]
+fn parse_copy_with_options() {
+    let sql = "COPY t (a,b) FROM STDIN WITH (FORMAT csv, HEADER true, DELIMITER ',');\n1\t2\n\\.";
+    match &pg_and_generic().parse_sql_statements(sql).unwrap()[..] {
+        [Statement::Copy {
+            target,
+            with,
+            options,
+            ..
+        }] => {
+            assert_eq!(&CopyTarget::Stdin, target);
+            assert!(with);
+            assert_eq!(
+                &vec![
+                    CopyOption::Format("csv".into()),
+                    CopyOption::Header(Some(true)),
+                    CopyOption::Delimiter(','),
+                ],
+                options
+            );
+        }
+        _ => unreachable!(),
+    }
+
+    pg_and_generic()
+        .verified_stmt("COPY t (a, b) FROM 'f' WITH (FORMAT csv, HEADER TRUE, DELIMITER ',')");
+    pg_and_generic().verified_stmt("COPY t TO STDOUT (FORMAT csv, HEADER, FORCE_QUOTE *)");
+
+    match pg_and_generic().verified_stmt("COPY t TO STDOUT WITH CSV HEADER") {
+        Statement::Copy {
+            with,
+            options,
+            legacy_options,
+            ..
+        } => {
+            assert!(with);
+            assert!(options.is_empty());
+            assert_eq!(
+                vec![CopyLegacyOption::Csv(vec![CopyLegacyCsvOption::Header])],
+                legacy_options
+            );
+        }
+        _ => unreachable!(),
+    }
+}
+
 #[test]
 fn parse_copy_from_error() {
     let res = pg().parse_sql_statements("COPY (SELECT 42 AS a, 'hello' AS b) FROM 'query.csv'");
@@ -1005,6 +1418,7 @@ fn parse_copy_to() {
             target: CopyTarget::File {
                 filename: "data.csv".to_string(),
             },
+            with: false,
             options: vec![],
             legacy_options: vec![],
             values: vec![],
@@ -1021,6 +1435,7 @@ fn parse_copy_to() {
             },
             to: true,
             target: CopyTarget::Stdout,
+            with: false,
             options: vec![CopyOption::Delimiter('|')],
             legacy_options: vec![],
             values: vec![],
@@ -1040,6 +1455,7 @@ fn parse_copy_to() {
             target: CopyTarget::Program {
                 command: "gzip > /usr1/proj/bray/sql/country_data.gz".into(),
             },
+            with: false,
             options: vec![],
             legacy_options: vec![],
             values: vec![],
@@ -1078,7 +1494,6 @@ fn parse_copy_to() {
                     group_by: GroupByExpr::Expressions(vec![]),
                     having: None,
                     named_window: vec![],
-                    window_before_qualify: false,
                     cluster_by: vec![],
                     distribute_by: vec![],
                     sort_by: vec![],
@@ -1098,6 +1513,7 @@ fn parse_copy_to() {
             target: CopyTarget::File {
                 filename: "query.csv".into(),
             },
+            with: false,
             options: vec![],
             legacy_options: vec![],
             values: vec![],
@@ -1119,6 +1535,7 @@ fn parse_copy_from_before_v9_0() {
             target: CopyTarget::File {
                 filename: "data.csv".to_string(),
             },
+            with: false,
             options: vec![],
             legacy_options: vec![
                 CopyLegacyOption::Binary,
@@ -1148,6 +1565,7 @@ fn parse_copy_from_before_v9_0() {
             target: CopyTarget::File {
                 filename: "data.csv".to_string(),
             },
+            with: false,
             options: vec![],
             legacy_options: vec![
                 CopyLegacyOption::Delimiter(','),
@@ -1176,6 +1594,7 @@ fn parse_copy_to_before_v9_0() {
             target: CopyTarget::File {
                 filename: "data.csv".to_string(),
             },
+            with: false,
             options: vec![],
             legacy_options: vec![
                 CopyLegacyOption::Binary,
@@ -1199,8 +1618,9 @@ fn parse_set() {
     assert_eq!(
         stmt,
         Statement::SetVariable {
-            local: false,
+            scope: ContextModifier::None,
             hivevar: false,
+            to_keyword: false,
             variables: OneOrManyWithParens::One(ObjectName(vec![Ident::new("a")])),
             value: vec![Expr::Identifier(Ident {
                 value: "b".into(),
@@ -1213,8 +1633,9 @@ fn parse_set() {
     assert_eq!(
         stmt,
         Statement::SetVariable {
-            local: false,
+            scope: ContextModifier::None,
             hivevar: false,
+            to_keyword: false,
             variables: OneOrManyWithParens::One(ObjectName(vec![Ident::new("a")])),
             value: vec![Expr::Value(Value::SingleQuotedString("b".into()))],
         }
@@ -1224,8 +1645,9 @@ fn parse_set() {
     assert_eq!(
         stmt,
         Statement::SetVariable {
-            local: false,
+            scope: ContextModifier::None,
             hivevar: false,
+            to_keyword: false,
             variables: OneOrManyWithParens::One(ObjectName(vec![Ident::new("a")])),
             value: vec![Expr::Value(number("0"))],
         }
@@ -1235,8 +1657,9 @@ fn parse_set() {
     assert_eq!(
         stmt,
         Statement::SetVariable {
-            local: false,
+            scope: ContextModifier::None,
             hivevar: false,
+            to_keyword: false,
             variables: OneOrManyWithParens::One(ObjectName(vec![Ident::new("a")])),
             value: vec![Expr::Identifier(Ident {
                 value: "DEFAULT".into(),
@@ -1249,8 +1672,9 @@ fn parse_set() {
     assert_eq!(
         stmt,
         Statement::SetVariable {
-            local: true,
+            scope: ContextModifier::Local,
             hivevar: false,
+            to_keyword: false,
             variables: OneOrManyWithParens::One(ObjectName(vec![Ident::new("a")])),
             value: vec![Expr::Identifier("b".into())],
         }
@@ -1260,8 +1684,9 @@ fn parse_set() {
     assert_eq!(
         stmt,
         Statement::SetVariable {
-            local: false,
+            scope: ContextModifier::None,
             hivevar: false,
+            to_keyword: false,
             variables: OneOrManyWithParens::One(ObjectName(vec![
                 Ident::new("a"),
                 Ident::new("b"),
@@ -1281,8 +1706,9 @@ fn parse_set() {
     assert_eq!(
         stmt,
         Statement::SetVariable {
-            local: false,
+            scope: ContextModifier::None,
             hivevar: false,
+            to_keyword: false,
             variables: OneOrManyWithParens::One(ObjectName(vec![
                 Ident::new("hive"),
                 Ident::new("tez"),
@@ -1294,8 +1720,73 @@ fn parse_set() {
         }
     );
 
-    pg_and_generic().one_statement_parses_to("SET a TO b", "SET a = b");
-    pg_and_generic().one_statement_parses_to("SET SESSION a = b", "SET a = b");
+    let stmt = pg_and_generic().verified_stmt("SET search_path TO public, other");
+    assert_eq!(
+        stmt,
+        Statement::SetVariable {
+            scope: ContextModifier::None,
+            hivevar: false,
+            variables: OneOrManyWithParens::One(ObjectName(vec![Ident::new("search_path")])),
+            value: vec![
+                Expr::Identifier(Ident::new("public")),
+                Expr::Identifier(Ident::new("other"))
+            ],
+            to_keyword: true,
+        }
+    );
+
+    let stmt = pg_and_generic().verified_stmt("SET SESSION a = b");
+    assert_eq!(
+        stmt,
+        Statement::SetVariable {
+            scope: ContextModifier::Session,
+            hivevar: false,
+            variables: OneOrManyWithParens::One(ObjectName(vec![Ident::new("a")])),
+            value: vec![Expr::Identifier(Ident::new("b"))],
+            to_keyword: false,
+        }
+    );
+
+    for (sql, to_keyword) in [
+        ("SET search_path = schema1, schema2", false),
+        ("SET search_path TO schema1, schema2", true),
+    ] {
+        assert_eq!(
+            pg_and_generic().verified_stmt(sql),
+            Statement::SetVariable {
+                scope: ContextModifier::None,
+                hivevar: false,
+                variables: OneOrManyWithParens::One(ObjectName(vec![Ident::new("search_path")])),
+                value: vec![
+                    Expr::Identifier(Ident::new("schema1")),
+                    Expr::Identifier(Ident::new("schema2"))
+                ],
+                to_keyword,
+            }
+        );
+    }
+
+    pg_and_generic().verified_stmt("SET LOCAL a TO b");
+    pg_and_generic().verified_stmt("SET SESSION TIME ZONE 'UTC'");
+    pg_and_generic().verified_stmt("SET LOCAL TIME ZONE DEFAULT");
+    // GLOBAL is not a scope in PostgreSQL, so it names the variable
+    assert_eq!(
+        pg().verified_stmt("SET global = 1"),
+        Statement::SetVariable {
+            scope: ContextModifier::None,
+            hivevar: false,
+            variables: OneOrManyWithParens::One(ObjectName(vec![Ident::new("global")])),
+            value: vec![Expr::Value(number("1"))],
+            to_keyword: false,
+        }
+    );
+    assert_eq!(
+        pg().verified_stmt("SET NAMES 'UTF8'"),
+        Statement::SetNames {
+            charset_name: Ident::with_quote('\'', "UTF8"),
+            collation_name: None,
+        }
+    );
 
     assert_eq!(
         pg_and_generic().parse_sql_statements("SET"),
@@ -1377,7 +1868,15 @@ fn parse_show() {
         Statement::ShowVariable {
             variable: vec!["ALL".into(), "ALL".into()]
         }
-    )
+    );
+
+    // MySQL-specific SHOW forms are plain configuration parameters here
+    assert_eq!(
+        pg().verified_stmt("SHOW databases"),
+        Statement::ShowVariable {
+            variable: vec!["databases".into()]
+        }
+    );
 }
 
 #[test]
@@ -1386,7 +1885,7 @@ fn parse_deallocate() {
     assert_eq!(
         stmt,
         Statement::Deallocate {
-            name: "a".into(),
+            name: Some("a".into()),
             prepare: false,
         }
     );
@@ -1395,7 +1894,7 @@ fn parse_deallocate() {
     assert_eq!(
         stmt,
         Statement::Deallocate {
-            name: "ALL".into(),
+            name: None,
             prepare: false,
         }
     );
@@ -1404,7 +1903,7 @@ fn parse_deallocate() {
     assert_eq!(
         stmt,
         Statement::Deallocate {
-            name: "a".into(),
+            name: Some("a".into()),
             prepare: true,
         }
     );
@@ -1413,10 +1912,20 @@ fn parse_deallocate() {
     assert_eq!(
         stmt,
         Statement::Deallocate {
-            name: "ALL".into(),
+            name: None,
             prepare: true,
         }
     );
+
+    // a quoted `"ALL"` is a statement name rather than the keyword
+    let stmt = pg_and_generic().verified_stmt(r#"DEALLOCATE "ALL""#);
+    assert_eq!(
+        stmt,
+        Statement::Deallocate {
+            name: Some(Ident::with_quote('"', "ALL")),
+            prepare: false,
+        }
+    );
 }
 
 #[test]
@@ -1536,6 +2045,61 @@ fn parse_prepare() {
     );
 }
 
+#[test]
+fn parse_pg_on_conflict_excluded_and_target_references() {
+    let stmt = pg().verified_stmt(
+        "INSERT INTO t AS x (id, a) VALUES (1, 2) \
+        ON CONFLICT(id) \
+        DO UPDATE SET a = excluded.a + x.a WHERE x.a < EXCLUDED.a",
+    );
+    match stmt {
+        Statement::Insert(Insert {
+            on:
+                Some(OnInsert::OnConflict(OnConflict {
+                    action:
+                        OnConflictAction::DoUpdate(DoUpdate {
+                            assignments,
+                            selection,
+                        }),
+                    ..
+                })),
+            ..
+        }) => {
+            assert_eq!(
+                vec![Assignment {
+                    id: vec!["a".into()],
+                    value: Expr::BinaryOp {
+                        left: Box::new(Expr::CompoundIdentifier(vec![
+                            "excluded".into(),
+                            "a".into()
+                        ])),
+                        op: BinaryOperator::Plus,
+                        right: Box::new(Expr::CompoundIdentifier(vec!["x".into(), "a".into()])),
+                    },
+                }],
+                assignments
+            );
+            assert_eq!(
+                Some(Expr::BinaryOp {
+                    left: Box::new(Expr::CompoundIdentifier(vec!["x".into(), "a".into()])),
+                    op: BinaryOperator::Lt,
+                    right: Box::new(Expr::CompoundIdentifier(vec![
+                        "EXCLUDED".into(),
+                        "a".into()
+                    ])),
+                }),
+                selection
+            );
+        }
+        _ => unreachable!(),
+    };
+
+    pg_and_generic().one_statement_parses_to(
+        "INSERT INTO t (id, a) VALUES (1, 2) ON CONFLICT (id) DO UPDATE SET a = excluded.a + t.a",
+        "INSERT INTO t (id, a) VALUES (1, 2) ON CONFLICT(id) DO UPDATE SET a = excluded.a + t.a",
+    );
+}
+
 #[test]
 fn parse_pg_on_conflict() {
     let stmt = pg_and_generic().verified_stmt(
@@ -1757,10 +2321,83 @@ fn parse_pg_returning() {
     };
 }
 
-fn test_operator(operator: &str, dialect: &TestedDialects, expected: BinaryOperator) {
-    let operator_tokens =
-        sqlparser::tokenizer::Tokenizer::new(&PostgreSqlDialect {}, &format!("a{operator}b"))
-            .tokenize()
+#[test]
+fn parse_data_modifying_cte() {
+    let sql = "WITH c AS (DELETE FROM t RETURNING *) INSERT INTO log SELECT * FROM c";
+    let query = match pg_and_generic().verified_stmt(sql) {
+        Statement::Query(query) => query,
+        _ => unreachable!(),
+    };
+    let cte = &query.with.as_ref().unwrap().cte_tables[0];
+    assert!(matches!(
+        *cte.query.body,
+        SetExpr::Delete(Statement::Delete(Delete {
+            returning: Some(_),
+            ..
+        }))
+    ));
+    assert!(matches!(*query.body, SetExpr::Insert(Statement::Insert(_))));
+
+    pg().verified_stmt("WITH c AS (UPDATE t SET a = 1 RETURNING a) SELECT * FROM c");
+    pg().verified_stmt("WITH c AS (INSERT INTO t (a) VALUES (1) RETURNING a) SELECT * FROM c");
+    pg().verified_stmt("WITH c AS (SELECT 1) DELETE FROM t WHERE a IN (SELECT * FROM c)");
+
+    // RETURNING is only reserved after a table name in PostgreSQL and the
+    // generic dialect
+    pg_and_generic().verified_stmt("DELETE FROM t RETURNING a");
+    all_dialects_except(|d| d.is::<PostgreSqlDialect>() || d.is::<GenericDialect>())
+        .one_statement_parses_to("SELECT * FROM t returning", "SELECT * FROM t AS returning");
+    assert!(
+        all_dialects_except(|d| d.is::<PostgreSqlDialect>() || d.is::<GenericDialect>())
+            .parse_sql_statements("WITH c AS (SELECT 1) DELETE FROM t")
+            .is_err()
+    );
+}
+
+#[test]
+fn parse_returning_into() {
+    match pg_and_generic().verified_stmt("UPDATE t SET x = 1 WHERE id = 5 RETURNING x INTO var") {
+        Statement::Update {
+            returning,
+            returning_into,
+            ..
+        } => {
+            assert_eq!(
+                Some(vec![SelectItem::UnnamedExpr(Expr::Identifier("x".into()))]),
+                returning
+            );
+            assert_eq!(
+                Some(ReturningInto {
+                    strict: false,
+                    targets: vec![Ident::new("var")],
+                }),
+                returning_into
+            );
+        }
+        _ => unreachable!(),
+    };
+
+    pg_and_generic().verified_stmt("INSERT INTO t (a) VALUES (1) RETURNING id, a INTO v_id, v_a");
+    pg_and_generic().verified_stmt("DELETE FROM t WHERE id = 5 RETURNING a INTO v_a");
+    match pg_and_generic().verified_stmt("DELETE FROM t WHERE id = 5 RETURNING a INTO STRICT v_a") {
+        Statement::Delete(Delete { returning_into, .. }) => {
+            assert!(returning_into.unwrap().strict);
+        }
+        _ => unreachable!(),
+    };
+
+    // other dialects do not accept INTO after RETURNING
+    assert!(
+        all_dialects_except(|d| d.is::<PostgreSqlDialect>() || d.is::<GenericDialect>())
+            .parse_sql_statements("UPDATE t SET x = 1 RETURNING x INTO var")
+            .is_err()
+    );
+}
+
+fn test_operator(operator: &str, dialect: &TestedDialects, expected: BinaryOperator) {
+    let operator_tokens =
+        sqlparser::tokenizer::Tokenizer::new(&PostgreSqlDialect {}, &format!("a{operator}b"))
+            .tokenize()
             .unwrap();
     assert_eq!(
         operator_tokens.len(),
@@ -2050,6 +2687,71 @@ fn parse_array_index_expr() {
     );
 }
 
+#[test]
+fn parse_array_constructor() {
+    let sql = "SELECT ARRAY[1, 2, 3]";
+    let select = pg_and_generic().verified_only_select(sql);
+    assert_eq!(
+        &Expr::Array(sqlparser::ast::Array {
+            elem: vec![
+                Expr::Value(number("1")),
+                Expr::Value(number("2")),
+                Expr::Value(number("3")),
+            ],
+            named: true
+        }),
+        expr_from_projection(only(&select.projection)),
+    );
+
+    pg_and_generic().verified_expr("ARRAY[[1, 2], [3, 4]]");
+    pg_and_generic().verified_expr("ARRAY[ARRAY[1, 2], ARRAY[3, 4]]");
+    pg_and_generic().verified_expr("ARRAY[]::INT[]");
+}
+
+#[test]
+#[cfg(not(feature = "bigdecimal"))]
+fn parse_number_with_underscores() {
+    let select = pg_and_generic().verified_only_select("SELECT 1_000_000, 1_000.000_1 FROM t");
+    assert_eq!(
+        &Expr::Value(Value::Number("1_000_000".to_string(), false)),
+        expr_from_projection(&select.projection[0])
+    );
+
+    assert!(pg().parse_sql_statements("SELECT 1_ FROM t").is_err());
+    // An underscore that can't be a separator starts an identifier, as before
+    pg_and_generic().one_statement_parses_to("SELECT 1_a FROM t", "SELECT 1 AS _a FROM t");
+}
+
+#[test]
+#[cfg(feature = "bigdecimal")]
+fn parse_number_with_underscores_bigdecimal() {
+    // `BigDecimal` can't keep the separators, so they are dropped
+    pg_and_generic().one_statement_parses_to("SELECT 1_000_000 FROM t", "SELECT 1000000 FROM t");
+}
+
+#[test]
+fn parse_collate_after_cast_and_subscript() {
+    match pg().verified_expr("a::TEXT COLLATE \"C\"") {
+        Expr::Collate { expr, collation } => {
+            assert!(matches!(*expr, Expr::Cast { .. }));
+            assert_eq!(collation.to_string(), "\"C\"");
+        }
+        _ => unreachable!(),
+    }
+
+    match pg().verified_expr("arr[1] COLLATE \"C\" || 'x'") {
+        Expr::BinaryOp {
+            left,
+            op: BinaryOperator::StringConcat,
+            ..
+        } => match *left {
+            Expr::Collate { expr, .. } => assert!(matches!(*expr, Expr::Subscript { .. })),
+            _ => unreachable!(),
+        },
+        _ => unreachable!(),
+    }
+}
+
 #[test]
 fn parse_array_subscript() {
     let tests = [
@@ -2130,6 +2832,54 @@ fn parse_array_subscript() {
     }
 
     pg_and_generic().verified_expr("schedule[:2][2:]");
+
+    // subscripts bind tighter than the surrounding operators
+    let select =
+        pg_and_generic().verified_only_select("SELECT arr[1] + matrix[1][2] = -arr[2:][1]");
+    assert_eq!(
+        &Expr::BinaryOp {
+            left: Box::new(Expr::BinaryOp {
+                left: Box::new(Expr::Subscript {
+                    expr: Box::new(Expr::Identifier(Ident::new("arr"))),
+                    subscript: Box::new(Subscript::Index {
+                        index: Expr::Value(number("1")),
+                    }),
+                }),
+                op: BinaryOperator::Plus,
+                right: Box::new(Expr::Subscript {
+                    expr: Box::new(Expr::Subscript {
+                        expr: Box::new(Expr::Identifier(Ident::new("matrix"))),
+                        subscript: Box::new(Subscript::Index {
+                            index: Expr::Value(number("1")),
+                        }),
+                    }),
+                    subscript: Box::new(Subscript::Index {
+                        index: Expr::Value(number("2")),
+                    }),
+                }),
+            }),
+            op: BinaryOperator::Eq,
+            right: Box::new(Expr::UnaryOp {
+                op: UnaryOperator::Minus,
+                expr: Box::new(Expr::Subscript {
+                    expr: Box::new(Expr::Subscript {
+                        expr: Box::new(Expr::Identifier(Ident::new("arr"))),
+                        subscript: Box::new(Subscript::Slice {
+                            lower_bound: Some(Expr::Value(number("2"))),
+                            upper_bound: None,
+                            stride: None,
+                        }),
+                    }),
+                    subscript: Box::new(Subscript::Index {
+                        index: Expr::Value(number("1")),
+                    }),
+                }),
+            }),
+        },
+        expr_from_projection(only(&select.projection))
+    );
+    pg_and_generic().verified_expr("arr[1:3]::TEXT[]");
+    pg_and_generic().verified_expr("t.arr[i + 1:j - 1]");
 }
 
 #[test]
@@ -2305,6 +3055,53 @@ fn parse_create_index_with_include() {
     }
 }
 
+#[test]
+fn parse_create_index_with_method_include_and_predicate() {
+    let sql = "CREATE INDEX idx ON t USING GIN (col) INCLUDE (other) WHERE active";
+    match pg().verified_stmt(sql) {
+        Statement::CreateIndex(CreateIndex {
+            using,
+            columns,
+            include,
+            predicate,
+            ..
+        }) => {
+            assert_eq!(Some(Ident::new("GIN")), using);
+            assert_eq_vec(&["col"], &columns);
+            assert_eq_vec(&["other"], &include);
+            assert_eq!(Some(Expr::Identifier(Ident::new("active"))), predicate);
+        }
+        _ => unreachable!(),
+    }
+
+    let sql = "CREATE INDEX idx ON t(a DESC NULLS LAST,b ASC,c)";
+    match pg().verified_stmt(sql) {
+        Statement::CreateIndex(CreateIndex { columns, .. }) => {
+            assert_eq!(
+                vec![
+                    OrderByExpr {
+                        expr: Expr::Identifier(Ident::new("a")),
+                        asc: Some(false),
+                        nulls_first: Some(false),
+                    },
+                    OrderByExpr {
+                        expr: Expr::Identifier(Ident::new("b")),
+                        asc: Some(true),
+                        nulls_first: None,
+                    },
+                    OrderByExpr {
+                        expr: Expr::Identifier(Ident::new("c")),
+                        asc: None,
+                        nulls_first: None,
+                    },
+                ],
+                columns
+            );
+        }
+        _ => unreachable!(),
+    }
+}
+
 #[test]
 fn parse_create_index_with_nulls_distinct() {
     let sql = "CREATE INDEX IF NOT EXISTS my_index ON my_table(col1,col2) NULLS NOT DISTINCT";
@@ -2389,7 +3186,6 @@ fn parse_array_subquery_expr() {
                         having: None,
                         named_window: vec![],
                         qualify: None,
-                        window_before_qualify: false,
                         value_table_mode: None,
                         connect_by: None,
                     }))),
@@ -2408,7 +3204,6 @@ fn parse_array_subquery_expr() {
                         having: None,
                         named_window: vec![],
                         qualify: None,
-                        window_before_qualify: false,
                         value_table_mode: None,
                         connect_by: None,
                     }))),
@@ -2423,11 +3218,103 @@ fn parse_array_subquery_expr() {
             })),
             filter: None,
             null_treatment: None,
+            nth_value_order: None,
             over: None,
             within_group: vec![]
         }),
         expr_from_projection(only(&select.projection)),
     );
+
+    let select =
+        pg_and_generic().verified_only_select("SELECT ARRAY(SELECT id FROM t ORDER BY id)");
+    match expr_from_projection(only(&select.projection)) {
+        Expr::Function(Function {
+            name,
+            args: FunctionArguments::Subquery(query),
+            ..
+        }) => {
+            assert_eq!("ARRAY", name.to_string());
+            assert_eq!("SELECT id FROM t ORDER BY id", query.to_string());
+        }
+        e => panic!("expected ARRAY subquery, got {e:?}"),
+    }
+
+    // `ARRAY[...]` remains an array literal
+    let select = pg_and_generic().verified_only_select("SELECT ARRAY[1, 2]");
+    assert!(matches!(
+        expr_from_projection(only(&select.projection)),
+        Expr::Array(_)
+    ));
+
+    pg_and_generic().verified_stmt("SELECT ARRAY(SELECT id FROM t)[1]");
+    pg_and_generic().verified_stmt("SELECT * FROM u WHERE x = ANY(ARRAY(SELECT id FROM t))");
+}
+
+#[test]
+fn parse_vacuum() {
+    match pg_and_generic().verified_stmt("VACUUM (FULL, ANALYZE, VERBOSE false) t (a, b), s.u") {
+        Statement::Vacuum {
+            options,
+            parenthesized_options,
+            tables,
+            into,
+        } => {
+            assert_eq!(
+                vec![
+                    VacuumOption {
+                        name: Ident::new("FULL"),
+                        value: None,
+                    },
+                    VacuumOption {
+                        name: Ident::new("ANALYZE"),
+                        value: None,
+                    },
+                    VacuumOption {
+                        name: Ident::new("VERBOSE"),
+                        value: Some(Expr::Value(Value::Boolean(false))),
+                    },
+                ],
+                options
+            );
+            assert!(parenthesized_options);
+            assert_eq!(
+                vec![
+                    VacuumTable {
+                        name: ObjectName(vec![Ident::new("t")]),
+                        columns: vec![Ident::new("a"), Ident::new("b")],
+                    },
+                    VacuumTable {
+                        name: ObjectName(vec![Ident::new("s"), Ident::new("u")]),
+                        columns: vec![],
+                    },
+                ],
+                tables
+            );
+            assert_eq!(None, into);
+        }
+        _ => unreachable!(),
+    }
+
+    match pg_and_generic().verified_stmt("VACUUM FULL VERBOSE t") {
+        Statement::Vacuum {
+            options,
+            parenthesized_options: false,
+            tables,
+            ..
+        } => {
+            assert_eq!("FULL VERBOSE", display_separated(&options, " ").to_string());
+            assert_eq!("t", tables[0].to_string());
+        }
+        _ => unreachable!(),
+    }
+
+    pg_and_generic().verified_stmt("VACUUM");
+    pg_and_generic().verified_stmt("VACUUM ANALYZE t (a)");
+    // legacy option keywords keep their spelling
+    pg_and_generic().verified_stmt("VACUUM full freeze t");
+    pg_and_generic()
+        .verified_stmt("VACUUM (INDEX_CLEANUP OFF, PARALLEL 4, BUFFER_USAGE_LIMIT '256MB') t");
+    pg().one_statement_parses_to("VACUUM (SKIP_LOCKED TRUE) t", "VACUUM (SKIP_LOCKED true) t");
 }
 
 #[test]
@@ -2743,6 +3630,7 @@ fn test_composite_value() {
                     clauses: vec![],
                 }),
                 null_treatment: None,
+                nth_value_order: None,
                 filter: None,
                 over: None,
                 within_group: vec![],
@@ -2799,6 +3687,51 @@ fn parse_comments() {
     }
 }
 
+#[test]
+fn parse_comment_on_objects() {
+    match pg().verified_stmt("COMMENT ON COLUMN s.t.c IS 'desc'") {
+        Statement::Comment {
+            object_type,
+            object_name,
+            comment: Some(comment),
+            ..
+        } => {
+            assert_eq!("desc", comment);
+            assert_eq!(
+                ObjectName(vec![Ident::new("s"), Ident::new("t"), Ident::new("c")]),
+                object_name
+            );
+            assert_eq!(CommentObject::Column, object_type);
+        }
+        _ => unreachable!(),
+    }
+
+    match pg().verified_stmt("COMMENT ON MATERIALIZED VIEW v IS 'x'") {
+        Statement::Comment { object_type, .. } => {
+            assert_eq!(CommentObject::MaterializedView, object_type);
+        }
+        _ => unreachable!(),
+    }
+
+    pg().verified_stmt("COMMENT ON TABLE t IS NULL");
+    pg().verified_stmt("COMMENT ON SCHEMA s IS 'x'");
+    pg().verified_stmt("COMMENT ON INDEX s.i IS 'x'");
+    pg().verified_stmt("COMMENT ON VIEW v IS 'x'");
+    pg().verified_stmt("COMMENT ON SEQUENCE seq IS 'x'");
+    pg().verified_stmt("COMMENT ON DATABASE db IS 'x'");
+    pg().verified_stmt("COMMENT ON EXTENSION ext IS 'x'");
+    pg().verified_stmt("COMMENT ON ROLE r IS 'x'");
+    pg().verified_stmt("COMMENT ON TYPE ty IS 'x'");
+    pg().verified_stmt("COMMENT ON TABLE t IS 'it''s'");
+
+    assert_eq!(
+        pg().parse_sql_statements("COMMENT ON FOO t IS 'x'")
+            .unwrap_err()
+            .to_string(),
+        "sql parser error: Expected comment object_type, found: FOO"
+    );
+}
+
 #[test]
 fn parse_quoted_identifier() {
     pg_and_generic().verified_stmt(r#"SELECT "quoted "" ident""#);
@@ -2931,6 +3864,87 @@ fn parse_escaped_literal_string() {
     ]);
 }
 
+#[test]
+fn parse_escaped_literal_string_doubled_quote() {
+    let select = pg_and_generic().verified_only_select(r"SELECT E'it\'s', 'it''s'");
+    assert_eq!(
+        &Expr::Value(Value::EscapedStringLiteral("it's".to_string())),
+        expr_from_projection(&select.projection[0])
+    );
+    assert_eq!(
+        &Expr::Value(Value::SingleQuotedString("it's".to_string())),
+        expr_from_projection(&select.projection[1])
+    );
+
+    // a doubled quote is read as the same value as an escaped one
+    assert_eq!(
+        pg_and_generic().parse_sql_statements(r"SELECT E'it\'s'"),
+        pg_and_generic().parse_sql_statements("SELECT E'it''s'"),
+    );
+}
+
+#[test]
+fn parse_unicode_escape_literal_string() {
+    let select = pg_and_generic()
+        .verified_only_select(r"SELECT U&'d\0061t\+000061', U&'\\ \00E9''s', U&'\D83D\DE00'");
+    assert_eq!(
+        vec![
+            Expr::Value(Value::UnicodeStringLiteral {
+                value: r"d\0061t\+000061".to_string(),
+                escape: None,
+            }),
+            Expr::Value(Value::UnicodeStringLiteral {
+                value: r"\\ \00E9's".to_string(),
+                escape: None,
+            }),
+            Expr::Value(Value::UnicodeStringLiteral {
+                value: r"\D83D\DE00".to_string(),
+                escape: None,
+            }),
+        ],
+        select
+            .projection
+            .iter()
+            .map(|item| expr_from_projection(item).clone())
+            .collect::<Vec<_>>()
+    );
+
+    pg_and_generic().one_statement_parses_to("SELECT u&'a'", "SELECT U&'a'");
+
+    let select = pg_and_generic().verified_only_select("SELECT U&'d!0061t!!' UESCAPE '!'");
+    assert_eq!(
+        &Expr::Value(Value::UnicodeStringLiteral {
+            value: "d!0061t!!".to_string(),
+            escape: Some('!'),
+        }),
+        expr_from_projection(&select.projection[0])
+    );
+
+    for (sql, err) in [
+        (r"SELECT U&'\006'", r"Invalid Unicode escape in U&'\006'"),
+        (
+            r"SELECT U&'\0000'",
+            r"Invalid Unicode code point in U&'\0000'",
+        ),
+        (
+            r"SELECT U&'\D83D'",
+            r"Invalid Unicode surrogate pair in U&'\D83D'",
+        ),
+        (
+            r"SELECT U&'a' UESCAPE 'a'",
+            "Invalid Unicode escape character: 'a'",
+        ),
+    ] {
+        assert_eq!(
+            pg_and_generic()
+                .parse_sql_statements(sql)
+                .unwrap_err()
+                .to_string(),
+            format!("sql parser error: {err}")
+        );
+    }
+}
+
 #[test]
 fn parse_declare() {
     pg_and_generic()
@@ -2957,6 +3971,7 @@ fn parse_current_functions() {
             name: ObjectName(vec![Ident::new("CURRENT_CATALOG")]),
             args: FunctionArguments::None,
             null_treatment: None,
+            nth_value_order: None,
             filter: None,
             over: None,
             within_group: vec![],
@@ -2968,6 +3983,7 @@ fn parse_current_functions() {
             name: ObjectName(vec![Ident::new("CURRENT_USER")]),
             args: FunctionArguments::None,
             null_treatment: None,
+            nth_value_order: None,
             filter: None,
             over: None,
             within_group: vec![],
@@ -2979,6 +3995,7 @@ fn parse_current_functions() {
             name: ObjectName(vec![Ident::new("SESSION_USER")]),
             args: FunctionArguments::None,
             null_treatment: None,
+            nth_value_order: None,
             filter: None,
             over: None,
             within_group: vec![],
@@ -2990,6 +4007,7 @@ fn parse_current_functions() {
             name: ObjectName(vec![Ident::new("USER")]),
             args: FunctionArguments::None,
             null_treatment: None,
+            nth_value_order: None,
             filter: None,
             over: None,
             within_group: vec![],
@@ -3090,103 +4108,76 @@ fn parse_create_role() {
     }
 
     let sql = "CREATE ROLE abc LOGIN PASSWORD NULL";
-    match pg().parse_sql_statements(sql).as_deref() {
-        Ok(
-            [Statement::CreateRole {
-                names,
-                login,
-                password,
-                ..
-            }],
-        ) => {
-            assert_eq_vec(&["abc"], names);
-            assert_eq!(*login, Some(true));
-            assert_eq!(*password, Some(Password::NullPassword));
+    match pg().verified_stmt(sql) {
+        Statement::CreateRole { names, options, .. } => {
+            assert_eq_vec(&["abc"], &names);
+            assert_eq!(
+                vec![
+                    CreateRoleOption::Option(Box::new(RoleOption::Login(true))),
+                    CreateRoleOption::Option(Box::new(RoleOption::Password(
+                        Password::NullPassword
+                    ))),
+                ],
+                options
+            );
         }
-        err => panic!("Failed to parse CREATE ROLE test case: {err:?}"),
+        _ => unreachable!(),
     }
 
-    let sql = "CREATE ROLE abc WITH LOGIN PASSWORD NULL";
-    match pg().parse_sql_statements(sql).as_deref() {
-        Ok(
-            [Statement::CreateRole {
-                names,
-                login,
-                password,
-                ..
-            }],
-        ) => {
-            assert_eq_vec(&["abc"], names);
-            assert_eq!(*login, Some(true));
-            assert_eq!(*password, Some(Password::NullPassword));
-        }
-        err => panic!("Failed to parse CREATE ROLE test case: {err:?}"),
-    }
+    pg().verified_stmt("CREATE ROLE abc WITH LOGIN PASSWORD NULL");
 
     let sql = "CREATE ROLE magician WITH SUPERUSER CREATEROLE NOCREATEDB BYPASSRLS INHERIT PASSWORD 'abcdef' LOGIN VALID UNTIL '2025-01-01' IN ROLE role1, role2 ROLE role3 ADMIN role4, role5 REPLICATION";
-    // Roundtrip order of optional parameters is not preserved
-    match pg().parse_sql_statements(sql).as_deref() {
-        Ok(
-            [Statement::CreateRole {
-                names,
-                if_not_exists,
-                bypassrls,
-                login,
-                inherit,
-                password,
-                superuser,
-                create_db,
-                create_role,
-                replication,
-                connection_limit,
-                valid_until,
-                in_role,
-                in_group,
-                role,
-                user: _,
-                admin,
-                authorization_owner,
-            }],
-        ) => {
-            assert_eq_vec(&["magician"], names);
-            assert!(!*if_not_exists);
-            assert_eq!(*login, Some(true));
-            assert_eq!(*inherit, Some(true));
-            assert_eq!(*bypassrls, Some(true));
-            assert_eq!(
-                *password,
-                Some(Password::Password(Expr::Value(Value::SingleQuotedString(
-                    "abcdef".into()
-                ))))
-            );
-            assert_eq!(*superuser, Some(true));
-            assert_eq!(*create_db, Some(false));
-            assert_eq!(*create_role, Some(true));
-            assert_eq!(*replication, Some(true));
-            assert_eq!(*connection_limit, None);
+    match pg().verified_stmt(sql) {
+        Statement::CreateRole {
+            names,
+            if_not_exists,
+            with,
+            options,
+            authorization_owner,
+        } => {
+            assert_eq_vec(&["magician"], &names);
+            assert!(!if_not_exists);
+            assert!(with);
             assert_eq!(
-                *valid_until,
-                Some(Expr::Value(Value::SingleQuotedString("2025-01-01".into())))
+                vec![
+                    CreateRoleOption::Option(Box::new(RoleOption::SuperUser(true))),
+                    CreateRoleOption::Option(Box::new(RoleOption::CreateRole(true))),
+                    CreateRoleOption::Option(Box::new(RoleOption::CreateDB(false))),
+                    CreateRoleOption::Option(Box::new(RoleOption::BypassRLS(true))),
+                    CreateRoleOption::Option(Box::new(RoleOption::Inherit(true))),
+                    CreateRoleOption::Option(Box::new(RoleOption::Password(Password::Password(
+                        Expr::Value(Value::SingleQuotedString("abcdef".into()))
+                    )))),
+                    CreateRoleOption::Option(Box::new(RoleOption::Login(true))),
+                    CreateRoleOption::Option(Box::new(RoleOption::ValidUntil(Expr::Value(
+                        Value::SingleQuotedString("2025-01-01".into())
+                    )))),
+                    CreateRoleOption::InRole(vec!["role1".into(), "role2".into()]),
+                    CreateRoleOption::Role(vec!["role3".into()]),
+                    CreateRoleOption::Admin(vec!["role4".into(), "role5".into()]),
+                    CreateRoleOption::Option(Box::new(RoleOption::Replication(true))),
+                ],
+                options
             );
-            assert_eq_vec(&["role1", "role2"], in_role);
-            assert!(in_group.is_empty());
-            assert_eq_vec(&["role3"], role);
-            assert_eq_vec(&["role4", "role5"], admin);
-            assert_eq!(*authorization_owner, None);
+            assert_eq!(authorization_owner, None);
         }
-        err => panic!("Failed to parse CREATE ROLE test case: {err:?}"),
+        _ => unreachable!(),
     }
 
+    pg().verified_stmt("CREATE ROLE app LOGIN PASSWORD 'x' CREATEDB");
+    pg().verified_stmt("CREATE ROLE app NOLOGIN NOSUPERUSER CONNECTION LIMIT 5");
+
     let sql = "CREATE ROLE abc WITH USER foo, bar ROLE baz ";
     match pg().parse_sql_statements(sql).as_deref() {
-        Ok(
-            [Statement::CreateRole {
-                names, user, role, ..
-            }],
-        ) => {
+        Ok([Statement::CreateRole { names, options, .. }]) => {
             assert_eq_vec(&["abc"], names);
-            assert_eq_vec(&["foo", "bar"], user);
-            assert_eq_vec(&["baz"], role);
+            assert_eq!(
+                &vec![
+                    CreateRoleOption::User(vec!["foo".into(), "bar".into()]),
+                    CreateRoleOption::Role(vec!["baz".into()]),
+                ],
+                options
+            );
         }
         err => panic!("Failed to parse CREATE ROLE test case: {err:?}"),
     }
@@ -3207,6 +4198,13 @@ fn parse_create_role() {
             panic!("Should not be able to parse CREATE ROLE containing both negated and non-negated versions of the same keyword: {negatable_kw}")
         }
     }
+
+    assert_eq!(
+        pg().parse_sql_statements("CREATE ROLE abc PASSWORD 'a' PASSWORD NULL")
+            .unwrap_err()
+            .to_string(),
+        "sql parser error: Found multiple PASSWORD"
+    );
 }
 
 #[test]
@@ -3444,6 +4442,7 @@ fn parse_delimited_identifiers() {
                 clauses: vec![],
             }),
             null_treatment: None,
+            nth_value_order: None,
             filter: None,
             over: None,
             within_group: vec![],
@@ -3521,6 +4520,58 @@ fn parse_create_function_detailed() {
     pg_and_generic().verified_stmt("CREATE OR REPLACE FUNCTION add(a INTEGER, IN b INTEGER = 1) RETURNS INTEGER LANGUAGE SQL STABLE CALLED ON NULL INPUT PARALLEL UNSAFE RETURN a + b");
     pg_and_generic().verified_stmt(r#"CREATE OR REPLACE FUNCTION increment(i INTEGER) RETURNS INTEGER LANGUAGE plpgsql AS $$ BEGIN RETURN i + 1; END; $$"#);
 }
+
+#[test]
+fn parse_create_function_with_arg_modes() {
+    let sql = "CREATE OR REPLACE FUNCTION f(IN a INTEGER, OUT b INTEGER, INOUT c TEXT) RETURNS INTEGER LANGUAGE plpgsql VOLATILE AS $$ BEGIN b := a; RETURN a; END; $$";
+    match pg_and_generic().verified_stmt(sql) {
+        Statement::CreateFunction {
+            args,
+            language,
+            behavior,
+            function_body,
+            ..
+        } => {
+            assert_eq!(
+                Some(vec![
+                    Some(ArgMode::In),
+                    Some(ArgMode::Out),
+                    Some(ArgMode::InOut)
+                ]),
+                args.map(|args| args.into_iter().map(|arg| arg.mode).collect())
+            );
+            assert_eq!(Some(Ident::new("plpgsql")), language);
+            assert_eq!(Some(FunctionBehavior::Volatile), behavior);
+            assert_eq!(
+                Some(CreateFunctionBody::AsBeforeOptions(Expr::Value(
+                    Value::DollarQuotedString(DollarQuotedString {
+                        value: " BEGIN b := a; RETURN a; END; ".to_string(),
+                        tag: None,
+                    })
+                ))),
+                function_body
+            );
+        }
+        _ => unreachable!(),
+    }
+
+    // The body may precede the options, and keeps its place
+    let sql =
+        "CREATE FUNCTION f() RETURNS INTEGER AS $body$ SELECT 1 $body$ LANGUAGE sql IMMUTABLE";
+    match pg_and_generic().verified_stmt(sql) {
+        Statement::CreateFunction { function_body, .. } => assert_eq!(
+            Some(CreateFunctionBody::AsBeforeCharacteristics(Expr::Value(
+                Value::DollarQuotedString(DollarQuotedString {
+                    value: " SELECT 1 ".to_string(),
+                    tag: Some("body".to_string()),
+                })
+            ))),
+            function_body
+        ),
+        _ => unreachable!(),
+    }
+}
+
 #[test]
 fn parse_incorrect_create_function_parallel() {
     let sql = "CREATE FUNCTION add(INTEGER, INTEGER) RETURNS INTEGER LANGUAGE SQL PARALLEL BLAH AS 'select $1 + $2;'";
@@ -3707,9 +4758,9 @@ fn parse_select_group_by_grouping_sets() {
         GroupByExpr::Expressions(vec![
             Expr::Identifier(Ident::new("size")),
             Expr::GroupingSets(vec![
-                vec![Expr::Identifier(Ident::new("brand"))],
-                vec![Expr::Identifier(Ident::new("size"))],
-                vec![],
+                OneOrManyWithParens::Many(vec![Expr::Identifier(Ident::new("brand"))]),
+                OneOrManyWithParens::Many(vec![Expr::Identifier(Ident::new("size"))]),
+                OneOrManyWithParens::Many(vec![]),
             ]),
         ]),
         select.group_by
@@ -3750,17 +4801,132 @@ fn parse_select_group_by_cube() {
     );
 }
 
+#[test]
+fn parse_create_materialized_view() {
+    match pg().verified_stmt(
+        "CREATE MATERIALIZED VIEW IF NOT EXISTS mv (a) WITH (fillfactor = 70) AS SELECT 1 WITH NO DATA",
+    ) {
+        Statement::CreateView {
+            materialized,
+            if_not_exists,
+            options,
+            with_data,
+            ..
+        } => {
+            assert!(materialized);
+            assert!(if_not_exists);
+            assert!(matches!(options, CreateTableOptions::With(_)));
+            assert_eq!(Some(false), with_data);
+        }
+        _ => unreachable!(),
+    }
+    pg().verified_stmt("CREATE MATERIALIZED VIEW mv AS SELECT 1 WITH DATA");
+
+    // `IF NOT EXISTS` is only accepted for materialized views
+    assert!(pg()
+        .parse_sql_statements("CREATE VIEW IF NOT EXISTS v AS SELECT 1")
+        .is_err());
+}
+
+#[test]
+fn parse_refresh_materialized_view() {
+    match pg_and_generic().verified_stmt("REFRESH MATERIALIZED VIEW CONCURRENTLY public.mv") {
+        Statement::RefreshMaterializedView {
+            name,
+            concurrently,
+            with_data,
+        } => {
+            assert_eq!(
+                ObjectName(vec![Ident::new("public"), Ident::new("mv")]),
+                name
+            );
+            assert!(concurrently);
+            assert_eq!(None, with_data);
+        }
+        _ => unreachable!(),
+    }
+
+    match pg_and_generic().verified_stmt("REFRESH MATERIALIZED VIEW mv WITH NO DATA") {
+        Statement::RefreshMaterializedView {
+            concurrently,
+            with_data,
+            ..
+        } => {
+            assert!(!concurrently);
+            assert_eq!(Some(false), with_data);
+        }
+        _ => unreachable!(),
+    }
+
+    pg_and_generic().verified_stmt("REFRESH MATERIALIZED VIEW CONCURRENTLY mv WITH DATA");
+}
+
 #[test]
 fn parse_truncate() {
     let truncate = pg_and_generic().verified_stmt("TRUNCATE db.table_name");
     assert_eq!(
         Statement::Truncate {
-            table_name: ObjectName(vec![Ident::new("db"), Ident::new("table_name")]),
+            table_names: vec![TruncateTableTarget {
+                only: false,
+                name: ObjectName(vec![Ident::new("db"), Ident::new("table_name")]),
+                descendants: false,
+            }],
+            partitions: None,
+            table: false,
+            identity: None,
+            cascade: None,
+        },
+        truncate
+    );
+}
+
+#[test]
+fn parse_truncate_with_options() {
+    let truncate =
+        pg_and_generic().verified_stmt("TRUNCATE TABLE ONLY a, b RESTART IDENTITY CASCADE");
+    assert_eq!(
+        Statement::Truncate {
+            table_names: vec![
+                TruncateTableTarget {
+                    only: true,
+                    name: ObjectName(vec![Ident::new("a")]),
+                    descendants: false,
+                },
+                TruncateTableTarget {
+                    only: false,
+                    name: ObjectName(vec![Ident::new("b")]),
+                    descendants: false,
+                },
+            ],
             partitions: None,
-            table: false
+            table: true,
+            identity: Some(TruncateIdentityOption::Restart),
+            cascade: Some(CascadeOption::Cascade),
         },
         truncate
     );
+
+    match pg_and_generic().verified_stmt("TRUNCATE a*, ONLY b") {
+        Statement::Truncate { table_names, .. } => assert_eq!(
+            vec![
+                TruncateTableTarget {
+                    only: false,
+                    name: ObjectName(vec![Ident::new("a")]),
+                    descendants: true,
+                },
+                TruncateTableTarget {
+                    only: true,
+                    name: ObjectName(vec![Ident::new("b")]),
+                    descendants: false,
+                },
+            ],
+            table_names
+        ),
+        _ => unreachable!(),
+    }
+
+    pg_and_generic().verified_stmt("TRUNCATE a CONTINUE IDENTITY RESTRICT");
+    pg_and_generic().verified_stmt("TRUNCATE TABLE a, b CASCADE");
 }
 
 #[test]
@@ -3932,9 +5098,11 @@ fn test_simple_postgres_insert_with_alias() {
             table: false,
             on: None,
             returning: None,
+            returning_into: None,
             replace_into: false,
             priority: None,
-            insert_alias: None
+            insert_alias: None,
+            assignments: vec![]
         })
     )
 }
@@ -3999,9 +5167,11 @@ fn test_simple_postgres_insert_with_alias() {
             table: false,
             on: None,
             returning: None,
+            returning_into: None,
             replace_into: false,
             priority: None,
-            insert_alias: None
+            insert_alias: None,
+            assignments: vec![]
         })
     )
 }
@@ -4062,13 +5232,57 @@ fn test_simple_insert_with_quoted_alias() {
             table: false,
             on: None,
             returning: None,
+            returning_into: None,
             replace_into: false,
             priority: None,
             insert_alias: None,
+            assignments: vec![],
         })
     )
 }
 
+#[test]
+fn parse_generate_series_with_step() {
+    let select = pg_and_generic().verified_only_select("SELECT generate_series(1, 10, 2)");
+    assert_eq!(
+        &call(
+            "generate_series",
+            [
+                Expr::Value(number("1")),
+                Expr::Value(number("10")),
+                Expr::Value(number("2")),
+            ]
+        ),
+        expr_from_projection(only(&select.projection))
+    );
+
+    let sql = "SELECT d FROM generate_series(DATE '2024-01-01', DATE '2024-02-01', INTERVAL '1 week') AS d";
+    let select = pg_and_generic().verified_only_select(sql);
+    match &only(&select.from).relation {
+        TableFactor::Table {
+            name,
+            args: Some(args),
+            alias: Some(alias),
+            ..
+        } => {
+            assert_eq!("generate_series", name.to_string());
+            assert_eq!(Ident::new("d"), alias.name);
+            assert_eq!(3, args.len());
+            assert_eq!(
+                FunctionArg::Unnamed(FunctionArgExpr::Expr(Expr::Interval(Interval {
+                    value: Box::new(Expr::Value(Value::SingleQuotedString("1 week".into()))),
+                    leading_field: None,
+                    leading_precision: None,
+                    last_field: None,
+                    fractional_seconds_precision: None,
+                }))),
+                args[2]
+            );
+        }
+        _ => unreachable!(),
+    }
+}
+
 #[test]
 fn parse_array_agg() {
     // follows general function with wildcard code path
@@ -4088,6 +5302,50 @@ fn parse_array_agg() {
     pg().verified_stmt(sql4);
 }
 
+#[test]
+fn parse_string_agg() {
+    let sql = "SELECT STRING_AGG(DISTINCT name, ', ' ORDER BY name DESC) FROM users";
+    let select = pg().verified_only_select(sql);
+    assert_eq!(
+        &Expr::Function(Function {
+            name: ObjectName(vec![Ident::new("STRING_AGG")]),
+            args: FunctionArguments::List(FunctionArgumentList {
+                duplicate_treatment: Some(DuplicateTreatment::Distinct),
+                args: vec![
+                    FunctionArg::Unnamed(FunctionArgExpr::Expr(Expr::Identifier(Ident::new(
+                        "name"
+                    )))),
+                    FunctionArg::Unnamed(FunctionArgExpr::Expr(Expr::Value(
+                        Value::SingleQuotedString(", ".to_string())
+                    ))),
+                ],
+                clauses: vec![FunctionArgumentClause::OrderBy(vec![OrderByExpr {
+                    expr: Expr::Identifier(Ident::new("name")),
+                    asc: Some(false),
+                    nulls_first: None,
+                }])],
+            }),
+            filter: None,
+            null_treatment: None,
+            nth_value_order: None,
+            over: None,
+            within_group: vec![],
+        }),
+        expr_from_projection(only(&select.projection))
+    );
+
+    pg_and_generic().verified_expr("STRING_AGG(name, ',')");
+    pg_and_generic().verified_expr("STRING_AGG(name, ',' ORDER BY id)");
+
+    // MySQL's `SEPARATOR` spelling is not accepted here
+    assert_eq!(
+        pg().parse_sql_statements("SELECT STRING_AGG(name SEPARATOR ',') FROM users"),
+        Err(ParserError::ParserError(
+            "Expected ), found: SEPARATOR".to_string()
+        ))
+    );
+}
+
 #[test]
 fn parse_mat_cte() {
     let sql = r#"WITH cte AS MATERIALIZED (SELECT id FROM accounts) SELECT id FROM cte"#;
@@ -4097,6 +5355,67 @@ fn parse_mat_cte() {
     pg().verified_stmt(sql2);
 }
 
+#[test]
+fn parse_similar_to_backslash_escape() {
+    let sql = r"SELECT * FROM t WHERE col NOT SIMILAR TO 'a\%' || suffix ESCAPE '\'";
+    let select = pg_and_generic().verified_only_select(sql);
+    assert_eq!(
+        Some(Expr::SimilarTo {
+            negated: true,
+            expr: Box::new(Expr::Identifier(Ident::new("col"))),
+            pattern: Box::new(Expr::BinaryOp {
+                left: Box::new(Expr::Value(Value::SingleQuotedString(r"a\%".to_string()))),
+                op: BinaryOperator::StringConcat,
+                right: Box::new(Expr::Identifier(Ident::new("suffix"))),
+            }),
+            escape_char: Some("\\".to_string()),
+        }),
+        select.selection
+    );
+}
+
+#[test]
+fn parse_extract_epoch_and_isodow() {
+    let select = pg().verified_only_select(
+        "SELECT EXTRACT(EPOCH FROM created_at), EXTRACT(ISODOW FROM created_at) FROM events",
+    );
+    assert_eq!(
+        vec![DateTimeField::Epoch, DateTimeField::Isodow],
+        select
+            .projection
+            .iter()
+            .map(|item| match expr_from_projection(item) {
+                Expr::Extract { field, .. } => field.clone(),
+                _ => unreachable!(),
+            })
+            .collect::<Vec<_>>()
+    );
+
+    // DATE_PART takes the same fields, given as a string
+    assert_eq!(
+        Expr::DatePart {
+            field: DateTimeField::Epoch,
+            expr: Box::new(Expr::Identifier(Ident::new("created_at"))),
+        },
+        pg_and_generic().verified_expr("DATE_PART('EPOCH', created_at)")
+    );
+    assert_eq!(
+        Expr::DatePart {
+            field: DateTimeField::Isodow,
+            expr: Box::new(Expr::Identifier(Ident::new("created_at"))),
+        },
+        pg_and_generic().expr_parses_to(
+            "date_part('isodow', created_at)",
+            "DATE_PART('ISODOW', created_at)"
+        )
+    );
+    assert_eq!(
+        pg().parse_sql_statements("SELECT DATE_PART('fortnight', created_at)")
+            .unwrap_err(),
+        ParserError::ParserError("Expected date/time field, found: 'fortnight'".to_string())
+    );
+}
+
 #[test]
 fn parse_at_time_zone() {
     pg_and_generic().verified_expr("CURRENT_TIMESTAMP AT TIME ZONE tz");
@@ -4135,6 +5454,21 @@ fn parse_at_time_zone() {
         ),
         expr
     );
+
+    // chained with a cast
+    pretty_assertions::assert_eq!(
+        pg_and_generic().verified_expr("(ts AT TIME ZONE 'UTC')::DATE"),
+        Expr::Cast {
+            kind: CastKind::DoubleColon,
+            expr: Box::new(Expr::Nested(Box::new(Expr::AtTimeZone {
+                timestamp: Box::new(Expr::Identifier(Ident::new("ts"))),
+                time_zone: Box::new(Expr::Value(Value::SingleQuotedString("UTC".to_owned()))),
+            }))),
+            data_type: DataType::Date,
+            format: None,
+        }
+    );
+    pg_and_generic().verified_expr("ts AT TIME ZONE 'UTC' AT TIME ZONE 'America/Los_Angeles'");
 }
 
 #[test]
@@ -4158,4 +5492,177 @@ fn parse_create_table_with_options() {
         }
         _ => unreachable!(),
     }
+
+    // legacy storage parameters
+    let sql = "CREATE TABLE t (c INT) WITH (OIDS=FALSE, fillfactor=70)";
+    match pg().one_statement_parses_to(
+        sql,
+        "CREATE TABLE t (c INT) WITH (OIDS = false, fillfactor = 70)",
+    ) {
+        Statement::CreateTable(CreateTable { with_options, .. }) => {
+            assert_eq!(
+                vec![
+                    SqlOption {
+                        name: "OIDS".into(),
+                        value: Expr::Value(Value::Boolean(false)),
+                    },
+                    SqlOption {
+                        name: "fillfactor".into(),
+                        value: Expr::Value(number("70")),
+                    },
+                ],
+                with_options
+            );
+        }
+        _ => unreachable!(),
+    }
+}
+
+#[test]
+fn parse_create_table_check_no_inherit() {
+    let sql = "CREATE TABLE t (\
+        a INT CONSTRAINT a_positive CHECK (a > 0) NO INHERIT, \
+        b INT CHECK ((b + 1) * (b - 1) > (0)), \
+        CONSTRAINT chk CHECK ((a) < (b)) NO INHERIT, \
+        CHECK (a <> b))";
+    match pg_and_generic().verified_stmt(sql) {
+        Statement::CreateTable(CreateTable {
+            columns,
+            constraints,
+            ..
+        }) => {
+            assert_eq!(
+                vec![ColumnOptionDef {
+                    name: Some("a_positive".into()),
+                    option: ColumnOption::Check {
+                        expr: pg().verified_expr("a > 0"),
+                        no_inherit: true,
+                    },
+                }],
+                columns[0].options
+            );
+            assert_eq!(
+                vec![ColumnOptionDef {
+                    name: None,
+                    option: ColumnOption::Check {
+                        expr: pg().verified_expr("(b + 1) * (b - 1) > (0)"),
+                        no_inherit: false,
+                    },
+                }],
+                columns[1].options
+            );
+            assert_eq!(
+                vec![
+                    TableConstraint::Check {
+                        name: Some("chk".into()),
+                        expr: Box::new(pg().verified_expr("(a) < (b)")),
+                        no_inherit: true,
+                    },
+                    TableConstraint::Check {
+                        name: None,
+                        expr: Box::new(pg().verified_expr("a <> b")),
+                        no_inherit: false,
+                    },
+                ],
+                constraints
+            );
+        }
+        _ => unreachable!(),
+    }
+
+    pg_and_generic().verified_stmt("ALTER TABLE t ADD CONSTRAINT chk CHECK (a > 0) NO INHERIT");
+
+    match pg_and_generic().verified_stmt("CREATE TABLE t (a INT, CHECK (a > 0) NO INHERIT)") {
+        Statement::CreateTable(CreateTable { constraints, .. }) => assert_eq!(
+            vec![TableConstraint::Check {
+                name: None,
+                expr: Box::new(pg().verified_expr("a > 0")),
+                no_inherit: true,
+            }],
+            constraints
+        ),
+        _ => unreachable!(),
+    }
+    match alter_table_op_with_name(
+        pg_and_generic().verified_stmt("ALTER TABLE t ADD CHECK (a > 0) NO INHERIT"),
+        "t",
+    ) {
+        AlterTableOperation::AddConstraint(TableConstraint::Check {
+            name: None,
+            no_inherit: true,
+            ..
+        }) => {}
+        op => panic!("unexpected operation: {op:?}"),
+    }
+
+    // NO INHERIT is PostgreSQL syntax
+    let dialects = all_dialects_except(|d| d.is::<PostgreSqlDialect>() || d.is::<GenericDialect>());
+    assert!(dialects
+        .parse_sql_statements("CREATE TABLE t (a INT CHECK (a > 0) NO INHERIT)")
+        .is_err());
+    assert!(dialects
+        .parse_sql_statements("CREATE TABLE t (a INT, CHECK (a > 0) NO INHERIT)")
+        .is_err());
+}
+
+#[test]
+fn parse_foreign_key_match_and_action_order() {
+    let sql = "CREATE TABLE t (\
+        a INT REFERENCES o (id) MATCH SIMPLE ON UPDATE RESTRICT ON DELETE CASCADE, \
+        b INT REFERENCES o (id) ON DELETE NO ACTION ON UPDATE SET DEFAULT, \
+        FOREIGN KEY (a, b) REFERENCES o(x, y) MATCH FULL ON UPDATE SET NULL ON DELETE SET DEFAULT)";
+    match pg_and_generic().verified_stmt(sql) {
+        Statement::CreateTable(CreateTable {
+            columns,
+            constraints,
+            ..
+        }) => {
+            assert_eq!(
+                ColumnOption::ForeignKey {
+                    foreign_table: ObjectName(vec!["o".into()]),
+                    referred_columns: vec!["id".into()],
+                    match_kind: Some(ConstraintReferenceMatchKind::Simple),
+                    on_delete: Some(ReferentialAction::Cascade),
+                    on_update: Some(ReferentialAction::Restrict),
+                    on_update_first: true,
+                    characteristics: None,
+                },
+                columns[0].options[0].option
+            );
+            assert_eq!(
+                ColumnOption::ForeignKey {
+                    foreign_table: ObjectName(vec!["o".into()]),
+                    referred_columns: vec!["id".into()],
+                    match_kind: None,
+                    on_delete: Some(ReferentialAction::NoAction),
+                    on_update: Some(ReferentialAction::SetDefault),
+                    on_update_first: false,
+                    characteristics: None,
+                },
+                columns[1].options[0].option
+            );
+            assert_eq!(
+                vec![TableConstraint::ForeignKey {
+                    name: None,
+                    columns: vec!["a".into(), "b".into()],
+                    foreign_table: ObjectName(vec!["o".into()]),
+                    referred_columns: vec!["x".into(), "y".into()],
+                    match_kind: Some(ConstraintReferenceMatchKind::Full),
+                    on_delete: Some(ReferentialAction::SetDefault),
+                    on_update: Some(ReferentialAction::SetNull),
+                    on_update_first: true,
+                    characteristics: None,
+                }],
+                constraints
+            );
+        }
+        _ => unreachable!(),
+    }
+
+    pg_and_generic().verified_stmt(
+        "ALTER TABLE t ADD CONSTRAINT fk FOREIGN KEY (a) REFERENCES o(id) MATCH PARTIAL ON DELETE CASCADE",
+    );
+    assert!(pg()
+        .parse_sql_statements("CREATE TABLE t (a INT REFERENCES o (id) MATCH ALL)")
+        .is_err());
 }
@@ -142,6 +142,7 @@ fn parse_delimited_identifiers() {
                 clauses: vec![],
             }),
             null_treatment: None,
+            nth_value_order: None,
             filter: None,
             over: None,
             within_group: vec![],
@@ -190,3 +191,64 @@ fn test_create_view_with_no_schema_binding() {
     redshift_and_generic()
         .verified_stmt("CREATE VIEW myevent AS SELECT eventname FROM event WITH NO SCHEMA BINDING");
 }
+
+#[test]
+fn parse_create_table_column_encode() {
+    let sql = "CREATE TABLE t (a INT ENCODE az64, b VARCHAR(10) NOT NULL ENCODE lzo)";
+    match redshift_and_generic().verified_stmt(sql) {
+        Statement::CreateTable(CreateTable { columns, .. }) => {
+            assert_eq!(
+                vec![ColumnOptionDef {
+                    name: None,
+                    option: ColumnOption::Encode(Ident::new("az64")),
+                }],
+                columns[0].options
+            );
+            assert_eq!(
+                ColumnOption::Encode(Ident::new("lzo")),
+                columns[1].options[1].option
+            );
+        }
+        _ => unreachable!(),
+    }
+}
+
+#[test]
+fn parse_create_table_dist_and_sort_keys() {
+    let sql = "CREATE TABLE t (a INT, b INT) DISTSTYLE KEY DISTKEY(a) SORTKEY(a, b)";
+    match redshift_and_generic().verified_stmt(sql) {
+        Statement::CreateTable(CreateTable {
+            dist_style,
+            dist_key,
+            sort_key,
+            ..
+        }) => {
+            assert_eq!(Some(DistStyle::Key), dist_style);
+            assert_eq!(Some(Ident::new("a")), dist_key);
+            assert_eq!(
+                Some(SortKey {
+                    kind: None,
+                    columns: vec![Ident::new("a"), Ident::new("b")],
+                }),
+                sort_key
+            );
+        }
+        _ => unreachable!(),
+    }
+
+    match redshift().verified_stmt("CREATE TABLE t (a INT) DISTSTYLE EVEN INTERLEAVED SORTKEY(a)") {
+        Statement::CreateTable(CreateTable {
+            dist_style,
+            dist_key,
+            sort_key,
+            ..
+        }) => {
+            assert_eq!(Some(DistStyle::Even), dist_style);
+            assert_eq!(None, dist_key);
+            assert_eq!(Some(SortKeyKind::Interleaved), sort_key.unwrap().kind);
+        }
+        _ => unreachable!(),
+    }
+
+    redshift().verified_stmt("CREATE TABLE t DISTKEY(a) COMPOUND SORTKEY(a) AS SELECT 1 AS a");
+}
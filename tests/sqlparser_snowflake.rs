@@ -520,6 +520,25 @@ fn test_snowflake_single_line_tokenize() {
     assert_eq!(expected, tokens);
 }
 
+#[test]
+fn parse_sf_create_materialized_view() {
+    match snowflake().verified_stmt(
+        "CREATE MATERIALIZED VIEW IF NOT EXISTS mv CLUSTER BY (a) AS SELECT a FROM t",
+    ) {
+        Statement::CreateView {
+            materialized,
+            if_not_exists,
+            cluster_by,
+            ..
+        } => {
+            assert!(materialized);
+            assert!(if_not_exists);
+            assert_eq!(vec![Ident::new("a")], cluster_by);
+        }
+        _ => unreachable!(),
+    }
+}
+
 #[test]
 fn parse_sf_create_or_replace_view_with_comment_missing_equal() {
     assert!(snowflake_and_generic()
@@ -552,6 +571,8 @@ fn parse_sf_create_or_replace_with_comment_for_snowflake() {
             with_no_schema_binding: late_binding,
             if_not_exists,
             temporary,
+            with_check_option,
+            with_data,
         } => {
             assert_eq!("v", name.to_string());
             assert_eq!(columns, vec![]);
@@ -563,6 +584,8 @@ fn parse_sf_create_or_replace_with_comment_for_snowflake() {
             assert!(comment.is_some());
             assert_eq!(comment.expect("expected comment"), "hello, world");
             assert!(!late_binding);
+            assert!(with_check_option.is_none());
+            assert!(with_data.is_none());
             assert!(!if_not_exists);
             assert!(!temporary);
         }
@@ -898,6 +921,7 @@ fn parse_delimited_identifiers() {
             }),
             filter: None,
             null_treatment: None,
+            nth_value_order: None,
             over: None,
             within_group: vec![],
         }),
@@ -2214,3 +2238,99 @@ fn asof_joins() {
           "ORDER BY s.observed",
     ));
 }
+
+#[test]
+fn parse_time_travel() {
+    for (sql, expected) in [
+        (
+            "SELECT * FROM t AT(TIMESTAMP => '2024-01-01 00:00:00'::TIMESTAMP_LTZ)",
+            TableVersion::At(TimeTravelPoint::Timestamp(Expr::Cast {
+                kind: CastKind::DoubleColon,
+                expr: Box::new(Expr::Value(Value::SingleQuotedString(
+                    "2024-01-01 00:00:00".to_string(),
+                ))),
+                data_type: DataType::Custom(ObjectName(vec![Ident::new("TIMESTAMP_LTZ")]), vec![]),
+                format: None,
+            })),
+        ),
+        (
+            "SELECT * FROM t AT(OFFSET => -60 * 5) AS t1",
+            TableVersion::At(TimeTravelPoint::Offset(Expr::BinaryOp {
+                left: Box::new(Expr::UnaryOp {
+                    op: UnaryOperator::Minus,
+                    expr: Box::new(Expr::Value(number("60"))),
+                }),
+                op: BinaryOperator::Multiply,
+                right: Box::new(Expr::Value(number("5"))),
+            })),
+        ),
+        (
+            "SELECT * FROM t BEFORE(STATEMENT => '8e5d0ca9')",
+            TableVersion::Before(TimeTravelPoint::Statement(Expr::Value(
+                Value::SingleQuotedString("8e5d0ca9".to_string()),
+            ))),
+        ),
+        (
+            "SELECT * FROM t AT(STREAM => 's1')",
+            TableVersion::At(TimeTravelPoint::Stream(Expr::Value(
+                Value::SingleQuotedString("s1".to_string()),
+            ))),
+        ),
+    ] {
+        let select = snowflake_and_generic().verified_only_select(sql);
+        match &select.from[0].relation {
+            TableFactor::Table { version, .. } => assert_eq!(&Some(expected), version),
+            _ => unreachable!(),
+        }
+    }
+}
+
+#[test]
+fn parse_create_function() {
+    snowflake().verified_stmt(
+        "CREATE OR REPLACE FUNCTION area(radius FLOAT) RETURNS FLOAT LANGUAGE SQL IMMUTABLE AS 'pi() * radius * radius'",
+    );
+    snowflake().verified_stmt("CREATE FUNCTION f() RETURNS NUMBER AS $$ 1 + 1 $$");
+    snowflake().verified_stmt("CREATE FUNCTION f(x NUMBER) RETURNS NUMBER VOLATILE AS 'x + 1'");
+
+    // The body comes last, after the documented options
+    assert_eq!(
+        snowflake()
+            .parse_sql_statements("CREATE FUNCTION f() RETURNS NUMBER AS '1' LANGUAGE SQL")
+            .unwrap_err(),
+        ParserError::ParserError("Expected end of statement, found: LANGUAGE".to_string())
+    );
+    assert!(snowflake()
+        .parse_sql_statements("CREATE FUNCTION f() RETURNS NUMBER IMMUTABLE LANGUAGE SQL AS '1'")
+        .is_err());
+}
+
+#[test]
+fn parse_use() {
+    let name = |parts: &[&str]| ObjectName(parts.iter().map(|p| Ident::new(*p)).collect());
+    assert_eq!(
+        snowflake().verified_stmt("USE mydb"),
+        Statement::Use(Use::Object(name(&["mydb"])))
+    );
+    assert_eq!(
+        snowflake().verified_stmt("USE DATABASE mydb"),
+        Statement::Use(Use::Database(name(&["mydb"])))
+    );
+    assert_eq!(
+        snowflake().verified_stmt("USE SCHEMA mydb.my_schema"),
+        Statement::Use(Use::Schema(name(&["mydb", "my_schema"])))
+    );
+    assert_eq!(
+        snowflake().verified_stmt("USE WAREHOUSE compute_wh"),
+        Statement::Use(Use::Warehouse(name(&["compute_wh"])))
+    );
+    assert_eq!(
+        snowflake().verified_stmt("USE ROLE analyst"),
+        Statement::Use(Use::Role(name(&["analyst"])))
+    );
+    // a bare object kind keyword names the object itself
+    assert_eq!(
+        snowflake().verified_stmt("USE warehouse"),
+        Statement::Use(Use::Object(name(&["warehouse"])))
+    );
+}
@@ -21,7 +21,7 @@ use test_utils::*;
 
 use sqlparser::ast::SelectItem::UnnamedExpr;
 use sqlparser::ast::*;
-use sqlparser::dialect::{GenericDialect, SQLiteDialect};
+use sqlparser::dialect::{DuckDbDialect, GenericDialect, SQLiteDialect};
 use sqlparser::parser::{ParserError, ParserOptions};
 use sqlparser::tokenizer::Token;
 
@@ -118,6 +118,61 @@ fn pragma_eq_placeholder_style() {
     }
 }
 
+#[test]
+fn pragma_unquoted_and_signed_values() {
+    match sqlite_and_generic().verified_stmt("PRAGMA table_info(t)") {
+        Statement::Pragma {
+            name,
+            value: Some(val),
+            is_eq: false,
+        } => {
+            assert_eq!("table_info", name.to_string());
+            assert_eq!(Value::UnQuotedString("t".to_string()), val);
+        }
+        _ => unreachable!(),
+    }
+
+    match sqlite_and_generic().verified_stmt("PRAGMA foreign_keys = ON") {
+        Statement::Pragma {
+            name,
+            value: Some(val),
+            is_eq: true,
+        } => {
+            assert_eq!("foreign_keys", name.to_string());
+            assert_eq!(Value::UnQuotedString("ON".to_string()), val);
+        }
+        _ => unreachable!(),
+    }
+
+    match sqlite_and_generic().verified_stmt("PRAGMA main.cache_size = -2000") {
+        Statement::Pragma {
+            name,
+            value: Some(val),
+            is_eq: true,
+        } => {
+            assert_eq!("main.cache_size", name.to_string());
+            assert_eq!(number("-2000"), val);
+        }
+        _ => unreachable!(),
+    }
+
+    sqlite().verified_stmt("PRAGMA journal_mode");
+    sqlite().verified_stmt("PRAGMA journal_mode = WAL");
+    sqlite().verified_stmt("PRAGMA schema.journal_mode(DELETE)");
+
+    assert_eq!(
+        ParserError::ParserError("Expected number, found: ON".to_string()),
+        sqlite()
+            .parse_sql_statements("PRAGMA cache_size = -ON")
+            .unwrap_err()
+    );
+    assert!(all_dialects_except(|d| {
+        d.is::<SQLiteDialect>() || d.is::<DuckDbDialect>() || d.is::<GenericDialect>()
+    })
+    .parse_sql_statements("PRAGMA journal_mode")
+    .is_err());
+}
+
 #[test]
 fn parse_create_table_without_rowid() {
     let sql = "CREATE TABLE t (a INT) WITHOUT ROWID";
@@ -171,6 +226,8 @@ fn parse_create_view_temporary_if_not_exists() {
             with_no_schema_binding: late_binding,
             if_not_exists,
             temporary,
+            with_check_option,
+            with_data,
         } => {
             assert_eq!("myschema.myview", name.to_string());
             assert_eq!(Vec::<ViewColumnDef>::new(), columns);
@@ -181,6 +238,8 @@ fn parse_create_view_temporary_if_not_exists() {
             assert_eq!(cluster_by, vec![]);
             assert!(comment.is_none());
             assert!(!late_binding);
+            assert!(with_check_option.is_none());
+            assert!(with_data.is_none());
             assert!(if_not_exists);
             assert!(temporary);
         }
@@ -342,6 +401,7 @@ fn parse_window_function_with_filter() {
                     clauses: vec![],
                 }),
                 null_treatment: None,
+                nth_value_order: None,
                 over: Some(WindowType::WindowSpec(WindowSpec {
                     window_name: None,
                     partition_by: vec![],
@@ -373,6 +433,58 @@ fn parse_attach_database() {
     }
 }
 
+#[test]
+fn parse_attach_and_detach_database_without_keyword() {
+    match sqlite().verified_stmt("ATTACH 'other.db' AS other") {
+        Statement::AttachDatabase {
+            schema_name,
+            database: false,
+            ..
+        } => assert_eq!("other", schema_name.value),
+        _ => unreachable!(),
+    }
+    sqlite().verified_stmt("ATTACH DATABASE ':memory:' || 'x' AS mem");
+
+    assert_eq!(
+        Statement::DetachDuckDBDatabase {
+            if_exists: false,
+            database: true,
+            database_alias: Ident::new("other"),
+        },
+        sqlite().verified_stmt("DETACH DATABASE other")
+    );
+    assert_eq!(
+        Statement::DetachDuckDBDatabase {
+            if_exists: false,
+            database: false,
+            database_alias: Ident::new("other"),
+        },
+        sqlite().verified_stmt("DETACH other")
+    );
+    assert!(sqlite()
+        .parse_sql_statements("DETACH DATABASE IF EXISTS other")
+        .is_err());
+}
+
+#[test]
+fn parse_vacuum() {
+    sqlite_and_generic().verified_stmt("VACUUM");
+    sqlite_and_generic().verified_stmt("VACUUM main");
+    match sqlite_and_generic().verified_stmt("VACUUM main INTO 'backup.db'") {
+        Statement::Vacuum { tables, into, .. } => {
+            assert_eq!("main", tables[0].to_string());
+            assert_eq!(
+                Some(Expr::Value(Value::SingleQuotedString(
+                    "backup.db".to_string()
+                ))),
+                into
+            );
+        }
+        _ => unreachable!(),
+    }
+    sqlite_and_generic().verified_stmt("VACUUM INTO 'backup.db'");
+}
+
 #[test]
 fn parse_where_in_empty_list() {
     let sql = "SELECT * FROM t1 WHERE a IN ()";
@@ -404,9 +516,9 @@ fn parse_start_transaction_with_modifier() {
     sqlite_and_generic().verified_stmt("BEGIN DEFERRED TRANSACTION");
     sqlite_and_generic().verified_stmt("BEGIN IMMEDIATE TRANSACTION");
     sqlite_and_generic().verified_stmt("BEGIN EXCLUSIVE TRANSACTION");
-    sqlite_and_generic().one_statement_parses_to("BEGIN DEFERRED", "BEGIN DEFERRED TRANSACTION");
-    sqlite_and_generic().one_statement_parses_to("BEGIN IMMEDIATE", "BEGIN IMMEDIATE TRANSACTION");
-    sqlite_and_generic().one_statement_parses_to("BEGIN EXCLUSIVE", "BEGIN EXCLUSIVE TRANSACTION");
+    sqlite_and_generic().verified_stmt("BEGIN DEFERRED");
+    sqlite_and_generic().verified_stmt("BEGIN IMMEDIATE");
+    sqlite_and_generic().verified_stmt("BEGIN EXCLUSIVE");
 
     let unsupported_dialects = TestedDialects {
         dialects: all_dialects()
@@ -433,6 +545,21 @@ fn parse_start_transaction_with_modifier() {
     );
 }
 
+#[test]
+fn parse_replace_into() {
+    match sqlite().verified_stmt("REPLACE INTO t (a, b) VALUES (1, 2)") {
+        Statement::Insert(Insert {
+            or, replace_into, ..
+        }) => {
+            assert_eq!(or, Some(SqliteOnConflict::Replace));
+            assert!(replace_into);
+        }
+        _ => unreachable!(),
+    }
+    sqlite().verified_stmt("REPLACE INTO t SELECT * FROM s");
+    sqlite().verified_stmt("INSERT OR REPLACE INTO t (a) VALUES (1)");
+}
+
 fn sqlite() -> TestedDialects {
     TestedDialects {
         dialects: vec![Box::new(SQLiteDialect {})],